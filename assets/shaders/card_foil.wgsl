@@ -0,0 +1,33 @@
+// A holographic foil effect over a card's back texture (see `CardMaterial`).
+#import bevy_pbr::{
+    forward_io::VertexOutput,
+    mesh_view_bindings::view,
+}
+
+struct CardMaterial {
+    color: vec4<f32>,
+    foil_intensity: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var base_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(1) var base_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(2) var<uniform> material: CardMaterial;
+
+const TAU: f32 = 6.28318530718;
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    let base = textureSample(base_texture, base_sampler, mesh.uv) * material.color;
+    // the texture's transparent (rounded) corners are cut out
+    if base.a < 0.5 {
+        discard;
+    }
+    // How directly the surface faces the camera; the foil's hue shifts as the card tilts.
+    let view_dir = normalize(view.world_position.xyz - mesh.world_position.xyz);
+    let facing = abs(dot(normalize(mesh.world_normal), view_dir));
+    // a diagonal sweep across the card, so the whole card isn't one flat color
+    let phase = facing * 3.0 + dot(mesh.uv, vec2<f32>(1.5, 1.0));
+    let rainbow = 0.5 + 0.5 * cos(TAU * (vec3<f32>(phase) + vec3<f32>(0.0, 0.33, 0.67)));
+    let foil = base.rgb * 0.6 + rainbow * 0.5;
+    return vec4<f32>(mix(base.rgb, foil, material.foil_intensity), 1.0);
+}
@@ -0,0 +1,186 @@
+//! Opt-in analytics for playtesting (see [`Settings::analytics`]).
+//!
+//! Each event is appended as a line of JSON to `analytics.jsonl` in the user's data directory
+//! (for example, `~/.local/share/bevy-52-card-pickup/analytics.jsonl` on Linux).
+//! Nothing is logged unless the player opts in, and nothing is ever logged on the web.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use rand::{RngExt, rng};
+use serde::Serialize;
+
+use crate::{
+    animator::CollectingCard,
+    mode::GameMode,
+    persist,
+    seed::GameSeed,
+    settings::Settings,
+    state::{CardsCollected, CardsDealt},
+    stats::RoundClock,
+};
+
+/// The name of the analytics log file.
+const FILE_NAME: &str = "analytics.jsonl";
+
+/// Resource that identifies this session in the analytics log, so each session's events can be told apart.
+#[derive(Resource, Debug)]
+pub struct AnalyticsSession(u64);
+
+impl Default for AnalyticsSession {
+    fn default() -> Self {
+        Self(rng().random())
+    }
+}
+
+/// An event recorded in the analytics log.
+#[derive(Serialize, Debug)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AnalyticsEvent {
+    /// A new board was dealt.
+    Deal {
+        seed: u64,
+        mode: &'static str,
+        cards: u8,
+    },
+    /// A card was picked up (and is on its way to the pile).
+    Pickup { card: String, round_time: f32 },
+    /// All cards were collected.
+    Win {
+        seed: u64,
+        mode: &'static str,
+        cards: u8,
+        round_time: f32,
+    },
+    /// The player gave up.
+    GiveUp {
+        seed: u64,
+        mode: &'static str,
+        collected: u8,
+        cards: u8,
+        round_time: f32,
+    },
+    /// A new board was dealt before the round ended (including the re-deals of [`GameMode::Zen`]).
+    Restart {
+        seed: u64,
+        mode: &'static str,
+        collected: u8,
+        cards: u8,
+        round_time: f32,
+    },
+    /// The [`Settings`] were changed (this holds all of them, not only the changed ones).
+    Settings { settings: Settings },
+}
+
+/// A line of the analytics log.
+#[derive(Serialize, Debug)]
+struct Record<'a> {
+    /// See [`AnalyticsSession`].
+    session: u64,
+    /// When the event happened (in seconds since the Unix epoch).
+    timestamp: f64,
+    #[serde(flatten)]
+    event: &'a AnalyticsEvent,
+}
+
+/// A [`SystemParam`] that logs [`AnalyticsEvent`]s (if [`Settings::analytics`] is enabled).
+#[derive(SystemParam)]
+pub struct Analytics<'w> {
+    settings: Res<'w, Settings>,
+    session: Res<'w, AnalyticsSession>,
+}
+
+impl Analytics<'_> {
+    /// Appends the `event` to the analytics log, unless the player has not opted in.
+    pub fn log(&self, event: AnalyticsEvent) {
+        if !self.settings.analytics || cfg!(target_arch = "wasm32") {
+            return;
+        }
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+        let record = Record {
+            session: self.session.0,
+            timestamp,
+            event: &event,
+        };
+        match serde_json::to_string(&record) {
+            Ok(line) => persist::append_line(FILE_NAME, &line),
+            Err(e) => warn!("Failed to serialize an analytics event: {e}"),
+        }
+    }
+}
+
+/// A [`SystemParam`] that describes the current round for the [`AnalyticsEvent`]s.
+#[derive(SystemParam)]
+pub struct RoundInfo<'w> {
+    seed: Res<'w, GameSeed>,
+    mode: Res<'w, GameMode>,
+    cards_collected: Res<'w, CardsCollected>,
+    cards_dealt: Res<'w, CardsDealt>,
+    clock: Res<'w, RoundClock>,
+}
+
+/// System that logs the [`AnalyticsEvent::Deal`] when entering the
+/// [`GameState::Deal`](crate::state::GameState::Deal) state (after the cards are dealt).
+pub fn log_deal(analytics: Analytics, round: RoundInfo) {
+    analytics.log(AnalyticsEvent::Deal {
+        seed: round.seed.0,
+        mode: round.mode.label(),
+        cards: round.cards_dealt.0,
+    });
+}
+
+/// The observer that logs the [`AnalyticsEvent::Pickup`] of each collected card.
+pub fn log_pickup(event: On<CollectingCard>, analytics: Analytics, clock: Res<RoundClock>) {
+    analytics.log(AnalyticsEvent::Pickup {
+        card: event.card.to_string(),
+        round_time: clock.0.elapsed_secs(),
+    });
+}
+
+/// System that logs the [`AnalyticsEvent::Win`] when changing from
+/// [`GameState::Play`](crate::state::GameState::Play) to [`GameState::Win`](crate::state::GameState::Win).
+pub fn log_win(analytics: Analytics, round: RoundInfo) {
+    analytics.log(AnalyticsEvent::Win {
+        seed: round.seed.0,
+        mode: round.mode.label(),
+        cards: round.cards_dealt.0,
+        round_time: round.clock.0.elapsed_secs(),
+    });
+}
+
+/// System that logs the [`AnalyticsEvent::GiveUp`] when changing from
+/// [`GameState::Play`](crate::state::GameState::Play) to [`GameState::Lose`](crate::state::GameState::Lose).
+pub fn log_give_up(analytics: Analytics, round: RoundInfo) {
+    analytics.log(AnalyticsEvent::GiveUp {
+        seed: round.seed.0,
+        mode: round.mode.label(),
+        collected: round.cards_collected.0,
+        cards: round.cards_dealt.0,
+        round_time: round.clock.0.elapsed_secs(),
+    });
+}
+
+/// System that logs the [`AnalyticsEvent::Restart`] when changing from
+/// [`GameState::Play`](crate::state::GameState::Play) to [`GameState::Deal`](crate::state::GameState::Deal).
+///
+/// This runs before the round's resources are reset (see [`reset_round`](crate::state::reset_round)).
+pub fn log_restart(analytics: Analytics, round: RoundInfo) {
+    analytics.log(AnalyticsEvent::Restart {
+        seed: round.seed.0,
+        mode: round.mode.label(),
+        collected: round.cards_collected.0,
+        cards: round.cards_dealt.0,
+        round_time: round.clock.0.elapsed_secs(),
+    });
+}
+
+/// System that logs the [`AnalyticsEvent::Settings`] whenever the [`Settings`] change
+/// (except when they are first loaded).
+pub fn log_settings(analytics: Analytics) {
+    if analytics.settings.is_added() {
+        return;
+    }
+    analytics.log(AnalyticsEvent::Settings {
+        settings: analytics.settings.clone(),
+    });
+}
@@ -3,11 +3,35 @@ use std::f32::consts::{FRAC_PI_2, PI};
 use bevy::{
     animation::{AnimationEvent, AnimationTargetId, animated_field},
     prelude::*,
+    window::PrimaryWindow,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    cards::{BOARD_HALF_SIZE, CARD_HALF_SIZE, CARD_THICKNESS, Card},
-    state::{CardsCollected, GameState},
+    audio::{Sounds, play_flip, play_sfx},
+    camera::MainCamera,
+    cards::Card,
+    controls::{KeyAction, KeyBindings},
+    dealing::DealFlight,
+    fade::FadeCommandsExt,
+    foundation::{Foundations, Shake},
+    freeze::FreezeFrame,
+    heatmap::PickTimeline,
+    hotseat::{CurrentPlayer, PlayerScores},
+    layout::{PileConfig, pile_position_at},
+    milestone::{Milestone, is_milestone},
+    mode::{GameMode, RoundsCleared},
+    read::ReadHold,
+    reveal::Reveal,
+    select::DelayedPickup,
+    settings::Settings,
+    speech::Announcer,
+    state::{CardsCollected, CardsDealt, GameState, PileSlot, PileSlots, SuitsCollected},
+    stats::{LifetimeStats, RoundClock},
+    table::is_on_board,
+    tooltip::CardTooltip,
+    touch::TouchHold,
+    trail::Trail,
 };
 
 #[derive(Debug, AnimationEvent, Clone, Copy)]
@@ -18,6 +42,42 @@ pub struct CollectingCard {
 #[derive(Debug, Clone, Copy, Component)]
 pub struct AnimatorNodeId(pub AnimationNodeIndex);
 
+/// How fast the game's animations (and countdowns) play, relative to their designed durations.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+}
+
+impl GameSpeed {
+    /// The factor that durations are divided by (and playback speeds are multiplied by).
+    pub fn factor(&self) -> f32 {
+        match self {
+            GameSpeed::Slow => 0.67,
+            GameSpeed::Normal => 1.0,
+            GameSpeed::Fast => 1.5,
+        }
+    }
+
+    /// The human readable name of the speed.
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameSpeed::Slow => "Slow",
+            GameSpeed::Normal => "Normal",
+            GameSpeed::Fast => "Fast",
+        }
+    }
+
+    /// Changes to the next faster (or slower if `step` is negative) speed, stopping at either end.
+    pub fn step(&mut self, step: i8) {
+        let list = [GameSpeed::Slow, GameSpeed::Normal, GameSpeed::Fast];
+        let index = list.iter().position(|speed| speed == self).unwrap_or(1);
+        *self = list[(index as isize + step as isize).clamp(0, 2) as usize];
+    }
+}
+
 /// Holds information about the animation we programmatically create.
 pub struct AnimationInfo {
     /// The name of the animation target (in this case, the text).
@@ -33,6 +93,28 @@ pub struct AnimationInfo {
 impl AnimationInfo {
     pub const ANIMATION_DURATION: f32 = 1.0;
 
+    /// The resting rotation of a card (dealt with the given `rotation`) once it is flipped face-up.
+    ///
+    /// The card is flipped over its own width, so it keeps the skew it was dealt with instead of
+    /// snapping to the table's axes. A card that was dealt face-up keeps its rotation.
+    pub fn flipped_rotation(rotation: Quat) -> Quat {
+        Quat::from_rotation_y(Self::skew(rotation)) * Quat::from_axis_angle(Vec3::X, PI + FRAC_PI_2)
+    }
+
+    /// The resting rotation of a card (currently at the given `rotation`) once it is flipped back face-down
+    /// (see [`Settings::collect_face_down`]).
+    ///
+    /// Like [`Self::flipped_rotation`], the card keeps its skew.
+    pub fn face_down_rotation(rotation: Quat) -> Quat {
+        Quat::from_rotation_y(Self::skew(rotation)) * Quat::from_axis_angle(Vec3::X, FRAC_PI_2)
+    }
+
+    /// How far (in radians) the card's width is turned (about the vertical axis) from the table's X axis.
+    fn skew(rotation: Quat) -> f32 {
+        let width = rotation * Vec3::X;
+        (-width.z).atan2(width.x)
+    }
+
     /// Programmatically creates the UI animation.
     pub fn create(
         transform: &Transform,
@@ -59,7 +141,7 @@ impl AnimationInfo {
 
         let rotation_curve = EasingCurve::new(
             transform.rotation,
-            Quat::from_axis_angle(Vec3::X, PI + FRAC_PI_2),
+            Self::flipped_rotation(transform.rotation),
             EaseFunction::SmoothStepOut,
         )
         .reparametrize_linear(interval(0.5, Self::ANIMATION_DURATION).unwrap())
@@ -94,29 +176,53 @@ impl AnimationInfo {
 /// System that runs when a card's flip animation is finished.
 ///
 /// This function will replace the card's animation with a new one that stacks the picked card on the pile of collected cards.
+/// If [`Settings::collect_face_down`] is enabled, the card is also flipped face-down on its way to the pile.
 /// It also increments the [`CardsCollected`] resource, which is used to determine the position (Y axis) of the pile.
-/// Once all cards are collected, this function will trigger the [`GameState::Win`] state.
+/// With [`Settings::collect_toward_player`], the pile is beside the board on the side nearest the player
+/// (as seen from the camera, see [`PileConfig::toward_player`]).
+/// Once all cards are collected, this function will trigger the [`GameState::Win`] state
+/// (or re-deal in [`GameMode::Zen`]).
+///
+/// Each card is only collected once (see [`Collected`]), so a repeated event cannot count a card twice.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn collect_card(
     event: On<CollectingCard>,
-    query: Query<(
-        &Card,
-        &mut Transform,
-        &mut AnimationPlayer,
-        &AnimationTargetId,
-        &AnimatorNodeId,
-        &mut AnimationGraphHandle,
-    )>,
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &Card,
+            &mut Transform,
+            &mut AnimationPlayer,
+            &AnimationTargetId,
+            &AnimatorNodeId,
+            &mut AnimationGraphHandle,
+            Option<&PileSlot>,
+        ),
+        Without<Collected>,
+    >,
     mut animation_clips: ResMut<Assets<AnimationClip>>,
     mut cards_collected: ResMut<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+    mut suits_collected: ResMut<SuitsCollected>,
     mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut rounds_cleared: ResMut<RoundsCleared>,
+    mut stats: ResMut<LifetimeStats>,
+    round_clock: Res<RoundClock>,
+    timeline: Res<PickTimeline>,
+    camera: Single<&GlobalTransform, With<MainCamera>>,
 ) {
     for (
+        entity,
         card,
         transform,
         mut animation_player,
         animation_target_id,
         animation_node_id,
         mut animation_graph_handle,
+        pile_slot,
     ) in query
     {
         if card.rank == event.card.rank
@@ -124,35 +230,107 @@ pub fn collect_card(
             && !card.playable
             && card.face_up
         {
-            cards_collected.0 += 1;
+            commands.entity(entity).insert(Collected);
+            cards_collected.add(&cards_dealt);
+            suits_collected.add(card.suit);
+            stats.cards_collected += 1;
             info!("Collecting Card {}", card);
 
             let mut animation_clip = AnimationClip::default();
-            let collection_curve = EasingCurve::new(
-                transform.translation,
-                Transform::from_xyz(
-                    BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x,
-                    cards_collected.0 as f32 * CARD_THICKNESS,
-                    BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y,
-                )
-                .translation,
-                EaseFunction::SmootherStepOut,
-            )
-            .reparametrize_linear(interval(0.0, AnimationInfo::ANIMATION_DURATION).unwrap())
-            .expect("this curve has bounded domain, so this should never fail");
+            // decorative motion is skipped with reduced motion
+            let collect_path = if settings.reduced_motion {
+                CollectPath::Straight
+            } else {
+                settings.collect_path
+            };
+            if settings.card_trails && !settings.reduced_motion {
+                commands
+                    .entity(entity)
+                    .insert(Trail::new(settings.game_speed));
+            }
+            let pile = collect_pile(&settings, &camera);
+            let pile_top = pile_position_at(
+                &settings,
+                &pile,
+                pile_slot.map_or(cards_collected.0, |slot| slot.0),
+            );
+            let collection_curve = collect_path.curve(transform.translation, pile_top);
             animation_clip.add_curve_to_target(
                 *animation_target_id,
                 AnimatableCurve::new(animated_field!(Transform::translation), collection_curve),
             );
-            if cards_collected.0 >= 52 {
+            if transform.scale != Vec3::ONE {
+                // a card of a grid deal is smaller (see `grid_scale`), so it grows to fit the pile
+                let scale_curve =
+                    EasingCurve::new(transform.scale, Vec3::ONE, EaseFunction::SmoothStepOut)
+                        .reparametrize_linear(
+                            interval(0.0, AnimationInfo::ANIMATION_DURATION).unwrap(),
+                        )
+                        .expect("this curve has bounded domain, so this should never fail");
+                animation_clip.add_curve_to_target(
+                    *animation_target_id,
+                    AnimatableCurve::new(animated_field!(Transform::scale), scale_curve),
+                );
+            }
+            if settings.collect_face_down {
+                // Flip the card back over (over its own width, like the pick up flip) while it travels.
+                // The flip finishes early so the card is flat before it lands on the pile.
+                let flip_curve = EasingCurve::new(
+                    transform.rotation,
+                    AnimationInfo::face_down_rotation(transform.rotation),
+                    EaseFunction::SmoothStepOut,
+                )
+                .reparametrize_linear(
+                    interval(0.0, AnimationInfo::ANIMATION_DURATION * 0.6).unwrap(),
+                )
+                .expect("this curve has bounded domain, so this should never fail");
+                animation_clip.add_curve_to_target(
+                    *animation_target_id,
+                    AnimatableCurve::new(animated_field!(Transform::rotation), flip_curve),
+                );
+            }
+            // the final card has its own celebration
+            if cards_collected.0 < cards_dealt.0 && is_milestone(&settings, cards_collected.0) {
+                animation_clip.add_event_fn(
+                    AnimationInfo::ANIMATION_DURATION,
+                    move |commands, _entity, _time, _weight| {
+                        commands.trigger(Milestone { position: pile_top });
+                    },
+                );
+            }
+            if cards_collected.0 >= cards_dealt.0 {
                 info!("All cards collected!");
+                // a short game would not be a fair comparison with the full deck's best time
+                // (nor would a sorted, face-up board, or an endless zen round that is never won)
+                if cards_dealt.is_full_deck() && *mode != GameMode::Learn && *mode != GameMode::Zen
+                {
+                    stats.record_win(round_clock.0.elapsed_secs(), timeline.splits());
+                }
+                // in zen mode, there is no win; the board is cleared and re-dealt instead
+                let next_state = match *mode {
+                    GameMode::Classic
+                    | GameMode::Timed
+                    | GameMode::Foundation
+                    | GameMode::HotSeat
+                    | GameMode::Drill
+                    | GameMode::Learn => GameState::Win,
+                    GameMode::Zen => {
+                        rounds_cleared.0 += 1;
+                        GameState::Deal
+                    }
+                };
+                // the completed pile is held on screen for a moment before the win menu
+                let hold = FreezeFrame::duration(&settings);
                 animation_clip.add_event_fn(
                     AnimationInfo::ANIMATION_DURATION + 0.1,
-                    |commands, _entity, _time, _weight| {
-                        commands.set_state(GameState::Win);
+                    move |commands, _entity, _time, _weight| {
+                        if next_state == GameState::Win {
+                            FreezeFrame::start(commands, hold);
+                        } else {
+                            commands.fade_to_state(next_state);
+                        }
                     },
                 );
-                cards_collected.0 = 0;
             }
             let animation_clip_handle = animation_clips.add(animation_clip);
             let (animation_graph, new_node_index) =
@@ -162,25 +340,539 @@ pub fn collect_card(
             animation_graph_handle.0 = new_graph_handle;
             animation_player.stop(animation_node_id.0);
             animation_graphs.remove(old_handle.id());
-            animation_player.play(new_node_index);
+            // the clip's events are timed in the clip, so they stay in sync at any speed
+            animation_player
+                .play(new_node_index)
+                .set_speed(settings.game_speed.factor());
+            // a card is unique, so nothing else can match
+            break;
+        }
+    }
+}
+
+/// Where the collected cards are piled (the [`Settings::pile`], unless [`Settings::collect_toward_player`]
+/// is enabled), as seen from the given `camera`.
+pub fn collect_pile(settings: &Settings, camera: &GlobalTransform) -> PileConfig {
+    if settings.collect_toward_player {
+        // the bottom of the screen, as a direction on the table
+        let down = -(*camera.up() + *camera.forward());
+        PileConfig::toward_player(settings, down.xz())
+    } else {
+        settings.pile
+    }
+}
+
+/// Event that picks up a card (flips it face-up and then collects it).
+///
+/// This is triggered when a card is pressed, but can also be triggered programmatically.
+/// Cards that are not playable ignore this event. Cards that were dealt face-up are lifted (without flipping) and collected.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct PickUpCard {
+    pub entity: Entity,
+}
+
+/// How pressing a card picks up cards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PickupMode {
+    /// Only the pressed card is picked up.
+    #[default]
+    Single,
+    /// All playable cards of the pressed card's suit are picked up in a cascade.
+    Suit,
+}
+
+impl PickupMode {
+    /// The human readable name of the mode.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PickupMode::Single => "Single card",
+            PickupMode::Suit => "Whole suit",
         }
     }
 }
 
+/// The path a collected card takes to the pile.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollectPath {
+    /// Straight to the pile.
+    #[default]
+    Straight,
+    /// Up and over in a parabola (like a tossed card) before settling on the pile.
+    Arc,
+}
+
+impl CollectPath {
+    /// How high (in world units) an [`CollectPath::Arc`] rises above the straight path at its peak.
+    const ARC_HEIGHT: f32 = 120.0;
+
+    /// The human readable name of the path.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CollectPath::Straight => "Straight",
+            CollectPath::Arc => "Arc",
+        }
+    }
+
+    /// The curve that moves a card from `start` to `end` along this path.
+    fn curve(self, start: Vec3, end: Vec3) -> CollectCurve {
+        CollectCurve {
+            start,
+            end,
+            height: match self {
+                CollectPath::Straight => 0.0,
+                CollectPath::Arc => Self::ARC_HEIGHT,
+            },
+        }
+    }
+}
+
+/// The curve (over the [`AnimationInfo::ANIMATION_DURATION`]) of a [`CollectPath`].
+///
+/// The card slows down as it nears the pile either way. An arc adds a quadratic hump to the height,
+/// which peaks halfway along the (eased) path and is gone when the card lands.
+#[derive(Debug, Clone, Reflect)]
+struct CollectCurve {
+    start: Vec3,
+    end: Vec3,
+    /// How high the hump rises above the straight path; `0` for a straight path.
+    height: f32,
+}
+
+impl Curve<Vec3> for CollectCurve {
+    fn domain(&self) -> Interval {
+        interval(0.0, AnimationInfo::ANIMATION_DURATION).unwrap()
+    }
+
+    fn sample_unchecked(&self, t: f32) -> Vec3 {
+        let progress =
+            EaseFunction::SmootherStepOut.sample_clamped(t / AnimationInfo::ANIMATION_DURATION);
+        self.start.lerp(self.end, progress)
+            + Vec3::Y * self.height * 4.0 * progress * (1.0 - progress)
+    }
+}
+
+/// The cards that a press can pick up (see [`press_card`]).
+pub type PressableCards<'w, 's> =
+    Query<'w, 's, (Entity, &'static Card, &'static Transform), Without<DelayedPickup>>;
+
 /// System that runs when a card is pressed.
+///
+/// A touch press is deferred until the touch is released, so a long press can peek at the card
+/// instead (see [`TouchHold`]). With [`Settings::hold_to_read`], a face-down card is held to be read
+/// until the press is released (see [`ReadHold`]). Otherwise, the card is picked up (see [`press_card`]).
+/// Presses outside the board (see [`is_on_board`]) are ignored.
 pub fn pressed_card(
     entity_event: On<Pointer<Press>>,
-    mut query: Query<(&mut Card, &AnimatorNodeId, &mut AnimationPlayer)>,
+    mut commands: Commands,
+    mut stats: ResMut<LifetimeStats>,
+    settings: Res<Settings>,
+    cards: PressableCards,
 ) {
     let entity = entity_event.event_target();
-    if let Ok((mut card, animation_node_index, mut animation_player)) = query.get_mut(entity)
+    if entity_event
+        .hit
+        .position
+        .is_some_and(|position| !is_on_board(&settings, position))
+    {
+        return;
+    }
+    if entity_event.pointer_id.is_touch() {
+        if cards.get(entity).is_ok_and(|(_, card, _)| card.playable) {
+            commands.entity(entity).insert(TouchHold::new(&settings));
+        }
+        return;
+    }
+    if settings.hold_to_read
+        && let Ok((_, card, transform)) = cards.get(entity)
         && card.playable
         && !card.face_up
     {
+        commands.entity(entity).insert(ReadHold::new(*transform));
+        return;
+    }
+    press_card(&mut commands, &mut stats, &settings, &cards, entity);
+}
+
+/// Picks up the pressed card.
+///
+/// Every press counts as a click in the [`LifetimeStats`]. In [`PickupMode::Suit`], the rest of the
+/// playable cards of the same suit are picked up after the pressed card (see [`DelayedPickup`]).
+pub fn press_card(
+    commands: &mut Commands,
+    stats: &mut LifetimeStats,
+    settings: &Settings,
+    cards: &PressableCards,
+    entity: Entity,
+) {
+    stats.clicks += 1;
+    commands.trigger(PickUpCard { entity });
+    if settings.pickup_mode != PickupMode::Suit {
+        return;
+    }
+    let Ok((_, pressed, _)) = cards.get(entity) else {
+        return;
+    };
+    if !pressed.playable {
+        return;
+    }
+    let mut group = cards
+        .iter()
+        .filter(|(other, card, _)| *other != entity && card.playable && card.suit == pressed.suit)
+        .collect::<Vec<_>>();
+    // cascade from the top of the board's piles down
+    group.sort_by(|(_, _, a), (_, _, b)| b.translation.y.total_cmp(&a.translation.y));
+    info!("Picking up {} more {}", group.len(), pressed.suit);
+    for (i, (other, _, _)) in group.into_iter().enumerate() {
+        commands.entity(other).insert(DelayedPickup::nth(i + 1));
+    }
+}
+
+/// System that runs when a [`PickUpCard`] event is triggered.
+///
+/// If enabled in [`Settings`], this also spawns a [`CardTooltip`] displaying the card's name
+/// (and announces it, see [`Announcer`]).
+/// If this is the last card on the board, time is slowed down by [`Settings::final_slow_motion`]
+/// (see [`restore_time_speed`]).
+///
+/// In [`GameMode::Foundation`], a card that is not next in its suit's [`Foundations`] is not picked up;
+/// instead it shakes (see [`Shake`]) and a buzzer sounds.
+///
+/// With [`Settings::two_stage_pickup`], a face-down card is only flipped face-up in place (see [`Reveal`]);
+/// the tooltip and announcement happen then. Pressing the face-up card again picks it up.
+///
+/// In [`GameMode::HotSeat`], the card counts toward the [`CurrentPlayer`], and the turn passes to the other player.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn pick_up_card(
+    event: On<PickUpCard>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    sounds: Res<Sounds>,
+    mut foundations: ResMut<Foundations>,
+    mut announcer: ResMut<Announcer>,
+    mut timeline: ResMut<PickTimeline>,
+    mut pile_slots: ResMut<PileSlots>,
+    mut current_player: ResMut<CurrentPlayer>,
+    mut scores: ResMut<PlayerScores>,
+    round_clock: Res<RoundClock>,
+    mut query: Query<(
+        &mut Card,
+        &AnimatorNodeId,
+        &mut AnimationPlayer,
+        &Transform,
+        Has<DealFlight>,
+        Has<Shake>,
+        Has<Reveal>,
+    )>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let entity = event.event_target();
+    // the cards (other than this one) that are yet to be picked up
+    let remaining = query
+        .iter()
+        .filter(|(card, _, _, _, is_dealing, _, is_revealing)| {
+            card.playable || *is_dealing || *is_revealing
+        })
+        .count()
+        .saturating_sub(1);
+    if let Ok((mut card, animation_node_index, mut animation_player, transform, _, is_shaking, _)) =
+        query.get_mut(entity)
+        && card.playable
+    {
+        // in two stages, the first press only reveals the card (which must not be rejected yet)
+        let revealing = settings.two_stage_pickup && !card.face_up;
+        if *mode == GameMode::Foundation && !revealing {
+            if !foundations.accepts(&card) {
+                info!("Card {} is not next in its foundation", card.as_ref());
+                play_sfx(&mut commands, &settings, &sounds.reject);
+                if !settings.reduced_motion && !is_shaking {
+                    commands
+                        .entity(entity)
+                        .insert(Shake::new(transform.translation));
+                }
+                return;
+            }
+            foundations.place(&card);
+        }
+        if !card.face_up {
+            play_flip(&mut commands, &settings, &sounds);
+        }
+        // the card's name is shown when its face is first revealed
+        let reveals_name = revealing || !settings.two_stage_pickup;
+        if settings.card_tooltips && reveals_name {
+            CardTooltip::spawn(
+                &mut commands,
+                &asset_server,
+                entity,
+                card.as_ref(),
+                settings.game_speed,
+            );
+        }
+        if settings.announce_cards && reveals_name {
+            announcer.announce(format!("{:#}", *card));
+        }
+        if revealing {
+            info!("Revealing Card {}", card.as_ref());
+            card.playable = false;
+            card.face_up = true;
+            let mut reveal = Reveal::new(*transform, settings.game_speed);
+            if settings.reduced_motion {
+                reveal.finish();
+            }
+            commands.entity(entity).insert(reveal);
+            return;
+        }
+        if remaining == 0 && !settings.reduced_motion && settings.final_slow_motion < 1.0 {
+            info!("Slowing down time for the final pickup");
+            time.set_relative_speed(settings.final_slow_motion);
+        }
         card.playable = false;
         card.face_up = true;
         info!("Picking up Card {}", card.as_ref());
-        animation_player.play(animation_node_index.0);
+        timeline.record(&card, round_clock.0.elapsed_secs());
+        commands.entity(entity).insert(pile_slots.reserve());
+        if *mode == GameMode::HotSeat {
+            scores.0[current_player.0] += 1;
+            current_player.advance();
+        }
+        animation_player
+            .play(animation_node_index.0)
+            .set_speed(settings.game_speed.factor());
         card.set_changed();
     }
 }
+
+/// A marker for a card that was collected (counted in [`CardsCollected`]), which is never collected again.
+#[derive(Component, Debug)]
+pub struct Collected;
+
+/// A marker for a collected card that has landed on the pile.
+///
+/// Its animations are stopped, so the pile's (up to 52) cards are no longer evaluated every frame.
+#[derive(Component, Debug)]
+pub struct Settled;
+
+/// System that runs during [`GameState::Play`] to mark each collected card that finished its
+/// animations as [`Settled`].
+///
+/// The card keeps the transform its animation left it with.
+pub fn settle_collected_cards(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Card, &mut AnimationPlayer), Without<Settled>>,
+) {
+    for (entity, card, mut animation_player) in &mut query {
+        if card.playable
+            || animation_player.playing_animations().next().is_none()
+            || !animation_player.all_finished()
+        {
+            continue;
+        }
+        animation_player.stop_all();
+        commands.entity(entity).insert(Settled);
+    }
+}
+
+/// System that restores the normal speed of time (after the final pickup was slowed down)
+/// when exiting [`GameState::Play`].
+pub fn restore_time_speed(mut time: ResMut<Time<Virtual>>) {
+    time.set_relative_speed(1.0);
+}
+
+/// System that runs during [`GameState::Play`] to pick up the topmost (highest on the Y axis)
+/// playable face-down card when the [`KeyAction::QuickPick`] key (Space by default) is pressed.
+///
+/// This only happens if [`Settings::quick_pick`] is enabled.
+pub fn quick_pick(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    query: Query<(Entity, &Card, &Transform)>,
+) {
+    if !settings.quick_pick || !bindings.just_pressed(&keys, KeyAction::QuickPick) {
+        return;
+    }
+    let topmost = query
+        .iter()
+        .filter(|(_, card, _)| card.playable && !card.face_up)
+        .max_by(|(_, _, a), (_, _, b)| a.translation.y.total_cmp(&b.translation.y));
+    if let Some((entity, _, _)) = topmost {
+        commands.trigger(PickUpCard { entity });
+    }
+}
+
+/// System that runs during [`GameState::Play`] to pick up the playable card nearest the cursor
+/// when the [`KeyAction::PickNearest`] key (F by default) is pressed.
+///
+/// The distance is measured on the table (ignoring height) from where the cursor points at it,
+/// so the cursor does not need to be exactly over a card. This only happens if [`Settings::pick_nearest`] is enabled.
+pub fn pick_nearest(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    query: Query<(Entity, &Card, &Transform)>,
+) {
+    if !settings.pick_nearest || !bindings.just_pressed(&keys, KeyAction::PickNearest) {
+        return;
+    }
+    let (camera, camera_transform) = camera.into_inner();
+    let Some(point) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor).ok())
+        .and_then(|ray| {
+            ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y))
+                .map(|distance| ray.get_point(distance))
+        })
+    else {
+        return;
+    };
+    let nearest =
+        query
+            .iter()
+            .filter(|(_, card, _)| card.playable)
+            .min_by(|(_, _, a), (_, _, b)| {
+                let distance =
+                    |transform: &Transform| transform.translation.xz().distance_squared(point.xz());
+                distance(a).total_cmp(&distance(b))
+            });
+    if let Some((entity, _, _)) = nearest {
+        commands.trigger(PickUpCard { entity });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::animation::AnimationEventTrigger;
+
+    use super::*;
+    use crate::cards::{Rank, Suit};
+
+    /// Are the two rotations the same (within a small tolerance)?
+    fn same_rotation(a: Quat, b: Quat) -> bool {
+        [Vec3::X, Vec3::Y, Vec3::Z]
+            .into_iter()
+            .all(|axis| (a * axis).abs_diff_eq(b * axis, 1e-4))
+    }
+
+    /// The rotation of a card dealt face-down (or face-up), turned by `skew` radians.
+    fn dealt(skew: f32, face_up: bool) -> Quat {
+        let mut transform = Transform::default().looking_to(Dir3::Y, Dir3::Z);
+        if face_up {
+            transform.rotate_axis(Dir3::Z, PI);
+        }
+        transform.rotate_axis(Dir3::Y, skew);
+        transform.rotation
+    }
+
+    #[test]
+    fn face_down_card_flips_face_up() {
+        for skew in [0.0, 0.3, -1.0, 2.5] {
+            let face_down = dealt(skew, false);
+            // a face-down card's front (its local +Z axis) points down into the table
+            assert!((face_down * Vec3::Z).abs_diff_eq(Vec3::NEG_Y, 1e-4));
+            let flipped = AnimationInfo::flipped_rotation(face_down);
+            assert!((flipped * Vec3::Z).abs_diff_eq(Vec3::Y, 1e-4));
+            // flipped over its own width, which keeps the skew
+            assert!((flipped * Vec3::X).abs_diff_eq(face_down * Vec3::X, 1e-4));
+        }
+    }
+
+    #[test]
+    fn face_down_collect_keeps_the_skew() {
+        for skew in [0.0, 0.3, -1.0, 2.5] {
+            let face_up = AnimationInfo::flipped_rotation(dealt(skew, false));
+            let face_down = AnimationInfo::face_down_rotation(face_up);
+            assert!((face_down * Vec3::Z).abs_diff_eq(Vec3::NEG_Y, 1e-4));
+            assert!((face_down * Vec3::X).abs_diff_eq(face_up * Vec3::X, 1e-4));
+            assert!(same_rotation(face_down, dealt(skew, false)));
+        }
+    }
+
+    #[test]
+    fn flipping_twice_keeps_the_flipped_rotation() {
+        for skew in [0.0, 0.3, -1.0, 2.5] {
+            let flipped = AnimationInfo::flipped_rotation(dealt(skew, false));
+            assert!(same_rotation(
+                AnimationInfo::flipped_rotation(flipped),
+                flipped
+            ));
+            let face_up = dealt(skew, true);
+            assert!(same_rotation(
+                AnimationInfo::flipped_rotation(face_up),
+                face_up
+            ));
+        }
+    }
+
+    #[test]
+    fn collecting_twice_counts_once() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<AnimationClip>()
+            .init_asset::<AnimationGraph>()
+            .init_resource::<Settings>()
+            .init_resource::<GameMode>()
+            .init_resource::<Foundations>()
+            .init_resource::<Announcer>()
+            .init_resource::<PickTimeline>()
+            .init_resource::<PileSlots>()
+            .init_resource::<CurrentPlayer>()
+            .init_resource::<PlayerScores>()
+            .init_resource::<RoundClock>()
+            .init_resource::<CardsCollected>()
+            .init_resource::<SuitsCollected>()
+            .init_resource::<RoundsCleared>()
+            .init_resource::<LifetimeStats>()
+            .insert_resource(CardsDealt(2))
+            .insert_resource(Sounds {
+                shuffle: default(),
+                slide: default(),
+                last_card: default(),
+                reject: default(),
+                flip: Vec::new(),
+                click: default(),
+            })
+            .add_observer(pick_up_card)
+            .add_observer(collect_card);
+        let world = app.world_mut();
+        world.spawn((MainCamera, GlobalTransform::default()));
+        let card = Card {
+            rank: Rank::Ace,
+            suit: Suit::Spades,
+            face_up: true,
+            playable: true,
+        };
+        let entity = world
+            .spawn((
+                card,
+                Transform::default(),
+                AnimationPlayer::default(),
+                AnimationTargetId::from_name(&Name::new("card")),
+                AnimatorNodeId(AnimationNodeIndex::new(0)),
+                AnimationGraphHandle::default(),
+            ))
+            .id();
+
+        // a repeated press, and a collect event that fires twice (like when its clip is replayed)
+        world.trigger(PickUpCard { entity });
+        world.trigger(PickUpCard { entity });
+        let picked = *world.get::<Card>(entity).unwrap();
+        // queued like the animation system's events (so each is applied before the next)
+        for _ in 0..2 {
+            world.commands().trigger_with(
+                CollectingCard { card: picked },
+                AnimationEventTrigger { target: entity },
+            );
+        }
+        world.flush();
+
+        assert_eq!(world.resource::<CardsCollected>().0, 1);
+        assert_eq!(world.resource::<PileSlots>().0, 1);
+        assert_eq!(world.get::<PileSlot>(entity).unwrap().0, 1);
+        assert!(world.get::<Collected>(entity).is_some());
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::{audio::Volume, prelude::*};
+use rand::{RngExt, rng};
+
+use crate::{
+    settings::Settings,
+    state::{CardsCollected, CardsDealt},
+};
+
+/// How many variants of the flip sound there are (see [`Sounds::flip`]).
+pub const FLIP_VARIANTS: u8 = 4;
+
+/// Resource that holds the handles of all sound effects.
+#[derive(Resource, Debug)]
+pub struct Sounds {
+    /// Played when the deck is shuffled.
+    pub shuffle: Handle<AudioSource>,
+    /// Played as cards slide out of the deck.
+    pub slide: Handle<AudioSource>,
+    /// Played when only one card is left to collect.
+    pub last_card: Handle<AudioSource>,
+    /// Played when a card cannot be picked up (e.g. out of order in foundation mode).
+    pub reject: Handle<AudioSource>,
+    /// The variants of the sound played when a card is flipped face-up (see [`play_flip`]).
+    pub flip: Vec<Handle<AudioSource>>,
+    /// Played when a menu button is pressed (see [`Settings::button_sounds`]).
+    pub click: Handle<AudioSource>,
+}
+
+/// System that loads all sound effects at startup.
+pub fn load_sounds(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Sounds {
+        shuffle: asset_server.load("sounds/shuffle.wav"),
+        slide: asset_server.load("sounds/slide.wav"),
+        last_card: asset_server.load("sounds/chime.wav"),
+        reject: asset_server.load("sounds/buzz.wav"),
+        flip: (1..=FLIP_VARIANTS)
+            .map(|i| asset_server.load(format!("sounds/flip{i}.wav")))
+            .collect(),
+        click: asset_server.load("sounds/click.wav"),
+    });
+}
+
+/// Plays the given sound effect at the volume set in [`Settings::sfx_volume`].
+///
+/// Nothing is played if the sound effects are muted (volume is `0`).
+pub fn play_sfx(commands: &mut Commands, settings: &Settings, sound: &Handle<AudioSource>) {
+    if settings.sfx_volume <= 0.0 {
+        return;
+    }
+    commands.spawn((
+        AudioPlayer::new(sound.clone()),
+        PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.sfx_volume)),
+    ));
+}
+
+/// Plays one of the first [`Settings::flip_variants`] flip sounds (picked at random),
+/// with its pitch varied by up to [`Settings::flip_pitch_variation`].
+///
+/// Like [`play_sfx`], nothing is played if the sound effects are muted.
+pub fn play_flip(commands: &mut Commands, settings: &Settings, sounds: &Sounds) {
+    if settings.sfx_volume <= 0.0 {
+        return;
+    }
+    let mut rng = rng();
+    let count = settings.flip_variants.clamp(1, FLIP_VARIANTS) as usize;
+    let Some(sound) = sounds.flip.get(rng.random_range(0..count)) else {
+        return;
+    };
+    let variation = settings.flip_pitch_variation;
+    let speed = if variation > 0.0 {
+        1.0 + rng.random_range(-variation..=variation)
+    } else {
+        1.0
+    };
+    commands.spawn((
+        AudioPlayer::new(sound.clone()),
+        PlaybackSettings::DESPAWN
+            .with_volume(Volume::Linear(settings.sfx_volume))
+            .with_speed(speed),
+    ));
+}
+
+/// System that plays the shuffle sound when entering the [`GameState::Deal`](crate::state::GameState::Deal) state.
+pub fn play_shuffle(mut commands: Commands, settings: Res<Settings>, sounds: Res<Sounds>) {
+    play_sfx(&mut commands, &settings, &sounds.shuffle);
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to play a chime
+/// once only one card is left to collect.
+pub fn play_last_card_chime(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sounds: Res<Sounds>,
+    cards_collected: Res<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+) {
+    if cards_collected.is_changed() && cards_collected.0 + 1 == cards_dealt.0 {
+        play_sfx(&mut commands, &settings, &sounds.last_card);
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::{
+    animator::{PickUpCard, Settled},
+    cards::Card,
+    dealing::DealFlight,
+    mode::GameMode,
+    select::DelayedPickup,
+    settings::Settings,
+};
+
+/// Resource that paces the [`Settings::auto_collect`] pickups.
+///
+/// The timer is restarted (with the [`Settings::auto_collect_delay`]) while any card is moving,
+/// so the delay is counted from the end of the previous card's animation.
+#[derive(Resource, Debug, Default)]
+pub struct AutoCollect(Timer);
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to pick up the next card
+/// (if [`Settings::auto_collect`] is enabled) once the [`AutoCollect`] delay has elapsed.
+///
+/// The next card is the topmost playable card. In [`GameMode::Foundation`], it is the playable card
+/// of the lowest rank instead, which is always next in its foundation.
+/// Nothing is picked up while the cards are dealt, or while a card is being picked up (or queued to be).
+#[allow(clippy::type_complexity)]
+pub fn auto_collect(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    mut auto_collect: ResMut<AutoCollect>,
+    cards: Query<(
+        Entity,
+        &Card,
+        &Transform,
+        Has<Settled>,
+        Has<DealFlight>,
+        Has<DelayedPickup>,
+    )>,
+) {
+    if !settings.auto_collect {
+        return;
+    }
+    let busy = cards
+        .iter()
+        .any(|(_, card, _, is_settled, is_dealing, is_queued)| {
+            is_dealing || is_queued || (!card.playable && !is_settled)
+        });
+    if busy {
+        auto_collect.0 = Timer::from_seconds(settings.auto_collect_delay, TimerMode::Once);
+        return;
+    }
+    if !auto_collect.0.tick(time.delta()).is_finished() {
+        return;
+    }
+    let playable = cards.iter().filter(|(_, card, ..)| card.playable);
+    let next = if *mode == GameMode::Foundation {
+        playable.min_by_key(|(_, card, ..)| card.rank.as_u8())
+    } else {
+        playable.max_by(|(_, _, a, ..), (_, _, b, ..)| a.translation.y.total_cmp(&b.translation.y))
+    };
+    if let Some((entity, ..)) = next {
+        commands.trigger(PickUpCard { entity });
+    }
+}
@@ -0,0 +1,123 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use rand::{RngExt, rng};
+
+use crate::{
+    card_mesh::card_mesh,
+    cards::{BOARD_HALF_SIZE, CARD_HALF_SIZE, Card, CardBundle},
+    settings::Settings,
+    state::GameState,
+};
+
+/// A decorative face-down card that drifts behind a menu (see [`Settings::menu_cards`]).
+///
+/// These cards are not [`Card`] entities, so they can never be picked up.
+#[derive(Component, Debug)]
+pub struct DriftingCard {
+    /// The linear velocity (in world units per second) across the table.
+    velocity: Vec2,
+    /// The angular velocity (in radians per second) about the table's vertical axis.
+    spin: f32,
+}
+
+impl DriftingCard {
+    /// How many cards drift behind a menu.
+    const COUNT: usize = 12;
+    /// The maximum speed (in world units per second) of a drifting card.
+    const MAX_SPEED: f32 = 24.0;
+    /// The maximum angular speed (in radians per second) of a drifting card.
+    const MAX_SPIN: f32 = 0.3;
+    /// How high above the table the cards drift, so they never intersect it.
+    const HEIGHT: f32 = 1.0;
+    /// The area (centered on the board) that the cards drift within, wrapping around its edges.
+    const AREA_HALF_SIZE: Vec2 = Vec2 {
+        x: BOARD_HALF_SIZE.x + CARD_HALF_SIZE.y * 2.0,
+        y: BOARD_HALF_SIZE.y + CARD_HALF_SIZE.y * 2.0,
+    };
+}
+
+/// The background color of a menu that may have [`DriftingCard`]s behind it.
+///
+/// The background is partially transparent (if [`Settings::menu_cards`] is enabled),
+/// which keeps the cards subtle enough that the menu's text stays readable.
+pub fn menu_background(settings: &Settings) -> Color {
+    let background = settings.ui_theme.background();
+    if settings.menu_cards {
+        background.with_alpha(0.85)
+    } else {
+        background
+    }
+}
+
+/// System that spawns [`DriftingCard`]s (if enabled in [`Settings`]) when entering a menu state.
+///
+/// The cards are despawned when exiting that state.
+pub fn spawn_drifting_cards(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    state: Res<State<GameState>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    if !settings.menu_cards {
+        return;
+    }
+    let mesh = meshes.add(card_mesh(
+        CardBundle::MESH_HALF_SIZE,
+        settings.card_corner_radius,
+    ));
+    let material = materials.add(StandardMaterial {
+        base_color_texture: Some(asset_server.load(Card::back_resource_name())),
+        alpha_mode: AlphaMode::Mask(0.5),
+        ..default()
+    });
+    let mut rand_ng = rng();
+    let area = DriftingCard::AREA_HALF_SIZE;
+    for i in 0..DriftingCard::COUNT {
+        let position = Vec3::new(
+            rand_ng.random_range(-area.x..area.x),
+            // stack the cards so overlapping ones don't flicker
+            DriftingCard::HEIGHT + i as f32 * CardBundle::MESH_HALF_SIZE.z * 4.0,
+            rand_ng.random_range(-area.y..area.y),
+        );
+        let mut transform = Transform::from_translation(position).looking_to(Dir3::Y, Dir3::Z);
+        transform.rotate_axis(Dir3::Y, rand_ng.random_range(-PI..PI));
+        let drift = DriftingCard {
+            velocity: Vec2::from_angle(rand_ng.random_range(-PI..PI))
+                * rand_ng.random_range(DriftingCard::MAX_SPEED / 3.0..DriftingCard::MAX_SPEED),
+            spin: rand_ng.random_range(-DriftingCard::MAX_SPIN..DriftingCard::MAX_SPIN),
+        };
+        commands.spawn((
+            drift,
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            transform,
+            Pickable::IGNORE,
+            DespawnOnExit(*state.get()),
+        ));
+    }
+}
+
+/// System that moves each [`DriftingCard`], wrapping it around the edges of the drifting area.
+///
+/// The cards hold still if [`Settings::reduced_motion`] is enabled.
+pub fn drift_cards(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut query: Query<(&DriftingCard, &mut Transform)>,
+) {
+    if settings.reduced_motion {
+        return;
+    }
+    let area = DriftingCard::AREA_HALF_SIZE;
+    for (drift, mut transform) in &mut query {
+        let step = drift.velocity * time.delta_secs();
+        transform.translation.x =
+            (transform.translation.x + step.x + area.x).rem_euclid(area.x * 2.0) - area.x;
+        transform.translation.z =
+            (transform.translation.z + step.y + area.y).rem_euclid(area.y * 2.0) - area.y;
+        transform.rotate_axis(Dir3::Y, drift.spin * time.delta_secs());
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::{picking::hover::HoverMap, prelude::*};
+
+use crate::{
+    camera::MainCamera,
+    cards::{CARD_HALF_SIZE, Card},
+    dealing::DealFlight,
+    foundation::Shake,
+    settings::Settings,
+    touch::TouchHold,
+};
+
+/// A resting card that is tilted toward the camera (see [`Settings::billboard_cards`]).
+///
+/// The tilt is layered over the card's resting transform, which is restored once the tilt eases out.
+#[derive(Component, Debug)]
+pub struct Billboard {
+    /// The card's transform before it was tilted.
+    rest: Transform,
+    /// How much of the full tilt is applied (from `0` to `1`).
+    weight: f32,
+}
+
+impl Billboard {
+    /// How much of the way (from flat) a fully tilted card turns to face the camera.
+    const TILT_FRACTION: f32 = 0.5;
+    /// How quickly (per second) the tilt eases in and out.
+    const SMOOTHING: f32 = 8.0;
+}
+
+/// The resting cards that may be tilted toward the camera.
+///
+/// Cards that are flying in, shaking, or being peeked at are animated by other systems.
+type RestingCards<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Card,
+        &'static mut Transform,
+        &'static AnimationPlayer,
+        Option<&'static mut Billboard>,
+    ),
+    (Without<DealFlight>, Without<Shake>, Without<TouchHold>),
+>;
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to tilt face-up and
+/// hovered cards toward the camera, which makes them easier to read.
+///
+/// The tilt eases in and out (instantly if [`Settings::reduced_motion`] is enabled). Only playable cards
+/// are tilted; once a card is picked up, its flip animation takes over from its resting transform.
+pub fn billboard_cards(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    hover_map: Res<HoverMap>,
+    camera: Single<&GlobalTransform, With<MainCamera>>,
+    mut cards: RestingCards,
+) {
+    let camera_position = camera.translation();
+    let is_hovered = |entity: Entity| {
+        hover_map
+            .values()
+            .any(|hovered| hovered.contains_key(&entity))
+    };
+    let blend = if settings.reduced_motion {
+        1.0
+    } else {
+        1.0 - (-Billboard::SMOOTHING * time.delta_secs()).exp()
+    };
+    for (entity, card, mut transform, player, billboard) in &mut cards {
+        if !card.playable {
+            if let Some(billboard) = billboard {
+                if player.all_finished() {
+                    // the card was locked in place (not picked up), so put it back down
+                    *transform = billboard.rest;
+                }
+                commands.entity(entity).remove::<Billboard>();
+            }
+            continue;
+        }
+        let tilted = settings.billboard_cards && (card.face_up || is_hovered(entity));
+        let mut billboard = match billboard {
+            Some(billboard) => billboard,
+            None if tilted => {
+                commands.entity(entity).insert(Billboard {
+                    rest: *transform,
+                    weight: 0.0,
+                });
+                continue;
+            }
+            None => continue,
+        };
+        let target = if tilted { 1.0 } else { 0.0 };
+        billboard.weight += (target - billboard.weight) * blend;
+        if !tilted && billboard.weight < 0.01 {
+            *transform = billboard.rest;
+            commands.entity(entity).remove::<Billboard>();
+            continue;
+        }
+        let rest = billboard.rest;
+        let to_camera = (camera_position - rest.translation).normalize_or(Vec3::Y);
+        let angle = Vec3::Y.angle_between(to_camera) * Billboard::TILT_FRACTION * billboard.weight;
+        let tilt = Quat::IDENTITY.slerp(
+            Quat::from_rotation_arc(Vec3::Y, to_camera),
+            Billboard::TILT_FRACTION * billboard.weight,
+        );
+        transform.rotation = tilt * rest.rotation;
+        // lift the card so its lowered edge doesn't sink into the table (or the cards below)
+        transform.translation = rest.translation + Vec3::Y * CARD_HALF_SIZE.y * angle.sin();
+    }
+}
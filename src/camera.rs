@@ -0,0 +1,354 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    pbr::ScreenSpaceAmbientOcclusion,
+    prelude::*,
+    window::PrimaryWindow,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cards::{BOARD_HALF_SIZE, CARD_W},
+    settings::Settings,
+};
+
+/// The default distance between the camera and the table.
+pub const CAMERA_DISTANCE: f32 = 668.0;
+
+/// The half size of the area that must be visible, which includes the piles beside the board.
+pub const FRAME_HALF_SIZE: Vec2 = Vec2 {
+    x: BOARD_HALF_SIZE.x + CARD_W,
+    y: BOARD_HALF_SIZE.y,
+};
+
+/// How many pixels (scrolled with a touchpad) count as one line of the mouse wheel.
+const SCROLL_PIXELS_PER_LINE: f32 = 40.0;
+
+/// The extra room to leave around the [`FRAME_HALF_SIZE`] (as a scale factor).
+const FRAME_MARGIN: f32 = 1.025;
+
+/// How much GPU-heavy rendering is applied to the table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphicsQuality {
+    /// Only the basic lighting and shadows.
+    #[default]
+    Standard,
+    /// Adds soft contact shadows (screen space ambient occlusion) under and between the cards.
+    ///
+    /// This is not supported on the web, where it falls back to [`Self::Standard`].
+    High,
+}
+
+impl GraphicsQuality {
+    /// The human readable name of the quality level.
+    pub fn label(&self) -> &'static str {
+        match self {
+            GraphicsQuality::Standard => "Standard",
+            GraphicsQuality::High => "High",
+        }
+    }
+}
+
+/// A marker for the game's camera, which is controlled by the [`CameraController`].
+///
+/// Other cameras (like the offscreen camera of a [`BoardSnapshot`](crate::export::BoardSnapshot))
+/// do not have it, so systems that need the game's camera query for this marker.
+#[derive(Component, Debug)]
+pub struct MainCamera;
+
+/// Resource that describes where the camera is looking.
+///
+/// All camera controls (like edge panning) should manipulate this resource instead of the
+/// camera's [`Transform`], so they don't fight over the camera. The [`apply_camera`] system
+/// derives the camera's [`Transform`] from this resource.
+#[derive(Resource, Debug)]
+pub struct CameraController {
+    /// The point on the table that the camera is looking at.
+    pub target: Vec3,
+    /// The distance between the camera and its [`Self::target`].
+    pub distance: f32,
+    /// The camera's vertical field of view (in radians).
+    pub fov: f32,
+    /// How far (in radians) the camera is tilted from looking straight down,
+    /// as if the player was seated at the bottom edge of the table.
+    pub tilt: f32,
+    /// The current zoom, as a factor of the distance that fits the whole board in view.
+    pub zoom: f32,
+    /// The zoom that [`Self::zoom`] smoothly approaches (changed by scrolling the mouse wheel).
+    pub target_zoom: f32,
+    /// The closest the camera may get to its [`Self::target`].
+    pub min_distance: f32,
+    /// The farthest the camera may get from its [`Self::target`].
+    pub max_distance: f32,
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            target: Vec3::ZERO,
+            distance: CAMERA_DISTANCE,
+            fov: FRAC_PI_4,
+            tilt: 0.0,
+            zoom: 1.0,
+            target_zoom: 1.0,
+            min_distance: CAMERA_DISTANCE * Self::ZOOM_MIN,
+            max_distance: CAMERA_DISTANCE * Self::ZOOM_MAX,
+        }
+    }
+}
+
+impl CameraController {
+    /// How close (in logical pixels) the cursor must be to a window edge to start panning.
+    pub const EDGE_PAN_MARGIN: f32 = 24.0;
+    /// The closest zoom, as a factor of the distance that fits the whole board in view.
+    pub const ZOOM_MIN: f32 = 0.25;
+    /// The farthest zoom, as a factor of the distance that fits the whole board in view.
+    pub const ZOOM_MAX: f32 = 1.5;
+    /// How much each line scrolled with the mouse wheel changes the zoom.
+    const ZOOM_STEP: f32 = 0.1;
+    /// How quickly (per second) the zoom approaches the [`Self::target_zoom`].
+    const ZOOM_SMOOTHING: f32 = 12.0;
+
+    /// The distance at which the whole board fits in view for the given vertical `fov`
+    /// (in radians), `aspect_ratio` (width / height) of the viewport, and `tilt` (in radians),
+    /// where `frame_half_size` is the half size (on the table) of the area that must be visible.
+    ///
+    /// With a tilt, the near edge of the board is closer to the camera than the target, so it
+    /// is the near edge (not the far edge) that limits the framing.
+    pub fn fit_distance(fov: f32, aspect_ratio: f32, tilt: f32, frame_half_size: Vec2) -> f32 {
+        let half_height = (fov / 2.0).tan();
+        let half_width = half_height * aspect_ratio;
+        let (sin, cos) = tilt.sin_cos();
+        (frame_half_size.y * cos / half_height).max(frame_half_size.x / half_width) * FRAME_MARGIN
+            + frame_half_size.y * sin
+    }
+
+    /// Updates the zoom bounds for the given distance that fits the whole board in view,
+    /// and returns the (clamped) distance for the current [`Self::zoom`].
+    pub fn fit_bounds(&mut self, fit_distance: f32) -> f32 {
+        self.min_distance = fit_distance * Self::ZOOM_MIN;
+        self.max_distance = fit_distance * Self::ZOOM_MAX;
+        (fit_distance * self.zoom).clamp(self.min_distance, self.max_distance)
+    }
+
+    /// The zoom after scrolling the mouse wheel by `lines` from the given `zoom`,
+    /// clamped between [`Self::ZOOM_MIN`] and [`Self::ZOOM_MAX`].
+    ///
+    /// Scrolling up (away from the player) zooms in.
+    pub fn scrolled_zoom(zoom: f32, lines: f32) -> f32 {
+        (zoom - lines * Self::ZOOM_STEP).clamp(Self::ZOOM_MIN, Self::ZOOM_MAX)
+    }
+
+    /// Returns the camera's [`Projection`] as described by this controller.
+    pub fn projection(&self) -> Projection {
+        Projection::from(PerspectiveProjection {
+            fov: self.fov,
+            ..default()
+        })
+    }
+
+    /// Returns the camera's [`Transform`] as described by this controller.
+    pub fn transform(&self) -> Transform {
+        // the camera leans back toward the bottom edge of the table (-Z)
+        let (sin, cos) = self.tilt.sin_cos();
+        Transform::from_translation(self.target + Vec3::new(0.0, cos, -sin) * self.distance)
+            .looking_at(self.target, Vec3::Z)
+    }
+}
+
+/// System that updates the camera's [`Transform`] and [`Projection`] when the [`CameraController`] changes.
+pub fn apply_camera(
+    controller: Res<CameraController>,
+    camera: Single<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    if controller.is_changed() {
+        let (mut transform, mut projection) = camera.into_inner();
+        *transform = controller.transform();
+        if let Projection::Perspective(perspective) = projection.as_mut() {
+            perspective.fov = controller.fov;
+        }
+    }
+}
+
+/// System that frames the board, so it stays fully visible when the window is resized
+/// or the field of view (or tilt) in [`Settings`] changes.
+///
+/// The board's framing is scaled by the [`CameraController::zoom`] within the controller's bounds.
+pub fn frame_camera(
+    settings: Res<Settings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut controller: ResMut<CameraController>,
+) {
+    let size = window.size();
+    if size.x <= 0.0 || size.y <= 0.0 {
+        // the window is minimized
+        return;
+    }
+    let fov = settings.camera_fov.to_radians();
+    let tilt = settings.camera_tilt.to_radians();
+    // the camera stays put, but a turned board may need more room one way
+    let frame = settings.board_rotation.extents(FRAME_HALF_SIZE);
+    let fit_distance = CameraController::fit_distance(fov, size.x / size.y, tilt, frame);
+    let distance = controller
+        .bypass_change_detection()
+        .fit_bounds(fit_distance);
+    if controller.fov != fov || controller.tilt != tilt || controller.distance != distance {
+        controller.fov = fov;
+        controller.tilt = tilt;
+        controller.distance = distance;
+    }
+}
+
+/// System that re-centers (and un-zooms) the camera when a new game is dealt.
+pub fn reset_camera_pan(mut controller: ResMut<CameraController>) {
+    controller.target = Vec3::ZERO;
+    controller.zoom = 1.0;
+    controller.target_zoom = 1.0;
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to zoom the camera
+/// with the mouse wheel.
+///
+/// The zoom changes smoothly (or immediately if [`Settings::reduced_motion`] is enabled) and is clamped
+/// between [`CameraController::ZOOM_MIN`] and [`CameraController::ZOOM_MAX`].
+pub fn scroll_zoom(
+    settings: Res<Settings>,
+    time: Res<Time>,
+    mut wheel: MessageReader<MouseWheel>,
+    mut controller: ResMut<CameraController>,
+) {
+    let lines: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / SCROLL_PIXELS_PER_LINE,
+        })
+        .sum();
+    if lines != 0.0 {
+        controller.target_zoom = CameraController::scrolled_zoom(controller.target_zoom, lines);
+    }
+    if controller.zoom == controller.target_zoom {
+        return;
+    }
+    let blend = 1.0 - (-CameraController::ZOOM_SMOOTHING * time.delta_secs()).exp();
+    let zoom = controller.zoom.lerp(controller.target_zoom, blend);
+    controller.zoom = if settings.reduced_motion || (zoom - controller.target_zoom).abs() < 0.001 {
+        controller.target_zoom
+    } else {
+        zoom
+    };
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to pan the camera
+/// when the cursor nears an edge of the primary window.
+///
+/// Panning is clamped to the board's bounds and only happens if enabled in [`Settings`].
+pub fn edge_pan(
+    settings: Res<Settings>,
+    time: Res<Time>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<&Transform, With<MainCamera>>,
+    mut controller: ResMut<CameraController>,
+) {
+    if !settings.edge_pan {
+        return;
+    }
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let size = window.size();
+    let margin = CameraController::EDGE_PAN_MARGIN;
+    // The direction (in screen space) to pan; Y is positive toward the top of the screen.
+    let mut screen_dir = Vec2::ZERO;
+    if cursor.x < margin {
+        screen_dir.x -= 1.0;
+    } else if cursor.x > size.x - margin {
+        screen_dir.x += 1.0;
+    }
+    if cursor.y < margin {
+        screen_dir.y += 1.0;
+    } else if cursor.y > size.y - margin {
+        screen_dir.y -= 1.0;
+    }
+    if screen_dir == Vec2::ZERO {
+        return;
+    }
+
+    // Project the camera's right and up vectors onto the table to get the pan direction.
+    let right = camera.right().with_y(0.0).normalize_or_zero();
+    let up = camera.up().with_y(0.0).normalize_or_zero();
+    let delta = (right * screen_dir.x + up * screen_dir.y).normalize_or_zero()
+        * settings.edge_pan_speed
+        * time.delta_secs();
+    let target = controller.target + delta;
+    let bounds = settings.board_rotation.extents(BOARD_HALF_SIZE);
+    controller.target = target
+        .with_x(target.x.clamp(-bounds.x, bounds.x))
+        .with_z(target.z.clamp(-bounds.y, bounds.y));
+}
+
+/// System that applies the [`Settings::graphics_quality`] to the camera whenever the settings change.
+///
+/// The ambient occlusion only darkens the table's indirect lighting, so it does not affect picking.
+pub fn apply_graphics_quality(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    camera: Single<(Entity, Has<ScreenSpaceAmbientOcclusion>), With<MainCamera>>,
+) {
+    let (camera, has_ssao) = *camera;
+    // not supported by WebGL2 (nor WebGPU)
+    let high = settings.graphics_quality == GraphicsQuality::High && !cfg!(target_arch = "wasm32");
+    if high && !has_ssao {
+        commands.entity(camera).insert((
+            ScreenSpaceAmbientOcclusion {
+                // the cards are thin compared to the table's scale
+                constant_object_thickness: 4.0,
+                ..default()
+            },
+            // ambient occlusion does not support multisampling
+            Msaa::Off,
+        ));
+    } else if !high && has_ssao {
+        commands
+            .entity(camera)
+            .remove::<ScreenSpaceAmbientOcclusion>()
+            .insert(Msaa::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrolled_zoom_is_clamped() {
+        assert_eq!(
+            CameraController::scrolled_zoom(1.0, 100.0),
+            CameraController::ZOOM_MIN
+        );
+        assert_eq!(
+            CameraController::scrolled_zoom(1.0, -100.0),
+            CameraController::ZOOM_MAX
+        );
+        let zoomed_in = CameraController::scrolled_zoom(1.0, 1.0);
+        assert!(zoomed_in < 1.0 && zoomed_in > CameraController::ZOOM_MIN);
+    }
+
+    #[test]
+    fn fit_bounds_clamps_distance() {
+        let mut controller = CameraController {
+            zoom: 10.0,
+            ..default()
+        };
+        assert_eq!(
+            controller.fit_bounds(100.0),
+            100.0 * CameraController::ZOOM_MAX
+        );
+        controller.zoom = 0.0;
+        assert_eq!(
+            controller.fit_bounds(100.0),
+            100.0 * CameraController::ZOOM_MIN
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    mesh::{Indices, PrimitiveTopology, VertexAttributeValues},
+    prelude::*,
+};
+
+/// How many segments each rounded corner is made of.
+const CORNER_SEGMENTS: u32 = 8;
+
+/// Builds the mesh of a card with the given `half_size`.
+///
+/// If `corner_radius` is `0`, this is a plain [`Cuboid`]. Otherwise, this is an extruded rounded rectangle
+/// with the same UVs as a [`Cuboid`] on the front (+Z) and back (-Z) faces, so textures map the same way.
+pub fn card_mesh(half_size: Vec3, corner_radius: f32) -> Mesh {
+    let radius = corner_radius.min(half_size.x).min(half_size.y);
+    if radius <= 0.0 {
+        return Cuboid { half_size }.into();
+    }
+
+    // The outline (counter-clockwise when viewed from +Z), and the outward normal at each point.
+    let inner = half_size.truncate() - Vec2::splat(radius);
+    let corners = [
+        inner,
+        Vec2::new(-inner.x, inner.y),
+        -inner,
+        Vec2::new(inner.x, -inner.y),
+    ];
+    let mut outline = Vec::with_capacity(corners.len() * (CORNER_SEGMENTS as usize + 1));
+    for (i, center) in corners.into_iter().enumerate() {
+        for segment in 0..=CORNER_SEGMENTS {
+            let angle = FRAC_PI_2 * (i as f32 + segment as f32 / CORNER_SEGMENTS as f32);
+            let normal = Vec2::from_angle(angle);
+            outline.push((center + normal * radius, normal));
+        }
+    }
+
+    // the same UV mapping as a cuboid's front and back faces
+    let size = half_size.truncate() * 2.0;
+    let front_uv = |point: Vec2| ((point + half_size.truncate()) / size).to_array();
+    let back_uv = |point: Vec2| ((half_size.truncate() - point) / size).to_array();
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+    let ring = outline.len() as u32;
+
+    // The front and back faces are triangle fans around their center.
+    for (z, normal) in [(half_size.z, Vec3::Z), (-half_size.z, Vec3::NEG_Z)] {
+        let uv = |point| {
+            if z > 0.0 {
+                front_uv(point)
+            } else {
+                back_uv(point)
+            }
+        };
+        let center = positions.len() as u32;
+        positions.push([0.0, 0.0, z]);
+        normals.push(normal.to_array());
+        uvs.push(uv(Vec2::ZERO));
+        for (point, _) in &outline {
+            positions.push(point.extend(z).to_array());
+            normals.push(normal.to_array());
+            uvs.push(uv(*point));
+        }
+        for i in 0..ring {
+            let (a, b) = (center + 1 + i, center + 1 + (i + 1) % ring);
+            if z > 0.0 {
+                indices.extend([center, a, b]);
+            } else {
+                indices.extend([center, b, a]);
+            }
+        }
+    }
+
+    // The edge is a strip of quads around the outline.
+    let edge = positions.len() as u32;
+    for (point, normal) in &outline {
+        for z in [half_size.z, -half_size.z] {
+            positions.push(point.extend(z).to_array());
+            normals.push(normal.extend(0.0).to_array());
+            uvs.push(front_uv(*point));
+        }
+    }
+    for i in 0..ring {
+        let (front_a, back_a) = (edge + i * 2, edge + i * 2 + 1);
+        let next = (i + 1) % ring;
+        let (front_b, back_b) = (edge + next * 2, edge + next * 2 + 1);
+        indices.extend([back_a, back_b, front_b, back_a, front_b, front_a]);
+    }
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Marks the front (+Z) side of a [`card_mesh`] as the card's face, for a
+/// [`DoubleSidedCardMaterial`](crate::cards::DoubleSidedCardMaterial).
+///
+/// The face side's vertices get a second UV of `(1, 0)` (all others get `(0, 0)`), and their UVs are
+/// mirrored horizontally so the face reads the same as a separate face mesh turned over onto the card.
+pub fn double_sided(mut mesh: Mesh) -> Mesh {
+    let is_face = mesh
+        .attribute(Mesh::ATTRIBUTE_NORMAL)
+        .and_then(|normals| normals.as_float3())
+        .map(|normals| {
+            normals
+                .iter()
+                .map(|normal| normal[2] > 0.5)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if let Some(VertexAttributeValues::Float32x2(uvs)) = mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0) {
+        for (uv, _) in uvs.iter_mut().zip(&is_face).filter(|(_, face)| **face) {
+            uv[0] = 1.0 - uv[0];
+        }
+    }
+    let sides = is_face
+        .iter()
+        .map(|face| if *face { [1.0, 0.0] } else { [0.0, 0.0] })
+        .collect::<Vec<_>>();
+    mesh.with_inserted_attribute(Mesh::ATTRIBUTE_UV_1, sides)
+}
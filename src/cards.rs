@@ -1,12 +1,18 @@
 #![allow(dead_code)]
 use bevy::{
-    animation::AnimationTargetId, ecs::relationship::RelatedSpawnerCommands, prelude::*,
+    animation::AnimationTargetId,
+    ecs::relationship::RelatedSpawnerCommands,
+    pbr::{ExtendedMaterial, MaterialExtension},
+    prelude::*,
     render::render_resource::AsBindGroup,
+    shader::ShaderRef,
 };
-use rand::{rng, seq::SliceRandom};
+use rand::{Rng, RngExt, SeedableRng, rng, rngs::StdRng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
 use std::{
     f32::consts::PI,
     fmt::{self, Display},
+    str::FromStr,
 };
 
 use crate::animator::{AnimationInfo, AnimatorNodeId};
@@ -21,6 +27,65 @@ pub const CARD_HALF_SIZE: Vec2 = Vec2 {
 };
 pub const BOARD_HALF_SIZE: Vec2 = Vec2 { x: 354.0, y: 270.0 };
 
+/// Resource that holds the aspect ratio (width / height) of the card art, so the cards are not stretched.
+///
+/// The ratio is measured from a face image once it has loaded (see [`measure_card_aspect`]).
+/// Until then (or if it fails to load), the standard [`CARD_SIZE_RATIO`] is used.
+/// The cards keep the standard height ([`CARD_H`]); only their width follows the art.
+#[derive(Resource, Debug)]
+pub struct CardAspect {
+    /// The face image that is measured.
+    image: Handle<Image>,
+    /// The measured ratio, if the image has loaded.
+    measured: Option<f32>,
+}
+
+impl CardAspect {
+    /// The aspect ratio (width / height) of the card art.
+    pub fn ratio(&self) -> f32 {
+        self.measured.unwrap_or(CARD_SIZE_RATIO)
+    }
+
+    /// The half size of a card's mesh (see [`CardBundle::MESH_HALF_SIZE`]) with the width matching the art.
+    pub fn mesh_half_size(&self) -> Vec3 {
+        CardBundle::MESH_HALF_SIZE.with_x(CARD_HALF_SIZE.y * self.ratio())
+    }
+
+    /// Has the aspect ratio been measured yet?
+    pub fn is_measured(&self) -> bool {
+        self.measured.is_some()
+    }
+}
+
+/// System that starts loading the image measured for the [`CardAspect`] at startup.
+pub fn load_card_aspect(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let card = Card {
+        rank: Rank::Ace,
+        suit: Suit::Spades,
+        face_up: true,
+        playable: false,
+    };
+    commands.insert_resource(CardAspect {
+        image: asset_server.load(card.face_resource_name()),
+        measured: None,
+    });
+}
+
+/// System that measures the [`CardAspect`] once its image has loaded.
+pub fn measure_card_aspect(images: Res<Assets<Image>>, mut aspect: ResMut<CardAspect>) {
+    let Some(image) = images.get(&aspect.image) else {
+        return;
+    };
+    let size = image.size_f32();
+    if size.y > 0.0 {
+        aspect.measured = Some(size.x / size.y);
+        info!(
+            "Measured the card art's aspect ratio as {:.3}",
+            size.x / size.y
+        );
+    }
+}
+
 #[derive(Bundle)]
 pub struct CardBundle<M: Material> {
     pub card: Card,
@@ -35,25 +100,27 @@ pub struct CardBundle<M: Material> {
 }
 
 impl CardBundle<StandardMaterial> {
-    const CARD_MESH: Cuboid = Cuboid {
-        half_size: CARD_HALF_SIZE.extend(0.1),
-    };
+    /// The half size of the card's mesh.
+    pub const MESH_HALF_SIZE: Vec3 = CARD_HALF_SIZE.extend(0.1);
+
+    /// How much more depth bias each dealt card's back and face get than the card dealt before it.
+    ///
+    /// Cards are stacked only [`CARD_THICKNESS`] apart (each one higher than the last), so their big flat
+    /// faces can shimmer when seen from above. The bias (in units of the depth buffer's precision) favors
+    /// the card dealt later, which is also the higher one. Even for a full deck, the total bias is far less
+    /// than the depth of one [`CARD_THICKNESS`], so it never lifts a card above one that is really higher
+    /// (like on the pile, where the order is different).
+    pub const DEPTH_BIAS_STEP: f32 = 1.0;
+
+    /// Creates a card with the given `back_material` (see [`Self::back_material`]).
     pub fn new(
         card: Card,
-        asset_server: &AssetServer,
-        materials: &mut Assets<StandardMaterial>,
-        meshes: &mut Assets<Mesh>,
+        back_material: Handle<StandardMaterial>,
+        mesh: Handle<Mesh>,
         transform: Transform,
         animation_graphs: &mut Assets<AnimationGraph>,
         animation_clips: &mut Assets<AnimationClip>,
     ) -> Self {
-        let back_material = materials.add(StandardMaterial {
-            base_color_texture: Some(asset_server.load(Card::back_resource_name())),
-            alpha_mode: AlphaMode::Mask(0.5),
-            ..default()
-        });
-        let mesh = meshes.add(Self::CARD_MESH);
-
         let AnimationInfo {
             target_name: animation_target_name,
             target_id: animation_target_id,
@@ -78,22 +145,101 @@ impl CardBundle<StandardMaterial> {
         }
     }
 
+    /// Creates the material of a card's back with the given `back` image.
+    ///
+    /// Each card gets its own back material, so its back has the same `depth_bias`
+    /// (see [`Self::depth_bias`]) as its face.
+    pub fn back_material(
+        back: Handle<Image>,
+        depth_bias: f32,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        materials.add(StandardMaterial {
+            base_color_texture: Some(back),
+            alpha_mode: AlphaMode::Mask(0.5),
+            depth_bias,
+            ..default()
+        })
+    }
+
+    /// The depth bias of the `index`th dealt card (see [`Self::DEPTH_BIAS_STEP`]).
+    pub fn depth_bias(index: usize) -> f32 {
+        index as f32 * Self::DEPTH_BIAS_STEP
+    }
+
+    /// Creates the card's face (a child of the card).
+    ///
+    /// If `glossy`, the face gets a laminated sheen that catches the light as the card tilts.
+    /// The `depth_bias` (see [`Self::depth_bias`]) settles depth ties with the cards dealt before it.
     pub fn make_children(
         &self,
         asset_server: &Res<AssetServer>,
         materials: &mut ResMut<Assets<StandardMaterial>>,
-        meshes: &mut ResMut<Assets<Mesh>>,
+        glossy: bool,
+        depth_bias: f32,
     ) -> (Mesh3d, MeshMaterial3d<StandardMaterial>, Transform) {
-        let face_material = MeshMaterial3d(materials.add(StandardMaterial {
+        let mut face_material = StandardMaterial {
             base_color_texture: Some(asset_server.load(self.card.face_resource_name())),
             alpha_mode: AlphaMode::Mask(0.5),
+            depth_bias,
             ..default()
-        }));
-        let face_mesh = Mesh3d(meshes.add(Self::CARD_MESH));
+        };
+        if glossy {
+            apply_gloss(&mut face_material);
+        }
+        let face_material = MeshMaterial3d(materials.add(face_material));
+        let face_mesh = self.mesh.clone();
         let mut face_transform = Transform::from_rotation(Quat::from_axis_angle(Vec3::X, PI));
         face_transform.translation.z += 0.1;
         (face_mesh, face_material, face_transform)
     }
+
+    /// Creates the card's [`DoubleSidedCardMaterial`] (and the one shown while hovered),
+    /// which replaces both the back material and the face child (see [`Self::make_children`]).
+    ///
+    /// The card's mesh must be made with [`double_sided`](crate::card_mesh::double_sided).
+    /// If `glossy`, both sides get a laminated sheen. The `depth_bias` is the same as the face's
+    /// (see [`Self::make_children`]).
+    pub fn double_sided_materials(
+        &self,
+        asset_server: &AssetServer,
+        materials: &mut Assets<DoubleSidedCardMaterial>,
+        back: Handle<Image>,
+        hover_back: Handle<Image>,
+        glossy: bool,
+        depth_bias: f32,
+    ) -> (
+        Handle<DoubleSidedCardMaterial>,
+        Handle<DoubleSidedCardMaterial>,
+    ) {
+        let face = CardFace {
+            texture: asset_server.load(self.card.face_resource_name()),
+        };
+        let mut material = |back| {
+            let mut base = StandardMaterial {
+                base_color_texture: Some(back),
+                alpha_mode: AlphaMode::Mask(0.5),
+                depth_bias,
+                ..default()
+            };
+            if glossy {
+                apply_gloss(&mut base);
+            }
+            materials.add(ExtendedMaterial {
+                base,
+                extension: face.clone(),
+            })
+        };
+        (material(back), material(hover_back))
+    }
+}
+
+/// Gives a card's material a laminated sheen that catches the light as the card tilts.
+fn apply_gloss(material: &mut StandardMaterial) {
+    // a thin clear coat keeps the art's colors (unlike a lower roughness on the base layer)
+    material.perceptual_roughness = 0.4;
+    material.clearcoat = 0.6;
+    material.clearcoat_perceptual_roughness = 0.25;
 }
 
 #[derive(Component, Debug, Clone, Copy)]
@@ -109,27 +255,38 @@ impl Card {
         node.spawn(self);
     }
 
+    /// The asset path of the card's face image (e.g. `images/Clubs/3c.png`).
+    ///
+    /// Asset paths are case-sensitive on some platforms (and the web), so the casing here must match
+    /// the files in `assets/images/` exactly: see [`Suit::folder_name`] and [`Suit::file_letter`].
     pub fn face_resource_name(&self) -> String {
-        let suit_str = match self.suit {
-            Suit::Clubs => "Clubs",
-            Suit::Diamonds => "Diamonds",
-            Suit::Hearts => "Hearts",
-            Suit::Spades => "Spades",
-        };
         format!(
-            "images/{suit_str}/{}{}.png",
+            "images/{}/{}{}.png",
+            self.suit.folder_name(),
             self.rank.as_u8(),
-            match self.suit {
-                Suit::Clubs => "c",
-                Suit::Diamonds => "d",
-                Suit::Hearts => "h",
-                Suit::Spades => "s",
-            }
+            self.suit.file_letter()
         )
     }
 
     pub fn back_resource_name() -> String {
-        "images/Back Blue.png".to_string()
+        Self::back_resource_names()[0].to_string()
+    }
+
+    /// The asset paths of all card backs. The first is the usual back, and the second is shown while hovered.
+    pub fn back_resource_names() -> [&'static str; 2] {
+        ["images/Back Blue.png", "images/Back Red.png"]
+    }
+
+    /// The index (in [`Self::back_resource_names`]) of this card's back in a mixed deck
+    /// (see [`Settings::mixed_backs`](crate::settings::Settings::mixed_backs)).
+    ///
+    /// The choice only depends on the `seed` and the card's rank and suit, so it is the same throughout a round.
+    pub fn mixed_back(&self, seed: u64) -> usize {
+        let identity =
+            self.suit.as_u8() as u64 * Rank::list().len() as u64 + self.rank.as_u8() as u64;
+        let mut rand_ng =
+            StdRng::seed_from_u64(seed ^ identity.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        rand_ng.random_range(0..Self::back_resource_names().len())
     }
 
     pub fn resource_name(&self) -> String {
@@ -144,11 +301,25 @@ impl Card {
         // Can stack if the other card is one rank lower and of opposite color
         self.rank.as_u8() + 1 == other.rank.as_u8() && self.suit.is_red() != other.suit.is_red()
     }
+
+    /// Can this card be placed on a foundation (of the same suit) topped by the given rank?
+    ///
+    /// An empty foundation (`None`) only accepts an Ace.
+    pub fn can_place_on(&self, top: Option<Rank>) -> bool {
+        self.rank.as_u8() == top.map_or(0, |rank| rank.as_u8()) + 1
+    }
 }
 
 impl Display for Card {
+    /// Formats the card in short form (e.g. `K-spades`).
+    ///
+    /// The alternate form (`{:#}`) is the card's full name (e.g. `King of Spades`).
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}-{}", self.rank, self.suit)
+        if f.alternate() {
+            write!(f, "{} of {}", self.rank.name(), self.suit.name())
+        } else {
+            write!(f, "{}-{}", self.rank, self.suit)
+        }
     }
 }
 
@@ -188,6 +359,25 @@ impl Rank {
         }
     }
 
+    /// The full name of the rank (e.g. `King`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rank::Ace => "Ace",
+            Rank::Two => "Two",
+            Rank::Three => "Three",
+            Rank::Four => "Four",
+            Rank::Five => "Five",
+            Rank::Six => "Six",
+            Rank::Seven => "Seven",
+            Rank::Eight => "Eight",
+            Rank::Nine => "Nine",
+            Rank::Ten => "Ten",
+            Rank::Jack => "Jack",
+            Rank::Queen => "Queen",
+            Rank::King => "King",
+        }
+    }
+
     pub fn list() -> [Rank; 13] {
         [
             Rank::Ace,
@@ -228,6 +418,18 @@ impl fmt::Display for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = String;
+
+    /// Parses a rank from its short form (e.g. `K` or `10`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rank::list()
+            .into_iter()
+            .find(|rank| rank.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("unknown rank `{s}`"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Suit {
     Clubs,
@@ -254,6 +456,43 @@ impl Suit {
         }
     }
 
+    /// The full (capitalized) name of the suit (e.g. `Spades`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Suit::Clubs => "Clubs",
+            Suit::Diamonds => "Diamonds",
+            Suit::Hearts => "Hearts",
+            Suit::Spades => "Spades",
+        }
+    }
+
+    /// The name of the folder (in `assets/images/`) that holds the suit's face images.
+    ///
+    /// This is the capitalized [`Suit::name`] (e.g. `Spades`).
+    pub fn folder_name(&self) -> &'static str {
+        self.name()
+    }
+
+    /// The lowercase letter that ends the file names of the suit's face images (e.g. `s` in `12s.png`).
+    pub fn file_letter(&self) -> char {
+        match self {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        }
+    }
+
+    /// The suit's symbol in the nerd font (Material Design icons).
+    pub fn glyph(&self) -> char {
+        match self {
+            Suit::Clubs => '\u{F08CE}',
+            Suit::Diamonds => '\u{F08CF}',
+            Suit::Hearts => '\u{F08D0}',
+            Suit::Spades => '\u{F08D1}',
+        }
+    }
+
     pub fn list() -> [Suit; 4] {
         [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
     }
@@ -271,7 +510,41 @@ impl fmt::Display for Suit {
     }
 }
 
+impl FromStr for Suit {
+    type Err = String;
+
+    /// Parses a suit from its name or its initial (e.g. `spades` or `s`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Suit::list()
+            .into_iter()
+            .find(|suit| {
+                let name = suit.name();
+                name.eq_ignore_ascii_case(s) || name[..1].eq_ignore_ascii_case(s)
+            })
+            .ok_or_else(|| format!("unknown suit `{s}`"))
+    }
+}
+
 pub fn shuffle_deck() -> Vec<Card> {
+    shuffle_deck_seeded(rng().random())
+}
+
+/// Returns a shuffled deck, which is reproducible for a given `seed`.
+pub fn shuffle_deck_seeded(seed: u64) -> Vec<Card> {
+    shuffle_deck_with(&mut StdRng::seed_from_u64(seed))
+}
+
+/// Returns a deck shuffled by the given random number generator.
+///
+/// The same generator state always gives the same order, so a seeded generator reproduces a deck.
+pub fn shuffle_deck_with<R: Rng + ?Sized>(rand_ng: &mut R) -> Vec<Card> {
+    let mut deck = sorted_deck();
+    deck.shuffle(rand_ng);
+    deck
+}
+
+/// Returns an unshuffled deck, sorted by suit (in [`Suit::list`] order) and then by rank (Ace first).
+pub fn sorted_deck() -> Vec<Card> {
     let mut deck = Vec::with_capacity(52);
     for &suit in Suit::list().iter() {
         for &rank in Rank::list().iter() {
@@ -283,19 +556,141 @@ pub fn shuffle_deck() -> Vec<Card> {
             });
         }
     }
-    let mut rand_ng = rng();
-    deck.shuffle(&mut rand_ng);
     deck
 }
 
+/// A material for a card's back with a holographic foil effect (see [`CardBack::Foil`]).
+///
+/// The foil's color shifts with the angle between the card and the camera.
 #[derive(Debug, AsBindGroup, Clone, Asset, TypePath)]
 pub struct CardMaterial {
+    /// The card's back texture.
     #[texture(0)]
+    #[sampler(1)]
     pub texture: Handle<Image>,
-    #[uniform(1)]
+    /// Tints the [`Self::texture`].
+    #[uniform(2)]
     pub color: LinearRgba,
-    #[texture(2)]
-    pub face_texture: Handle<Image>,
+    /// How strongly the foil effect is applied (from `0` to `1`).
+    #[uniform(2)]
+    pub foil_intensity: f32,
+    /// The card's depth bias (see [`CardBundle::depth_bias`]).
+    pub depth_bias: f32,
 }
 
-impl Material for CardMaterial {}
+impl Material for CardMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/card_foil.wgsl".into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode {
+        AlphaMode::Mask(0.5)
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+}
+
+/// A material extension that shows a card's face on the front side of a
+/// [`double_sided`](crate::card_mesh::double_sided) card mesh.
+///
+/// The base [`StandardMaterial`] holds the card's back texture, so the whole card is drawn in one mesh.
+#[derive(Debug, AsBindGroup, Clone, Asset, TypePath)]
+pub struct CardFace {
+    /// The card's face texture.
+    #[texture(100)]
+    #[sampler(101)]
+    pub texture: Handle<Image>,
+}
+
+impl MaterialExtension for CardFace {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/card_double_sided.wgsl".into()
+    }
+}
+
+/// A card's material that draws both its back and its face (see [`CardFace`]).
+pub type DoubleSidedCardMaterial = ExtendedMaterial<StandardMaterial, CardFace>;
+
+/// The style of the cards' backs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CardBack {
+    /// The plain back texture.
+    #[default]
+    Plain,
+    /// The back texture with a holographic foil effect (see [`CardMaterial`]).
+    Foil,
+    /// The back texture with a band of light that sweeps across it
+    /// (see [`ShimmerBack`](crate::shimmer::ShimmerBack)).
+    Shimmer,
+}
+
+impl CardBack {
+    /// The human readable name of the style.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardBack::Plain => "Plain",
+            CardBack::Foil => "Foil",
+            CardBack::Shimmer => "Shimmer",
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) style.
+    pub fn cycle(&mut self, step: i8) {
+        let list = [CardBack::Plain, CardBack::Foil, CardBack::Shimmer];
+        let index = list.iter().position(|back| back == self).unwrap_or(0);
+        *self = list[(index as isize + step as isize).rem_euclid(list.len() as isize) as usize];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn face_resource_name_matches_casing() {
+        let card = Card {
+            rank: Rank::Three,
+            suit: Suit::Clubs,
+            face_up: false,
+            playable: false,
+        };
+        assert_eq!(card.face_resource_name(), "images/Clubs/3c.png");
+    }
+
+    #[test]
+    fn every_face_resource_exists() {
+        let assets = Path::new(env!("CARGO_MANIFEST_DIR")).join("assets");
+        for card in sorted_deck() {
+            let path = card.face_resource_name();
+            assert!(assets.join(&path).is_file(), "{path} is missing");
+        }
+    }
+
+    /// The rank and suit of each card, which identify it.
+    fn identities(deck: &[Card]) -> Vec<(u8, u8)> {
+        deck.iter()
+            .map(|card| (card.suit.as_u8(), card.rank.as_u8()))
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_shuffles_same_order() {
+        let a = shuffle_deck_with(&mut StdRng::seed_from_u64(42));
+        let b = shuffle_deck_with(&mut StdRng::seed_from_u64(42));
+        assert_eq!(identities(&a), identities(&b));
+    }
+
+    #[test]
+    fn shuffle_is_permutation_of_deck() {
+        let mut shuffled = identities(&shuffle_deck_with(&mut StdRng::seed_from_u64(7)));
+        shuffled.sort();
+        let mut sorted = identities(&sorted_deck());
+        sorted.sort();
+        assert_eq!(shuffled.len(), 52);
+        assert_eq!(shuffled, sorted);
+    }
+}
@@ -0,0 +1,111 @@
+//! Developer shortcuts for testing the game's flow quickly.
+//!
+//! These are not meant for players, so they only work in debug builds.
+
+use bevy::prelude::*;
+
+use crate::{
+    animator::{AnimationInfo, Collected, Settled, collect_pile},
+    camera::MainCamera,
+    cards::Card,
+    controls::{KeyAction, KeyBindings},
+    dealing::DealFlight,
+    foundation::Shake,
+    freeze::FreezeFrame,
+    heatmap::PickTimeline,
+    hotseat::{CurrentPlayer, PlayerScores},
+    layout::pile_position_at,
+    mode::GameMode,
+    reveal::Reveal,
+    select::DelayedPickup,
+    settings::Settings,
+    state::{CardsCollected, CardsDealt, PileSlot, PileSlots, SuitsCollected},
+    stats::RoundClock,
+};
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to win the round at once
+/// when the [`KeyAction::InstantWin`] key (F9 by default) is pressed.
+///
+/// This is a developer shortcut for testing the win screen (and the [`FreezeFrame`] before it)
+/// without collecting every card by hand, so it only works in debug builds.
+/// Each remaining card is snapped onto the pile and counted as if it was just picked up,
+/// so the round's resources (like the [`PickTimeline`] and [`PlayerScores`]) describe a finished round.
+/// The win is not recorded in the lifetime statistics.
+///
+/// Nothing happens while the cards are being dealt.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn instant_win(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    camera: Single<&GlobalTransform, With<MainCamera>>,
+    mut cards: Query<
+        (
+            Entity,
+            &mut Card,
+            &mut Transform,
+            &mut AnimationPlayer,
+            Option<&PileSlot>,
+            Has<DealFlight>,
+        ),
+        Without<Collected>,
+    >,
+    // grouped to stay within the number of parameters a system may have
+    (mut cards_collected, cards_dealt, mut suits_collected, mut pile_slots): (
+        ResMut<CardsCollected>,
+        Res<CardsDealt>,
+        ResMut<SuitsCollected>,
+        ResMut<PileSlots>,
+    ),
+    (mut timeline, round_clock, mut current_player, mut scores): (
+        ResMut<PickTimeline>,
+        Res<RoundClock>,
+        ResMut<CurrentPlayer>,
+        ResMut<PlayerScores>,
+    ),
+) {
+    if !cfg!(debug_assertions)
+        || !bindings.just_pressed(&keys, KeyAction::InstantWin)
+        || cards_collected.0 >= cards_dealt.0
+        || cards.iter().any(|(.., is_dealing)| is_dealing)
+    {
+        return;
+    }
+    info!("Winning instantly (a developer shortcut)");
+    let pile = collect_pile(&settings, &camera);
+    for (entity, mut card, mut transform, mut animation_player, pile_slot, _) in &mut cards {
+        // cards that were already picked up are on their way to their reserved places
+        let slot = match pile_slot {
+            Some(slot) => *slot,
+            None => {
+                timeline.record(&card, round_clock.0.elapsed_secs());
+                if *mode == GameMode::HotSeat {
+                    scores.0[current_player.0] += 1;
+                    current_player.advance();
+                }
+                pile_slots.reserve()
+            }
+        };
+        card.playable = false;
+        card.face_up = true;
+        suits_collected.add(card.suit);
+        // the animations (and their events) would collect the card again
+        animation_player.stop_all();
+        transform.translation = pile_position_at(&settings, &pile, slot.0);
+        // a card of a grid deal is smaller (see [`grid_scale`](crate::layout::grid_scale))
+        transform.scale = Vec3::ONE;
+        transform.rotation = if settings.collect_face_down {
+            AnimationInfo::face_down_rotation(transform.rotation)
+        } else {
+            AnimationInfo::flipped_rotation(transform.rotation)
+        };
+        commands
+            .entity(entity)
+            .remove::<(Reveal, DelayedPickup, Shake)>()
+            .insert((slot, Collected, Settled));
+    }
+    cards_collected.0 = cards_dealt.0;
+    FreezeFrame::start(&mut commands, FreezeFrame::duration(&settings));
+}
@@ -0,0 +1,29 @@
+//! A thin wrapper around the system clipboard.
+//!
+//! The clipboard is not accessible (synchronously) on the web, so these functions always fail there.
+
+/// Copies the given `text` to the system clipboard.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn set_text(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Gets the text currently in the system clipboard.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn get_text() -> Result<String, String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn set_text(_text: &str) -> Result<(), String> {
+    Err("clipboard is unavailable on the web".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn get_text() -> Result<String, String> {
+    Err("clipboard is unavailable on the web".to_string())
+}
@@ -0,0 +1,240 @@
+use std::str::FromStr;
+
+use bevy::{
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+
+use crate::{
+    animator::PickUpCard,
+    cards::{Card, Rank, Suit},
+    controls::{KeyAction, KeyBindings},
+    seed::NextSeed,
+    state::{CardsCollected, GameState},
+    toast::Toast,
+};
+
+/// Resource that exists while the debug console is open. It holds the command being typed.
+///
+/// Other keyboard shortcuts should not run while this resource exists.
+#[derive(Resource, Debug, Default)]
+pub struct DebugConsole {
+    input: String,
+}
+
+/// A marker for the root node of the debug console's overlay.
+#[derive(Component, Debug)]
+pub struct ConsoleOverlay;
+
+/// A marker for the text that displays the command being typed.
+#[derive(Component, Debug)]
+pub struct ConsoleInputText;
+
+/// The commands accepted by the [`DebugConsole`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleCommand {
+    /// Deal a new game.
+    Deal,
+    /// Skip to the [`GameState::Win`] screen.
+    Win,
+    /// Deal a new game with the given seed.
+    Seed(u64),
+    /// Pick up the given card (if it is on the board).
+    Collect(Rank, Suit),
+    /// Abandon the current game and return to the main menu.
+    Reset,
+}
+
+impl ConsoleCommand {
+    /// A summary of the accepted commands.
+    const USAGE: &str = "deal | win | seed <n> | collect <rank><suit> | reset";
+}
+
+impl FromStr for ConsoleCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+        let command = words.next().unwrap_or_default().to_lowercase();
+        let argument = words.next();
+        if words.next().is_some() {
+            return Err(format!("too many arguments for `{command}`"));
+        }
+        match (command.as_str(), argument) {
+            ("deal", None) => Ok(Self::Deal),
+            ("win", None) => Ok(Self::Win),
+            ("reset", None) => Ok(Self::Reset),
+            ("seed", Some(seed)) => seed
+                .parse()
+                .map(Self::Seed)
+                .map_err(|_| format!("`{seed}` is not a valid seed")),
+            ("collect", Some(card)) => {
+                // the suit is the last character, e.g. `10h` or `Ks`
+                let split = card
+                    .char_indices()
+                    .last()
+                    .map(|(i, _)| i)
+                    .unwrap_or_default();
+                let (rank, suit) = card.split_at(split);
+                Ok(Self::Collect(rank.parse()?, suit.parse()?))
+            }
+            ("seed" | "collect", None) => Err(format!("`{command}` needs an argument")),
+            ("deal" | "win" | "reset", Some(_)) => {
+                Err(format!("`{command}` does not take an argument"))
+            }
+            _ => Err(format!("unknown command `{command}`")),
+        }
+    }
+}
+
+/// System that opens (or closes) the [`DebugConsole`] when the [`KeyAction::ToggleConsole`] key
+/// (backtick by default) is pressed.
+///
+/// The console can also be closed with Escape. It is only available in debug builds.
+pub fn toggle_console(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    console: Option<Res<DebugConsole>>,
+    overlay: Query<Entity, With<ConsoleOverlay>>,
+) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let close = console.is_some() && keys.just_pressed(KeyCode::Escape);
+    if !bindings.just_pressed(&keys, KeyAction::ToggleConsole) && !close {
+        return;
+    }
+    if console.is_some() {
+        commands.remove_resource::<DebugConsole>();
+        for entity in overlay {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    commands.init_resource::<DebugConsole>();
+    let font = TextFont {
+        font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+        font_size: 18.0,
+        ..default()
+    };
+    commands.spawn((
+        ConsoleOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.85)),
+        GlobalZIndex(20),
+        children![
+            (
+                Text::new(ConsoleCommand::USAGE),
+                TextColor(Color::WHITE.with_alpha(0.6)),
+                font.clone(),
+            ),
+            (
+                ConsoleInputText,
+                Text::new("> _"),
+                TextColor(Color::WHITE),
+                font,
+            ),
+        ],
+    ));
+}
+
+/// System that types into the [`DebugConsole`] and runs the typed [`ConsoleCommand`] when Enter is pressed.
+#[allow(clippy::too_many_arguments)]
+pub fn console_input(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut console: ResMut<DebugConsole>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut next_seed: ResMut<NextSeed>,
+    mut cards_collected: ResMut<CardsCollected>,
+    cards: Query<(Entity, &Card)>,
+) {
+    for input in keyboard.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+        match &input.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                if line.trim().is_empty() {
+                    continue;
+                }
+                info!("Console: {line}");
+                let command = match line.parse::<ConsoleCommand>() {
+                    Ok(command) => command,
+                    Err(e) => {
+                        Toast::spawn(&mut commands, &asset_server, e);
+                        continue;
+                    }
+                };
+                match command {
+                    ConsoleCommand::Deal => game_state.set(GameState::Deal),
+                    ConsoleCommand::Win => game_state.set(GameState::Win),
+                    ConsoleCommand::Seed(seed) => {
+                        next_seed.0 = Some(seed);
+                        game_state.set(GameState::Deal);
+                    }
+                    ConsoleCommand::Collect(rank, suit) => {
+                        let found = cards.iter().find(|(_, card)| {
+                            card.rank == rank && card.suit == suit && card.playable
+                        });
+                        match found {
+                            Some((entity, _)) => commands.trigger(PickUpCard { entity }),
+                            None => {
+                                let card = Card {
+                                    rank,
+                                    suit,
+                                    face_up: false,
+                                    playable: false,
+                                };
+                                let message = format!("The {card:#} is not on the board");
+                                Toast::spawn(&mut commands, &asset_server, message);
+                            }
+                        }
+                    }
+                    ConsoleCommand::Reset => {
+                        cards_collected.0 = 0;
+                        next_seed.0 = None;
+                        game_state.set(GameState::Menu);
+                    }
+                }
+            }
+            Key::Backspace => {
+                console.input.pop();
+            }
+            _ => {
+                if let Some(text) = &input.text {
+                    // ignore the backtick that toggles the console
+                    console
+                        .input
+                        .extend(text.chars().filter(|c| *c != '`' && !c.is_control()));
+                }
+            }
+        }
+    }
+}
+
+/// System that displays the command being typed in the [`DebugConsole`].
+pub fn update_console_text(
+    console: Res<DebugConsole>,
+    mut query: Query<&mut Text, With<ConsoleInputText>>,
+) {
+    if console.is_changed() {
+        for mut text in &mut query {
+            text.0 = format!("> {}_", console.input);
+        }
+    }
+}
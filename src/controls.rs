@@ -0,0 +1,454 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    menu::{ButtonAction, spawn_button},
+    persist,
+    settings::Settings,
+    state::GameState,
+    toast::Toast,
+};
+
+/// The keys that can be bound to a [`KeyAction`].
+///
+/// Keys are persisted by name, so only these keys can be saved (and loaded) in the [`KeyBindings`] file.
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::KeyA,
+    KeyCode::KeyB,
+    KeyCode::KeyC,
+    KeyCode::KeyD,
+    KeyCode::KeyE,
+    KeyCode::KeyF,
+    KeyCode::KeyG,
+    KeyCode::KeyH,
+    KeyCode::KeyI,
+    KeyCode::KeyJ,
+    KeyCode::KeyK,
+    KeyCode::KeyL,
+    KeyCode::KeyM,
+    KeyCode::KeyN,
+    KeyCode::KeyO,
+    KeyCode::KeyP,
+    KeyCode::KeyQ,
+    KeyCode::KeyR,
+    KeyCode::KeyS,
+    KeyCode::KeyT,
+    KeyCode::KeyU,
+    KeyCode::KeyV,
+    KeyCode::KeyW,
+    KeyCode::KeyX,
+    KeyCode::KeyY,
+    KeyCode::KeyZ,
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Space,
+    KeyCode::Enter,
+    KeyCode::Tab,
+    KeyCode::Backspace,
+    KeyCode::Escape,
+    KeyCode::Backquote,
+    KeyCode::Minus,
+    KeyCode::Equal,
+    KeyCode::BracketLeft,
+    KeyCode::BracketRight,
+    KeyCode::Backslash,
+    KeyCode::Semicolon,
+    KeyCode::Quote,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Insert,
+    KeyCode::Delete,
+];
+
+/// (De)serializes a [`KeyCode`] by its name (e.g. `KeyC` or `Space`).
+mod key_name {
+    use bevy::input::keyboard::KeyCode;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::BINDABLE_KEYS;
+
+    pub fn serialize<S: Serializer>(key: &KeyCode, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{key:?}"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<KeyCode, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        BINDABLE_KEYS
+            .iter()
+            .find(|key| format!("{key:?}") == name)
+            .copied()
+            .ok_or_else(|| D::Error::custom(format!("`{name}` is not a bindable key")))
+    }
+}
+
+/// The actions that are performed with a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub enum KeyAction {
+    /// Picks up the topmost card (see [`Settings::quick_pick`]).
+    QuickPick,
+    /// Gives up the current game.
+    GiveUp,
+    /// Copies the current game's seed to the clipboard.
+    CopySeed,
+    /// Pastes a seed for the next game from the clipboard (while Ctrl or Cmd is held).
+    PasteSeed,
+    /// Opens or closes the debug console (in debug builds only).
+    ToggleConsole,
+    /// Deals a fresh game from any screen (see [`quick_restart`](crate::hud::quick_restart)).
+    QuickRestart,
+    /// Picks up the card nearest the cursor (see [`Settings::pick_nearest`]).
+    PickNearest,
+    /// Shows or hides each card's pick hitbox (in debug builds only).
+    ToggleHitboxes,
+    /// Collects all remaining cards at once and wins the round (in debug builds only).
+    InstantWin,
+    /// Saves an image of the board (see [`export_board`](crate::export::export_board)).
+    ExportBoard,
+}
+
+impl KeyAction {
+    /// Every action, with the ones that are only performed in debug builds last.
+    const ALL: &[KeyAction] = &[
+        KeyAction::QuickPick,
+        KeyAction::GiveUp,
+        KeyAction::CopySeed,
+        KeyAction::PasteSeed,
+        KeyAction::QuickRestart,
+        KeyAction::PickNearest,
+        KeyAction::ExportBoard,
+        KeyAction::ToggleConsole,
+        KeyAction::ToggleHitboxes,
+        KeyAction::InstantWin,
+    ];
+
+    /// How many of the last [`Self::ALL`] actions are only performed in debug builds.
+    const DEBUG_ONLY: usize = 3;
+
+    /// All actions (in the order they are displayed on the controls screen).
+    ///
+    /// Release builds leave out the debug-only actions, so they are not shown, and their keys
+    /// do not conflict with other actions (see [`KeyBindings::conflicts`]).
+    pub fn list() -> &'static [KeyAction] {
+        if cfg!(debug_assertions) {
+            Self::ALL
+        } else {
+            &Self::ALL[..Self::ALL.len() - Self::DEBUG_ONLY]
+        }
+    }
+
+    /// The name of this action displayed on the controls screen.
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeyAction::QuickPick => "Pick up topmost card",
+            KeyAction::GiveUp => "Give up",
+            KeyAction::CopySeed => "Copy seed",
+            KeyAction::PasteSeed => "Paste seed (with Ctrl)",
+            KeyAction::ToggleConsole => "Debug console",
+            KeyAction::QuickRestart => "Quick restart",
+            KeyAction::PickNearest => "Pick up nearest card",
+            KeyAction::ToggleHitboxes => "Debug hitboxes",
+            KeyAction::InstantWin => "Debug instant win",
+            KeyAction::ExportBoard => "Export board image",
+        }
+    }
+}
+
+/// Resource that maps each [`KeyAction`] to the key that performs it.
+///
+/// The bindings are persisted to a file in the user's config directory (except on the web).
+/// Actions missing from the file (like ones added since it was saved) keep their default keys.
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    #[serde(with = "key_name")]
+    pub quick_pick: KeyCode,
+    #[serde(with = "key_name")]
+    pub give_up: KeyCode,
+    #[serde(with = "key_name")]
+    pub copy_seed: KeyCode,
+    #[serde(with = "key_name")]
+    pub paste_seed: KeyCode,
+    #[serde(with = "key_name")]
+    pub toggle_console: KeyCode,
+    #[serde(with = "key_name")]
+    pub quick_restart: KeyCode,
+    #[serde(with = "key_name")]
+    pub pick_nearest: KeyCode,
+    #[serde(with = "key_name")]
+    pub toggle_hitboxes: KeyCode,
+    #[serde(with = "key_name")]
+    pub instant_win: KeyCode,
+    #[serde(with = "key_name")]
+    pub export_board: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            quick_pick: KeyCode::Space,
+            give_up: KeyCode::Escape,
+            copy_seed: KeyCode::KeyC,
+            paste_seed: KeyCode::KeyV,
+            toggle_console: KeyCode::Backquote,
+            quick_restart: KeyCode::F5,
+            pick_nearest: KeyCode::KeyF,
+            toggle_hitboxes: KeyCode::F3,
+            instant_win: KeyCode::F9,
+            export_board: KeyCode::F12,
+        }
+    }
+}
+
+impl KeyBindings {
+    /// The name of the persisted key bindings file.
+    const FILE_NAME: &str = "controls.ron";
+
+    /// The key bound to the given `action`.
+    pub fn key(&self, action: KeyAction) -> KeyCode {
+        match action {
+            KeyAction::QuickPick => self.quick_pick,
+            KeyAction::GiveUp => self.give_up,
+            KeyAction::CopySeed => self.copy_seed,
+            KeyAction::PasteSeed => self.paste_seed,
+            KeyAction::ToggleConsole => self.toggle_console,
+            KeyAction::QuickRestart => self.quick_restart,
+            KeyAction::PickNearest => self.pick_nearest,
+            KeyAction::ToggleHitboxes => self.toggle_hitboxes,
+            KeyAction::InstantWin => self.instant_win,
+            KeyAction::ExportBoard => self.export_board,
+        }
+    }
+
+    /// Binds the given `key` to the given `action`.
+    pub fn bind(&mut self, action: KeyAction, key: KeyCode) {
+        let binding = match action {
+            KeyAction::QuickPick => &mut self.quick_pick,
+            KeyAction::GiveUp => &mut self.give_up,
+            KeyAction::CopySeed => &mut self.copy_seed,
+            KeyAction::PasteSeed => &mut self.paste_seed,
+            KeyAction::ToggleConsole => &mut self.toggle_console,
+            KeyAction::QuickRestart => &mut self.quick_restart,
+            KeyAction::PickNearest => &mut self.pick_nearest,
+            KeyAction::ToggleHitboxes => &mut self.toggle_hitboxes,
+            KeyAction::InstantWin => &mut self.instant_win,
+            KeyAction::ExportBoard => &mut self.export_board,
+        };
+        *binding = key;
+    }
+
+    /// Is the key bound to the given `action` just pressed?
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>, action: KeyAction) -> bool {
+        keys.just_pressed(self.key(action))
+    }
+
+    /// The other actions bound to the same key as the given `action`.
+    pub fn conflicts(&self, action: KeyAction) -> impl Iterator<Item = KeyAction> + '_ {
+        KeyAction::list()
+            .iter()
+            .copied()
+            .filter(move |other| *other != action && self.key(*other) == self.key(action))
+    }
+}
+
+/// Resource that exists while the controls screen waits for a key to bind to the held [`KeyAction`].
+#[derive(Resource, Debug)]
+pub struct Rebinding(pub KeyAction);
+
+/// System that loads the persisted [`KeyBindings`] at startup.
+///
+/// A warning is logged for each key bound to more than one action.
+pub fn load_key_bindings(mut bindings: ResMut<KeyBindings>) {
+    if let Some(loaded) = persist::load(KeyBindings::FILE_NAME) {
+        *bindings = loaded;
+    }
+    for action in KeyAction::list() {
+        for other in bindings.conflicts(*action) {
+            warn!(
+                "{action:?} and {other:?} are both bound to {:?}",
+                bindings.key(*action)
+            );
+        }
+    }
+}
+
+/// System that persists the [`KeyBindings`] whenever they change.
+pub fn save_key_bindings(bindings: Res<KeyBindings>) {
+    persist::save(KeyBindings::FILE_NAME, bindings.as_ref());
+}
+
+/// System that runs during [`GameState::Controls`] (while [`Rebinding`]) to bind the next pressed key.
+///
+/// The player is warned (with a [`Toast`]) if the key is already bound to another action.
+pub fn capture_rebinding(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    keys: Res<ButtonInput<KeyCode>>,
+    rebinding: Res<Rebinding>,
+    mut bindings: ResMut<KeyBindings>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    let Some(key) = keys
+        .get_just_pressed()
+        .find(|key| BINDABLE_KEYS.contains(key))
+    else {
+        return;
+    };
+    let action = rebinding.0;
+    bindings.bind(action, *key);
+    info!("Bound {action:?} to {key:?}");
+    for other in bindings.conflicts(action) {
+        warn!("{action:?} and {other:?} are both bound to {key:?}");
+        let message = format!("{key:?} is also bound to \"{}\"", other.label());
+        Toast::spawn(&mut commands, &asset_server, message);
+    }
+    commands.remove_resource::<Rebinding>();
+    // re-enter the controls screen to respawn it with the new binding
+    game_state.set(GameState::Controls);
+}
+
+/// System that spawns the controls screen when entering the [`GameState::Controls`] state.
+///
+/// Each [`KeyAction`] is displayed as a row with a button that rebinds it when pressed.
+pub fn controls_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    bindings: Res<KeyBindings>,
+    rebinding: Option<Res<Rebinding>>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Controls),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                // embedded the FontAwesome keyboard icon via unicode code-point
+                Text::new("\u{F11C} Controls"),
+                TextColor(theme.text()),
+                TextFont {
+                    // nerd font required for code-point to render correctly
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+            ));
+            for action in KeyAction::list() {
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Node {
+                            width: Val::Px(280.0),
+                            ..Default::default()
+                        },
+                        Text::new(action.label()),
+                        TextColor(theme.text()),
+                        font_component.clone(),
+                    ));
+                    let key = if rebinding.as_ref().is_some_and(|r| r.0 == *action) {
+                        "Press a key...".to_string()
+                    } else if bindings.conflicts(*action).next().is_some() {
+                        format!("\u{F071} {:?}", bindings.key(*action))
+                    } else {
+                        format!("{:?}", bindings.key(*action))
+                    };
+                    spawn_button(
+                        row,
+                        font_component.clone(),
+                        theme,
+                        &key,
+                        ButtonAction::Rebind(*action),
+                    );
+                });
+            }
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome undo icon via unicode code-point
+                "\u{F0E2} Reset to Defaults",
+                ButtonAction::ResetBindings,
+            );
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome arrow-left icon via unicode code-point
+                "\u{F060} Back",
+                ButtonAction::OpenSettings,
+            );
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_actions_are_left_out_of_release_builds() {
+        let (release, debug) =
+            KeyAction::ALL.split_at(KeyAction::ALL.len() - KeyAction::DEBUG_ONLY);
+        assert!(
+            release
+                .iter()
+                .all(|action| !action.label().starts_with("Debug"))
+        );
+        assert!(
+            debug
+                .iter()
+                .all(|action| action.label().starts_with("Debug"))
+        );
+    }
+}
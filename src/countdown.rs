@@ -0,0 +1,152 @@
+use std::f32::consts::TAU;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    cards::Card, fade::FadeCommandsExt, mode::GameMode, settings::Settings, state::GameState,
+};
+
+/// Resource that tracks the time remaining to collect all cards in [`GameMode::Timed`].
+#[derive(Resource, Debug)]
+pub struct Countdown(pub Timer);
+
+impl Countdown {
+    /// How long (in seconds) the player has to collect all cards.
+    pub const TIME_LIMIT: f32 = 120.0;
+}
+
+impl Default for Countdown {
+    fn default() -> Self {
+        Self(Timer::from_seconds(Self::TIME_LIMIT, TimerMode::Once))
+    }
+}
+
+/// A marker for the ring that follows the mouse cursor, showing the remaining [`Countdown`] time.
+#[derive(Component, Debug)]
+pub struct CursorRing;
+
+/// One segment of the [`CursorRing`], holding its index (clockwise from the top).
+#[derive(Component, Debug)]
+pub struct CursorRingSegment(usize);
+
+impl CursorRing {
+    /// The radius (in logical pixels) of the ring.
+    const RADIUS: f32 = 28.0;
+    /// How many segments the ring is made of.
+    const SEGMENTS: usize = 40;
+    /// The size (in logical pixels) of each segment.
+    const SEGMENT_SIZE: f32 = 5.0;
+    /// The fraction of remaining time under which the ring turns red.
+    const WARNING_FRACTION: f32 = 0.25;
+}
+
+/// System that runs during [`GameState::Play`] (in [`GameMode::Timed`]) to advance the [`Countdown`].
+///
+/// The countdown only advances while there are cards to pick up (faster or slower with the
+/// [`Settings::game_speed`]). When time runs out,
+/// the remaining cards can no longer be picked up and the [`GameState::Lose`] summary is shown.
+pub fn tick_countdown(
+    mut commands: Commands,
+    time: Res<Time>,
+    mode: Res<GameMode>,
+    settings: Res<Settings>,
+    mut countdown: ResMut<Countdown>,
+    mut cards: Query<&mut Card>,
+) {
+    if *mode != GameMode::Timed || !cards.iter().any(|card| card.playable) {
+        return;
+    }
+    countdown
+        .0
+        .tick(time.delta().mul_f32(settings.game_speed.factor()));
+    // a mis-click penalty (see `penalize_misclick`) may have run out the time between ticks
+    if countdown.0.is_finished() {
+        info!("Time is up");
+        for mut card in &mut cards {
+            card.playable = false;
+        }
+        commands.fade_to_state(GameState::Lose);
+    }
+}
+
+/// System that spawns the [`CursorRing`] when entering [`GameState::Play`] (in [`GameMode::Timed`] only).
+pub fn spawn_cursor_ring(mut commands: Commands, mode: Res<GameMode>) {
+    if *mode != GameMode::Timed {
+        return;
+    }
+    let size = CursorRing::RADIUS * 2.0 + CursorRing::SEGMENT_SIZE;
+    commands
+        .spawn((
+            CursorRing,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(size),
+                height: Val::Px(size),
+                ..default()
+            },
+            // the ring should never block the pointer from reaching the cards
+            Pickable::IGNORE,
+            // hidden until positioned around the cursor
+            Visibility::Hidden,
+            GlobalZIndex(5),
+            DespawnOnExit(GameState::Play),
+        ))
+        .with_children(|ring| {
+            for index in 0..CursorRing::SEGMENTS {
+                // clockwise from the top (UI coordinates point down)
+                let angle = TAU * index as f32 / CursorRing::SEGMENTS as f32;
+                let offset = Vec2::new(angle.sin(), -angle.cos()) * CursorRing::RADIUS
+                    + Vec2::splat(CursorRing::RADIUS);
+                ring.spawn((
+                    CursorRingSegment(index),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(offset.x),
+                        top: Val::Px(offset.y),
+                        width: Val::Px(CursorRing::SEGMENT_SIZE),
+                        height: Val::Px(CursorRing::SEGMENT_SIZE),
+                        border_radius: BorderRadius::MAX,
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE.with_alpha(0.8)),
+                    Pickable::IGNORE,
+                ));
+            }
+        });
+}
+
+/// System that moves the [`CursorRing`] along with the mouse cursor and shrinks it as the
+/// [`Countdown`] runs out.
+///
+/// The ring is hidden while the cursor is outside the window.
+pub fn update_cursor_ring(
+    window: Single<&Window, With<PrimaryWindow>>,
+    countdown: Res<Countdown>,
+    ring: Single<(&mut Node, &mut Visibility), With<CursorRing>>,
+    mut segments: Query<(&CursorRingSegment, &mut BackgroundColor)>,
+) {
+    let (mut node, mut visibility) = ring.into_inner();
+    let Some(cursor) = window.cursor_position() else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+    *visibility = Visibility::Inherited;
+    let half_size = CursorRing::RADIUS + CursorRing::SEGMENT_SIZE / 2.0;
+    node.left = Val::Px(cursor.x - half_size);
+    node.top = Val::Px(cursor.y - half_size);
+
+    let remaining = countdown.0.fraction_remaining();
+    let filled = (remaining * CursorRing::SEGMENTS as f32).ceil() as usize;
+    let color = if remaining < CursorRing::WARNING_FRACTION {
+        Color::srgb(1.0, 0.3, 0.3)
+    } else {
+        Color::WHITE
+    };
+    for (segment, mut background) in &mut segments {
+        background.0 = if segment.0 < filled {
+            color.with_alpha(0.8)
+        } else {
+            Color::NONE
+        };
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+use crate::{
+    animator::GameSpeed,
+    audio::{Sounds, play_sfx},
+    cards::Card,
+    deck::DeckRemaining,
+    settings::Settings,
+};
+
+/// The delay between each card leaving the deck.
+pub const DEAL_STAGGER: f32 = 0.04;
+
+/// The time a card takes to fly from the deck to its place on the board.
+pub const DEAL_FLIGHT_DURATION: f32 = 0.35;
+
+/// Animates a card flying from the deck to its place on the board.
+///
+/// The card is not playable until it lands.
+#[derive(Component, Debug)]
+pub struct DealFlight {
+    /// The time to wait before the card leaves the deck.
+    pub delay: Timer,
+    /// The time the card spends in the air.
+    pub flight: Timer,
+    /// Where the card leaves the deck.
+    pub start: Transform,
+    /// Where the card lands on the board.
+    pub end: Transform,
+}
+
+impl DealFlight {
+    /// Creates a flight for the `index`-th card dealt out of the deck, at the given `speed`.
+    pub fn new(index: usize, start: Transform, end: Transform, speed: GameSpeed) -> Self {
+        Self {
+            delay: Timer::from_seconds(
+                index as f32 * DEAL_STAGGER / speed.factor(),
+                TimerMode::Once,
+            ),
+            flight: Timer::from_seconds(DEAL_FLIGHT_DURATION / speed.factor(), TimerMode::Once),
+            start,
+            end,
+        }
+    }
+}
+
+/// Resource that throttles the sound of cards sliding out of the deck,
+/// so many cards dealt in quick succession don't overlap into noise.
+#[derive(Resource, Debug)]
+pub struct SlideSoundCooldown(pub Timer);
+
+impl Default for SlideSoundCooldown {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(0.12, TimerMode::Once);
+        // ready to play the first slide immediately
+        timer.finish();
+        Self(timer)
+    }
+}
+
+/// System that moves each card along its [`DealFlight`].
+///
+/// When a card leaves the deck, the [`DeckRemaining`] count is decremented and a (throttled)
+/// slide sound is played. When a card lands, it becomes playable.
+pub fn fly_in(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    sounds: Res<Sounds>,
+    mut cooldown: ResMut<SlideSoundCooldown>,
+    mut deck_remaining: ResMut<DeckRemaining>,
+    mut query: Query<(Entity, &mut DealFlight, &mut Transform, &mut Card)>,
+) {
+    cooldown.0.tick(time.delta());
+    for (entity, mut flight, mut transform, mut card) in &mut query {
+        if !flight.delay.is_finished() {
+            if !flight.delay.tick(time.delta()).just_finished() {
+                continue;
+            }
+            // the card leaves the deck
+            deck_remaining.0 = deck_remaining.0.saturating_sub(1);
+            if cooldown.0.is_finished() {
+                play_sfx(&mut commands, &settings, &sounds.slide);
+                cooldown.0.reset();
+            }
+        }
+        flight.flight.tick(time.delta());
+        let progress = EaseFunction::SmoothStepOut.sample_clamped(flight.flight.fraction());
+        transform.translation = flight
+            .start
+            .translation
+            .lerp(flight.end.translation, progress);
+        transform.rotation = flight.start.rotation.slerp(flight.end.rotation, progress);
+        if flight.flight.is_finished() {
+            *transform = flight.end;
+            card.playable = true;
+            commands.entity(entity).remove::<DealFlight>();
+        }
+    }
+}
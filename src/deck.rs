@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+
+use crate::{
+    cards::{CARD_HALF_SIZE, CARD_THICKNESS, Card, Rank, Suit},
+    fade::FadeCommandsExt,
+    layout::deck_position,
+    settings::Settings,
+    state::{CardsCollected, CardsDealt, GameState},
+};
+
+/// The number of cards in a full deck.
+pub const DECK_SIZE: u8 = 52;
+
+/// Resource that tracks how many cards are left in the deck (not yet dealt).
+#[derive(Resource, Debug)]
+pub struct DeckRemaining(pub u8);
+
+impl Default for DeckRemaining {
+    fn default() -> Self {
+        Self(DECK_SIZE)
+    }
+}
+
+/// Marks the entity that visually represents the deck pile on the table.
+#[derive(Component, Debug)]
+pub struct DeckPile {
+    /// The material used while the deck still has cards in it.
+    pub full_material: Handle<StandardMaterial>,
+    /// The faint "card outline" material used once the deck is exhausted.
+    pub empty_material: Handle<StandardMaterial>,
+}
+
+impl DeckPile {
+    /// The transform of the deck pile when it holds `remaining` cards.
+    ///
+    /// An empty deck is rendered as a single (very thin) card outline.
+    fn transform(settings: &Settings, remaining: u8) -> Transform {
+        let height = remaining.max(1) as f32 * CARD_THICKNESS;
+        let mut transform =
+            Transform::from_translation(deck_position(settings).with_y(height / 2.0))
+                .looking_to(Dir3::Y, Dir3::Z);
+        transform.scale.z = height;
+        transform
+    }
+}
+
+/// System that spawns the deck pile at startup.
+pub fn spawn_deck(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let full_material = materials.add(StandardMaterial {
+        base_color_texture: Some(asset_server.load(Card::back_resource_name())),
+        alpha_mode: AlphaMode::Mask(0.5),
+        ..default()
+    });
+    let empty_material = materials.add(StandardMaterial {
+        base_color: Color::WHITE.with_alpha(0.2),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    // The mesh is a unit-thick card, which is then scaled (on its local Z axis) to the deck's height.
+    let mesh = meshes.add(Cuboid {
+        half_size: CARD_HALF_SIZE.extend(0.5),
+    });
+    commands.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(full_material.clone()),
+        DeckPile::transform(&settings, DECK_SIZE),
+        DeckPile {
+            full_material,
+            empty_material,
+        },
+    ));
+}
+
+/// System that runs when entering the [`GameState::Deal`](crate::state::GameState::Deal) state.
+///
+/// This restores the full deck before any cards are dealt out of it.
+pub fn restock_deck(mut deck_remaining: ResMut<DeckRemaining>) {
+    deck_remaining.0 = DECK_SIZE;
+}
+
+/// System that keeps the deck pile's visual in sync with the [`DeckRemaining`] resource
+/// (and the board layout in [`Settings`]).
+///
+/// Once the deck is exhausted, the pile is replaced with a faint card outline placeholder.
+pub fn update_deck(
+    deck_remaining: Res<DeckRemaining>,
+    settings: Res<Settings>,
+    mut query: Query<(
+        &DeckPile,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    if !deck_remaining.is_changed() && !settings.is_changed() {
+        return;
+    }
+    for (deck, mut transform, mut material) in &mut query {
+        *transform = DeckPile::transform(&settings, deck_remaining.0);
+        material.0 = if deck_remaining.0 == 0 {
+            deck.empty_material.clone()
+        } else {
+            deck.full_material.clone()
+        };
+    }
+}
+
+/// System that runs when entering the [`GameState::Play`](crate::state::GameState::Play) state
+/// to verify the dealt cards are a complete deck (exactly one of each rank and suit).
+///
+/// This is only a safeguard against bugs in the dealing code, so it only runs in debug builds.
+/// Any missing or duplicated cards are logged as an error. In a short game (see [`CardsDealt`]),
+/// only the number of cards and the duplicates are checked.
+pub fn verify_dealt_cards(cards: Query<&Card>, cards_dealt: Res<CardsDealt>) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let (missing, duplicated) = check_deck(cards.iter(), cards_dealt.is_full_deck());
+    let count = cards.iter().count();
+    if count != cards_dealt.0 as usize {
+        error!(
+            "{count} cards are on the table, but {} were dealt",
+            cards_dealt.0
+        );
+    }
+    if !missing.is_empty() || !duplicated.is_empty() {
+        error!(
+            "The dealt cards are not a complete deck; missing: [{}], duplicated: [{}]",
+            missing.join(", "),
+            duplicated.join(", ")
+        );
+    }
+}
+
+/// Lists the cards that are missing from (only if `full_deck`) and duplicated in the given `cards`.
+///
+/// A duplicated card is listed with the number of copies (e.g. "Ace of Spades (x2)").
+fn check_deck<'a>(
+    cards: impl IntoIterator<Item = &'a Card>,
+    full_deck: bool,
+) -> (Vec<String>, Vec<String>) {
+    let mut counts = [[0u8; 13]; 4];
+    for card in cards {
+        counts[card.suit.as_u8() as usize][card.rank.as_u8() as usize - 1] += 1;
+    }
+    let mut missing = Vec::new();
+    let mut duplicated = Vec::new();
+    for suit in Suit::list() {
+        for rank in Rank::list() {
+            let card = Card {
+                rank,
+                suit,
+                face_up: false,
+                playable: false,
+            };
+            match counts[suit.as_u8() as usize][rank.as_u8() as usize - 1] {
+                0 if full_deck => missing.push(card.to_string()),
+                0 => {}
+                1 => {}
+                n => duplicated.push(format!("{card} (x{n})")),
+            }
+        }
+    }
+    (missing, duplicated)
+}
+
+/// System that runs during [`GameState::Play`] to recover from a board that has no cards at all
+/// while the round is not yet won (see [`CardsCollected`]).
+///
+/// Collected cards stay on the table (in the pile), so this can only happen if cards were despawned
+/// by a bug. Instead of leaving the round unwinnable, a warning is logged and the game returns to the
+/// [`GameState::Menu`].
+pub fn recover_empty_board(
+    mut commands: Commands,
+    cards: Query<(), With<Card>>,
+    cards_collected: Res<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+) {
+    if !cards.is_empty() || cards_collected.0 >= cards_dealt.0 {
+        return;
+    }
+    warn!(
+        "No cards are on the table, but only {} of {} were collected; returning to the menu",
+        cards_collected.0, cards_dealt.0
+    );
+    commands.fade_to_state(GameState::Menu);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::cards::sorted_deck;
+
+    /// Runs [`recover_empty_board`] with the given `cards` on the table, and returns the next state (if any).
+    fn recover(cards: &[Card], cards_collected: u8) -> Option<GameState> {
+        let mut world = World::new();
+        world.insert_resource(Settings {
+            reduced_motion: true,
+            ..default()
+        });
+        world.init_resource::<NextState<GameState>>();
+        world.insert_resource(CardsCollected(cards_collected));
+        world.insert_resource(CardsDealt::default());
+        for card in cards {
+            world.spawn(*card);
+        }
+        world.run_system_once(recover_empty_board).unwrap();
+        match world.resource::<NextState<GameState>>() {
+            NextState::Pending(state) => Some(*state),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn empty_board_returns_to_menu() {
+        assert_eq!(recover(&[], 10), Some(GameState::Menu));
+    }
+
+    #[test]
+    fn board_with_cards_is_kept() {
+        assert_eq!(recover(&sorted_deck()[..3], 10), None);
+    }
+
+    #[test]
+    fn won_round_is_kept() {
+        assert_eq!(recover(&[], DECK_SIZE), None);
+    }
+
+    #[test]
+    fn complete_deck_passes() {
+        let deck = sorted_deck();
+        assert_eq!(check_deck(&deck, true), (vec![], vec![]));
+    }
+
+    #[test]
+    fn missing_and_duplicated_cards_are_listed() {
+        let mut deck = sorted_deck();
+        let removed = deck.pop().unwrap();
+        deck.push(deck[0]);
+        let (missing, duplicated) = check_deck(&deck, true);
+        assert_eq!(missing, vec![removed.to_string()]);
+        assert_eq!(duplicated, vec![format!("{} (x2)", deck[0])]);
+    }
+
+    #[test]
+    fn short_game_only_lists_duplicates() {
+        let deck = sorted_deck();
+        let (missing, duplicated) = check_deck(&deck[..10], false);
+        assert!(missing.is_empty());
+        assert!(duplicated.is_empty());
+    }
+}
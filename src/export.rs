@@ -0,0 +1,130 @@
+//! Exports an image of the board (without any UI) for sharing a puzzle or reporting a bad deal.
+
+use std::path::PathBuf;
+
+use bevy::{
+    camera::RenderTarget,
+    prelude::*,
+    render::{
+        render_resource::TextureFormat,
+        view::screenshot::{Screenshot, ScreenshotCaptured, save_to_disk},
+    },
+};
+
+use crate::{
+    camera::{CameraController, FRAME_HALF_SIZE},
+    controls::{KeyAction, KeyBindings},
+    persist,
+    seed::GameSeed,
+    settings::Settings,
+    state::{CardsCollected, CardsDealt},
+    toast::Toast,
+};
+
+/// The size (in pixels) of an exported board image.
+const EXPORT_SIZE: UVec2 = UVec2 { x: 1920, y: 1080 };
+
+/// An offscreen camera that renders the board straight from above into an image,
+/// which is saved once it is captured (see [`export_board`]).
+///
+/// The camera is only around for the few frames it takes to capture the image.
+/// It is not the [`MainCamera`](crate::camera::MainCamera), so the live camera's systems ignore it.
+#[derive(Component, Debug)]
+pub struct BoardSnapshot {
+    /// The image that the camera renders to.
+    image: Handle<Image>,
+    /// Where the image is saved.
+    path: PathBuf,
+    /// Has the capture of the image been requested?
+    requested: bool,
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to spawn a [`BoardSnapshot`]
+/// when the [`KeyAction::ExportBoard`] key (F12 by default) is pressed.
+///
+/// The snapshot's camera always frames the whole board from straight above (as turned by
+/// [`Settings::board_rotation`]), no matter how the live camera is tilted, zoomed, or panned.
+/// The image is named after the game's seed and the number of cards left, so it can be matched
+/// to the deal it shows.
+#[allow(clippy::too_many_arguments)]
+pub fn export_board(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    seed: Res<GameSeed>,
+    // grouped to stay within the number of parameters a system may have
+    (cards_dealt, cards_collected): (Res<CardsDealt>, Res<CardsCollected>),
+    mut images: ResMut<Assets<Image>>,
+    snapshots: Query<(), With<BoardSnapshot>>,
+) {
+    if !bindings.just_pressed(&keys, KeyAction::ExportBoard) || !snapshots.is_empty() {
+        return;
+    }
+    let remaining = cards_dealt.0.saturating_sub(cards_collected.0);
+    let file_name = format!("board-{}-{remaining}.png", seed.0);
+    let Some(path) = persist::export_path(&file_name) else {
+        Toast::spawn(
+            &mut commands,
+            &asset_server,
+            "Could not export the board image",
+        );
+        return;
+    };
+    let image = images.add(Image::new_target_texture(
+        EXPORT_SIZE.x,
+        EXPORT_SIZE.y,
+        TextureFormat::Rgba8UnormSrgb,
+        None,
+    ));
+    let mut controller = CameraController::default();
+    controller.distance = CameraController::fit_distance(
+        controller.fov,
+        EXPORT_SIZE.x as f32 / EXPORT_SIZE.y as f32,
+        0.0,
+        settings.board_rotation.extents(FRAME_HALF_SIZE),
+    );
+    commands.spawn((
+        BoardSnapshot {
+            image: image.clone(),
+            path,
+            requested: false,
+        },
+        Camera3d::default(),
+        RenderTarget::Image(image.into()),
+        controller.projection(),
+        controller.transform(),
+    ));
+}
+
+/// System that captures the image of each [`BoardSnapshot`] (after its camera has rendered the board),
+/// then saves the image and despawns the snapshot.
+pub fn capture_board_snapshots(
+    mut commands: Commands,
+    mut snapshots: Query<(Entity, &mut BoardSnapshot)>,
+) {
+    for (entity, mut snapshot) in &mut snapshots {
+        if snapshot.requested {
+            continue;
+        }
+        snapshot.requested = true;
+        let path = snapshot.path.clone();
+        commands
+            .spawn(Screenshot::image(snapshot.image.clone()))
+            .observe(save_to_disk(path.clone()))
+            .observe(
+                move |_captured: On<ScreenshotCaptured>,
+                      mut commands: Commands,
+                      asset_server: Res<AssetServer>| {
+                    commands.entity(entity).despawn();
+                    info!("Exported the board image to {}", path.display());
+                    Toast::spawn(
+                        &mut commands,
+                        &asset_server,
+                        format!("Exported the board image to {}", path.display()),
+                    );
+                },
+            );
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::{settings::Settings, state::GameState};
+
+/// Resource that exists while the screen fades between two [`GameState`]s.
+///
+/// The screen fades out (to black), the state changes to [`Self::target`], then the screen fades back in.
+#[derive(Resource, Debug)]
+pub struct ScreenFade {
+    /// The state to change to once the screen has faded out.
+    ///
+    /// This is [`None`] while the screen fades back in.
+    target: Option<GameState>,
+    /// Tracks the progress of the current half (out or in) of the fade.
+    timer: Timer,
+}
+
+/// A marker for the full-screen node that covers the screen while fading.
+#[derive(Component, Debug)]
+pub struct FadeOverlay;
+
+/// Extends [`Commands`] to change the [`GameState`] with a [`ScreenFade`].
+pub trait FadeCommandsExt {
+    /// Fades the screen out, changes to the given `state`, then fades the screen back in.
+    ///
+    /// The state changes immediately if [`Settings::reduced_motion`] is enabled
+    /// (or [`Settings::fade_duration`] is `0`). While the screen is fading out,
+    /// any other requested state change is ignored.
+    fn fade_to_state(&mut self, state: GameState);
+}
+
+impl FadeCommandsExt for Commands<'_, '_> {
+    fn fade_to_state(&mut self, state: GameState) {
+        self.queue(move |world: &mut World| {
+            let settings = world.resource::<Settings>();
+            let duration = settings.fade_duration;
+            if settings.reduced_motion || duration <= 0.0 {
+                world.resource_mut::<NextState<GameState>>().set(state);
+                return;
+            }
+            if world
+                .get_resource::<ScreenFade>()
+                .is_some_and(|fade| fade.target.is_some())
+            {
+                return;
+            }
+            world.insert_resource(ScreenFade {
+                target: Some(state),
+                timer: Timer::from_seconds(duration, TimerMode::Once),
+            });
+        });
+    }
+}
+
+/// System that spawns the (initially transparent) [`FadeOverlay`] at startup.
+pub fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        FadeOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        // the overlay should never block the pointer from reaching anything beneath it
+        Pickable::IGNORE,
+        GlobalZIndex(30),
+    ));
+}
+
+/// System that animates the [`FadeOverlay`]'s opacity while a [`ScreenFade`] is in progress.
+///
+/// Once the screen has faded out, this changes the game state to the [`ScreenFade`]'s target.
+pub fn update_fade(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fade: ResMut<ScreenFade>,
+    mut overlay: Single<&mut BackgroundColor, With<FadeOverlay>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    fade.timer.tick(time.delta());
+    let opacity =
+        EasingCurve::new(0.0, 1.0, EaseFunction::SmoothStep).sample_clamped(fade.timer.fraction());
+    let alpha = if fade.target.is_some() {
+        opacity
+    } else {
+        1.0 - opacity
+    };
+    overlay.0 = Color::BLACK.with_alpha(alpha);
+    if fade.timer.is_finished() {
+        if let Some(target) = fade.target.take() {
+            game_state.set(target);
+            fade.timer.reset();
+        } else {
+            commands.remove_resource::<ScreenFade>();
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use bevy::{
+    asset::{LoadState, RenderAssetUsages},
+    image::ImageSampler,
+    platform::collections::HashSet,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::cards::Card;
+
+/// The size (in pixels) of the generated fallback texture (same aspect ratio as a card).
+const FALLBACK_SIZE: UVec2 = UVec2 { x: 21, y: 30 };
+
+/// How much each pixel of [`QUESTION_MARK`] is scaled up in the fallback texture.
+const GLYPH_SCALE: u32 = 3;
+
+/// A 5x7 bitmap of a "?" glyph (one row per byte, most significant of the 5 bits on the left).
+const QUESTION_MARK: [u8; 7] = [
+    0b01110, 0b10001, 0b00001, 0b00110, 0b00100, 0b00000, 0b00100,
+];
+
+/// Resource that holds a generated texture to show in place of any card texture that failed to load.
+#[derive(Resource, Debug)]
+pub struct FallbackTexture(pub Handle<Image>);
+
+impl FromWorld for FallbackTexture {
+    fn from_world(world: &mut World) -> Self {
+        let mut images = world.resource_mut::<Assets<Image>>();
+        Self(images.add(Self::generate()))
+    }
+}
+
+impl FallbackTexture {
+    /// Generates a light gray texture with a "?" drawn in the middle.
+    fn generate() -> Image {
+        let background = [0xD0, 0xD0, 0xD0, 0xFF];
+        let foreground = [0x40, 0x40, 0x40, 0xFF];
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: FALLBACK_SIZE.x,
+                height: FALLBACK_SIZE.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &background,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        // keep the glyph's edges crisp
+        image.sampler = ImageSampler::nearest();
+
+        let offset_x = (FALLBACK_SIZE.x - 5 * GLYPH_SCALE) / 2;
+        let offset_y = (FALLBACK_SIZE.y - 7 * GLYPH_SCALE) / 2;
+        let data = image.data.as_mut().expect("image was created with data");
+        for y in 0..FALLBACK_SIZE.y {
+            for x in 0..FALLBACK_SIZE.x {
+                let (Some(gx), Some(gy)) = (x.checked_sub(offset_x), y.checked_sub(offset_y))
+                else {
+                    continue;
+                };
+                let (gx, gy) = (gx / GLYPH_SCALE, gy / GLYPH_SCALE);
+                if gx < 5 && gy < 7 && QUESTION_MARK[gy as usize] & (0b10000 >> gx) != 0 {
+                    let i = ((y * FALLBACK_SIZE.x + x) * 4) as usize;
+                    data[i..i + 4].copy_from_slice(&foreground);
+                }
+            }
+        }
+        image
+    }
+}
+
+/// System that checks the load state of every material's texture, and substitutes the
+/// [`FallbackTexture`] for any texture that failed to load (e.g. a missing image file).
+///
+/// This keeps the game playable with a broken (or incomplete) art pack.
+/// A warning naming the missing file is logged once per texture.
+pub fn substitute_missing_textures(
+    asset_server: Res<AssetServer>,
+    fallback: Res<FallbackTexture>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut reported: Local<HashSet<AssetId<Image>>>,
+) {
+    let failed = materials
+        .iter()
+        .filter_map(|(id, material)| {
+            let texture = material.base_color_texture.as_ref()?;
+            matches!(
+                asset_server.get_load_state(texture),
+                Some(LoadState::Failed(_))
+            )
+            .then_some((id, texture.id()))
+        })
+        .collect::<Vec<_>>();
+    for (material_id, texture_id) in failed {
+        if reported.insert(texture_id) {
+            let path = asset_server
+                .get_path(texture_id)
+                .map(|path| path.to_string())
+                .unwrap_or_else(|| texture_id.to_string());
+            warn!("Failed to load texture {path}; using a placeholder instead");
+        }
+        if let Some(material) = materials.get_mut(material_id) {
+            material.base_color_texture = Some(fallback.0.clone());
+        }
+    }
+}
+
+/// The color shown on a card's face while its texture is still loading (see [`LoadingFace`]).
+const LOADING_FACE_COLOR: Color = Color::srgb(0.82, 0.82, 0.8);
+
+/// A card's face that shows a plain [`LOADING_FACE_COLOR`] material while its texture is still loading.
+///
+/// An unloaded texture would otherwise leave the face blank (or see-through) for the first frames of a deal.
+#[derive(Component, Debug)]
+pub struct LoadingFace {
+    /// The face's texture that is loading.
+    texture: Handle<Image>,
+    /// The face's (textured) material, which is shown once the texture has loaded.
+    material: Handle<StandardMaterial>,
+}
+
+/// Is the given texture done loading (whether or not it loaded successfully)?
+///
+/// A texture that failed to load gets the [`FallbackTexture`] (see [`substitute_missing_textures`]).
+fn is_done_loading(asset_server: &AssetServer, texture: &Handle<Image>) -> bool {
+    is_done(asset_server.get_load_state(texture))
+}
+
+/// Is an asset in the given `load_state` done loading (whether or not it loaded successfully)?
+fn is_done(load_state: Option<LoadState>) -> bool {
+    matches!(load_state, Some(LoadState::Loaded | LoadState::Failed(_)))
+}
+
+impl LoadingFace {
+    /// The plain material shown in place of the given (textured) face `material` while its texture loads.
+    ///
+    /// The face's depth bias is kept, so overlapping cards still draw in order.
+    fn placeholder(material: &StandardMaterial) -> StandardMaterial {
+        StandardMaterial {
+            base_color: LOADING_FACE_COLOR,
+            depth_bias: material.depth_bias,
+            ..default()
+        }
+    }
+}
+
+/// System that gives each newly dealt card's face a plain material (and a [`LoadingFace`])
+/// if the face's texture has not loaded yet (see [`LoadingFace::placeholder`]).
+#[allow(clippy::type_complexity)]
+pub fn hold_loading_faces(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut faces: Query<
+        (Entity, &ChildOf, &mut MeshMaterial3d<StandardMaterial>),
+        Added<MeshMaterial3d<StandardMaterial>>,
+    >,
+    cards: Query<(), With<Card>>,
+) {
+    for (entity, child_of, mut face_material) in &mut faces {
+        if !cards.contains(child_of.parent()) {
+            continue;
+        }
+        let Some(material) = materials.get(&face_material.0) else {
+            continue;
+        };
+        let Some(texture) = material.base_color_texture.clone() else {
+            continue;
+        };
+        if is_done_loading(&asset_server, &texture) {
+            continue;
+        }
+        let placeholder = LoadingFace::placeholder(material);
+        let material = std::mem::replace(&mut face_material.0, materials.add(placeholder));
+        commands
+            .entity(entity)
+            .insert(LoadingFace { texture, material });
+    }
+}
+
+/// System that gives each [`LoadingFace`] its textured material back once the texture is done loading.
+pub fn show_loaded_faces(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut faces: Query<(Entity, &LoadingFace, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, face, mut face_material) in &mut faces {
+        if is_done_loading(&asset_server, &face.texture) {
+            face_material.0 = face.material.clone();
+            commands.entity(entity).remove::<LoadingFace>();
+        }
+    }
+}
+
+/// The path of the nerd font that all UI text uses (for its icon glyphs).
+const NERD_FONT: &str = "fonts/UbuntuNerdFont-Medium.ttf";
+
+/// Is the given character one of the nerd font's icon glyphs?
+///
+/// The icons are all in Unicode's private use areas, which no other font draws.
+fn is_icon(c: char) -> bool {
+    matches!(c, '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}')
+}
+
+/// Removes the icon glyphs (see [`is_icon`]) from the given text, leaving a plain text alternative
+/// (e.g. "\u{F04B} Start Game" becomes "Start Game").
+pub fn strip_icons(text: &str) -> String {
+    let stripped = text.chars().filter(|c| !is_icon(*c)).collect::<String>();
+    stripped
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// System that substitutes Bevy's default font for the nerd font if it failed to load (e.g. a missing font file).
+///
+/// Without the nerd font, the icon glyphs would be drawn as boxes, so they are removed from the text
+/// (see [`strip_icons`]), including text that changes later (like the HUD).
+/// A warning is logged once.
+pub fn substitute_missing_font(
+    asset_server: Res<AssetServer>,
+    mut reported: Local<bool>,
+    mut texts: Query<(&mut Text, &mut TextFont)>,
+) {
+    let Some(nerd_font) = asset_server.get_handle::<Font>(NERD_FONT) else {
+        return;
+    };
+    if !matches!(
+        asset_server.get_load_state(&nerd_font),
+        Some(LoadState::Failed(_))
+    ) {
+        return;
+    }
+    if !*reported {
+        *reported = true;
+        warn!("Failed to load font {NERD_FONT}; using the default font without icons instead");
+    }
+    for (mut text, mut font) in &mut texts {
+        let uses_nerd_font = font.font.id() == nerd_font.id();
+        if !uses_nerd_font && !text.is_changed() {
+            continue;
+        }
+        if uses_nerd_font {
+            font.font = Handle::default();
+        }
+        if text.0.chars().any(is_icon) {
+            text.0 = strip_icons(&text.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy::asset::AssetLoadError;
+
+    use super::*;
+
+    #[test]
+    fn fallback_texture_has_card_size_and_glyph() {
+        let image = FallbackTexture::generate();
+        assert_eq!(image.size(), FALLBACK_SIZE);
+        let data = image.data.expect("image was created with data");
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * FALLBACK_SIZE.x + x) * 4) as usize;
+            [data[i], data[i + 1], data[i + 2], data[i + 3]]
+        };
+        let background = [0xD0, 0xD0, 0xD0, 0xFF];
+        assert_eq!(pixel(0, 0), background);
+        assert_eq!(pixel(FALLBACK_SIZE.x - 1, FALLBACK_SIZE.y - 1), background);
+        // the dot of the "?" is in the middle of its last row
+        let dot = pixel(
+            FALLBACK_SIZE.x / 2,
+            (FALLBACK_SIZE.y + 7 * GLYPH_SCALE) / 2 - 1,
+        );
+        assert_eq!(dot, [0x40, 0x40, 0x40, 0xFF]);
+    }
+
+    #[test]
+    fn loading_ends_with_success_or_failure() {
+        assert!(is_done(Some(LoadState::Loaded)));
+        let error = AssetLoadError::AssetMetaReadError;
+        assert!(is_done(Some(LoadState::Failed(Arc::new(error)))));
+        assert!(!is_done(Some(LoadState::Loading)));
+        assert!(!is_done(Some(LoadState::NotLoaded)));
+        assert!(!is_done(None));
+    }
+
+    #[test]
+    fn placeholder_keeps_depth_bias() {
+        let material = StandardMaterial {
+            depth_bias: 3.0,
+            ..default()
+        };
+        let placeholder = LoadingFace::placeholder(&material);
+        assert_eq!(placeholder.depth_bias, 3.0);
+        assert_eq!(placeholder.base_color, LOADING_FACE_COLOR);
+        assert!(placeholder.base_color_texture.is_none());
+    }
+
+    #[test]
+    fn icons_are_private_use_glyphs() {
+        assert!(is_icon('\u{F04B}'));
+        assert!(is_icon('\u{F0000}'));
+        assert!(!is_icon('A'));
+        assert!(!is_icon('♠'));
+    }
+
+    #[test]
+    fn strip_icons_leaves_plain_text() {
+        assert_eq!(strip_icons("\u{F04B} Start Game"), "Start Game");
+        assert_eq!(
+            strip_icons("\u{F04B} Cards\n\u{F0000} 3 of 52 "),
+            "Cards\n3 of 52"
+        );
+        assert_eq!(strip_icons("No icons"), "No icons");
+    }
+}
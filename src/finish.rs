@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+
+use crate::{
+    cards::Card,
+    dealing::DealFlight,
+    menu::{ButtonAction, spawn_button},
+    mode::GameMode,
+    reveal::Reveal,
+    select::DelayedPickup,
+    settings::Settings,
+    state::GameState,
+};
+
+/// Event that picks up all remaining playable cards in a quick cascade (see [`DelayedPickup`]).
+///
+/// The cards are picked up like any other, so the round ends (and is won) the usual way.
+#[derive(Event, Debug)]
+pub struct CollectRest;
+
+/// A marker for the HUD's "Collect Rest" button (see [`Settings::collect_rest_below`]).
+#[derive(Component, Debug)]
+pub struct CollectRestButton;
+
+/// The observer that queues a [`DelayedPickup`] for each remaining playable card.
+///
+/// The topmost cards go first. In [`GameMode::Foundation`], the cards go in rank order instead,
+/// so each card is next in its foundation when it is picked up.
+pub fn collect_rest(
+    _event: On<CollectRest>,
+    mut commands: Commands,
+    mode: Res<GameMode>,
+    cards: Query<(Entity, &Card, &Transform), Without<DelayedPickup>>,
+) {
+    let mut remaining = cards
+        .iter()
+        .filter(|(_, card, _)| card.playable)
+        .collect::<Vec<_>>();
+    if *mode == GameMode::Foundation {
+        remaining.sort_by_key(|(_, card, _)| card.rank.as_u8());
+    } else {
+        remaining.sort_by(|(_, _, a), (_, _, b)| b.translation.y.total_cmp(&a.translation.y));
+    }
+    info!("Collecting the remaining {} cards", remaining.len());
+    for (i, (entity, _, _)) in remaining.into_iter().enumerate() {
+        commands.entity(entity).insert(DelayedPickup::nth(i));
+    }
+}
+
+/// System that runs during [`GameState::Play`] to show the [`CollectRestButton`] while only a few cards
+/// (no more than [`Settings::collect_rest_below`]) remain to be picked up.
+#[allow(clippy::type_complexity)]
+pub fn update_collect_rest_button(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    cards: Query<(&Card, Has<DealFlight>, Has<Reveal>, Has<DelayedPickup>)>,
+    button: Query<Entity, With<CollectRestButton>>,
+) {
+    let dealing = cards.iter().any(|(_, is_dealing, _, _)| is_dealing);
+    let remaining = cards
+        .iter()
+        .filter(|(card, _, is_revealing, is_queued)| {
+            (card.playable || *is_revealing) && !*is_queued
+        })
+        .count();
+    let shown = !dealing && remaining > 0 && remaining <= settings.collect_rest_below as usize;
+    if !shown {
+        for entity in button {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !button.is_empty() {
+        return;
+    }
+    let font_component = TextFont {
+        font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+        font_size: 16.0,
+        ..Default::default()
+    };
+    commands
+        .spawn((
+            CollectRestButton,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                right: Val::Px(0.0),
+                ..Default::default()
+            },
+            DespawnOnExit(GameState::Play),
+        ))
+        .with_children(|corner| {
+            spawn_button(
+                corner,
+                font_component,
+                settings.ui_theme,
+                // embedded the FontAwesome forward icon via unicode code-point
+                "\u{F04E} Collect Rest",
+                ButtonAction::CollectRest,
+            );
+        });
+}
@@ -0,0 +1,266 @@
+use bevy::prelude::*;
+
+use crate::{
+    gallery::Scrollable,
+    menu::{ActivatedButtons, ButtonAction},
+    settings::{SettingKind, Settings},
+};
+
+/// The width (in pixels) of the outline around the focused control.
+const FOCUS_OUTLINE_WIDTH: f32 = 2.0;
+
+/// Resource that tracks which control of the settings screen has the keyboard (or gamepad) focus
+/// (see [`navigate_settings`]).
+///
+/// This is kept when leaving the settings screen, so the focus is where it was upon returning.
+#[derive(Resource, Debug, Default)]
+pub struct SettingsFocus(usize);
+
+impl SettingsFocus {
+    /// Moves the focus by `step` controls (of `count`), wrapping around at either end.
+    ///
+    /// A focus that is past the last control (e.g. after the screen was rebuilt) goes back to the first.
+    fn step(&mut self, step: isize, count: usize) {
+        if step != 0 {
+            self.0 = (self.0 as isize + step).rem_euclid(count as isize) as usize;
+        } else if self.0 >= count {
+            self.0 = 0;
+        }
+    }
+}
+
+/// A control of the settings screen that can be focused, in the given order (starting at `0`).
+#[derive(Component, Debug)]
+pub struct Focusable(pub usize);
+
+/// A marker for the row of the settings screen that adjusts the given setting.
+#[derive(Component, Debug)]
+pub struct SettingRow(pub SettingKind);
+
+/// System that runs during [`GameState::Settings`](crate::state::GameState::Settings) to navigate
+/// the settings screen without the mouse.
+///
+/// | Keyboard         | Gamepad            | Does                                                       |
+/// |------------------|--------------------|------------------------------------------------------------|
+/// | Down / Tab       | D-pad down         | Focuses the next control                                   |
+/// | Up / Shift + Tab | D-pad up           | Focuses the previous control                               |
+/// | Left / Right     | D-pad left / right | Adjusts the focused setting                                |
+/// | Enter / Space    | South (A)          | Steps (or toggles) the focused setting, or presses the focused button |
+/// | Escape           | East (B)           | Returns to the main menu                                   |
+///
+/// The resulting actions are queued as [`ActivatedButtons`], so they behave exactly like the buttons.
+pub fn navigate_settings(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<SettingsFocus>,
+    mut activated: ResMut<ActivatedButtons>,
+    controls: Query<(&Focusable, Option<&SettingRow>, Option<&ButtonAction>)>,
+) {
+    let pad = |button: GamepadButton| gamepads.iter().any(|pad| pad.just_pressed(button));
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let count = controls.iter().count();
+    if count == 0 {
+        return;
+    }
+    let mut step = 0;
+    if keys.just_pressed(KeyCode::ArrowDown) || pad(GamepadButton::DPadDown) {
+        step += 1;
+    }
+    if keys.just_pressed(KeyCode::ArrowUp) || pad(GamepadButton::DPadUp) {
+        step -= 1;
+    }
+    if keys.just_pressed(KeyCode::Tab) {
+        step += if shift { -1 } else { 1 };
+    }
+    focus.step(step, count);
+
+    if keys.just_pressed(KeyCode::Escape) || pad(GamepadButton::East) {
+        activated.activate(ButtonAction::Back);
+        return;
+    }
+    let Some((_, row, button)) = controls
+        .iter()
+        .find(|(focusable, _, _)| focusable.0 == focus.0)
+    else {
+        return;
+    };
+    let adjust = if keys.just_pressed(KeyCode::ArrowLeft) || pad(GamepadButton::DPadLeft) {
+        -1
+    } else if keys.just_pressed(KeyCode::ArrowRight) || pad(GamepadButton::DPadRight) {
+        1
+    } else {
+        0
+    };
+    let confirm = keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter, KeyCode::Space])
+        || pad(GamepadButton::South);
+    match (row, button) {
+        (Some(row), _) if adjust != 0 => activated.activate(ButtonAction::Adjust(row.0, adjust)),
+        // toggles ignore the step's sign, and anything else steps up
+        (Some(row), _) if confirm => activated.activate(ButtonAction::Adjust(row.0, 1)),
+        (None, Some(action)) if confirm => activated.activate(*action),
+        _ => {}
+    }
+}
+
+/// System that outlines the focused control of the settings screen (see [`SettingsFocus`]),
+/// and scrolls the list of settings to keep the focused row in view.
+#[allow(clippy::type_complexity)]
+pub fn show_settings_focus(
+    mut commands: Commands,
+    focus: Res<SettingsFocus>,
+    settings: Res<Settings>,
+    mut controls: Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        &UiGlobalTransform,
+        Option<&mut Outline>,
+        Option<&ChildOf>,
+    )>,
+    mut lists: Query<(&ComputedNode, &UiGlobalTransform, &mut ScrollPosition), With<Scrollable>>,
+) {
+    let color = settings.ui_theme.text();
+    for (entity, focusable, node, transform, outline, parent) in &mut controls {
+        let focused = focusable.0 == focus.0;
+        let outline_color = if focused { color } else { Color::NONE };
+        match outline {
+            Some(mut outline) => outline.color = outline_color,
+            None => {
+                commands.entity(entity).insert(Outline::new(
+                    Val::Px(FOCUS_OUTLINE_WIDTH),
+                    Val::Px(FOCUS_OUTLINE_WIDTH),
+                    outline_color,
+                ));
+            }
+        }
+        if !focused || !focus.is_changed() {
+            continue;
+        }
+        let Some((list, list_transform, mut scroll)) =
+            parent.and_then(|parent| lists.get_mut(parent.parent()).ok())
+        else {
+            continue;
+        };
+        // the layout is in physical pixels, but the scroll position is in logical pixels
+        let view = Rect::from_center_size(list_transform.translation, list.size());
+        let row = Rect::from_center_size(transform.translation, node.size());
+        scroll.0.y += scroll_into_view(view, row) * list.inverse_scale_factor();
+    }
+}
+
+/// How far to scroll (down, if positive) a list showing the `view` so the given `row` is fully in view.
+///
+/// A row that is already in view needs no scrolling.
+fn scroll_into_view(view: Rect, row: Rect) -> f32 {
+    if row.min.y < view.min.y {
+        row.min.y - view.min.y
+    } else if row.max.y > view.max.y {
+        row.max.y - view.max.y
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{
+        cards::Suit,
+        controls::KeyBindings,
+        menu::button_detector,
+        mode::{DrillSuit, GameMode, RoundsCleared},
+        seed::SeedMode,
+        state::{CardsCollected, GameState},
+        stats::LifetimeStats,
+    };
+
+    /// The settings to start adjusting from (with the fixed seed shown, so it can be adjusted).
+    fn start_settings() -> Settings {
+        Settings {
+            seed_mode: SeedMode::Fixed,
+            ..default()
+        }
+    }
+
+    /// Presses the `key` with the control at `index` focused, and returns the resulting settings.
+    fn press(world: &mut World, index: usize, key: KeyCode) -> Settings {
+        world.insert_resource(start_settings());
+        world.insert_resource(SettingsFocus(index));
+        let mut keys = ButtonInput::default();
+        keys.press(key);
+        world.insert_resource(keys);
+        world.run_system_once(navigate_settings).unwrap();
+        world.run_system_once(button_detector).unwrap();
+        world.resource::<Settings>().clone()
+    }
+
+    #[test]
+    fn keys_adjust_every_setting() {
+        let mut world = World::new();
+        world.init_resource::<ActivatedButtons>();
+        world.init_resource::<NextState<GameState>>();
+        world.init_resource::<GameMode>();
+        world.insert_resource(DrillSuit(Suit::Spades));
+        world.init_resource::<RoundsCleared>();
+        world.init_resource::<LifetimeStats>();
+        world.init_resource::<KeyBindings>();
+        world.init_resource::<CardsCollected>();
+        for (index, kind) in SettingKind::list().iter().enumerate() {
+            world.spawn((Focusable(index), SettingRow(*kind)));
+        }
+
+        let serialized = |settings: &Settings| ron::to_string(settings).unwrap();
+        for (index, kind) in SettingKind::list().iter().enumerate() {
+            let mut changed = false;
+            for (key, step) in [
+                (KeyCode::ArrowLeft, -1),
+                (KeyCode::ArrowRight, 1),
+                (KeyCode::Enter, 1),
+            ] {
+                let pressed = press(&mut world, index, key);
+                let mut expected = start_settings();
+                kind.adjust(&mut expected, step);
+                // only the focused setting changes, the same as with its buttons
+                assert_eq!(
+                    serialized(&pressed),
+                    serialized(&expected),
+                    "{kind:?} {key:?}"
+                );
+                changed |= serialized(&pressed) != serialized(&start_settings());
+            }
+            assert!(changed, "{kind:?} cannot be changed with the keyboard");
+        }
+    }
+
+    #[test]
+    fn focus_wraps_around() {
+        let mut focus = SettingsFocus::default();
+        focus.step(-1, 5);
+        assert_eq!(focus.0, 4);
+        focus.step(1, 5);
+        assert_eq!(focus.0, 0);
+        focus.step(2, 5);
+        assert_eq!(focus.0, 2);
+    }
+
+    #[test]
+    fn focus_past_last_control_resets() {
+        let mut focus = SettingsFocus(8);
+        focus.step(0, 5);
+        assert_eq!(focus.0, 0);
+        let mut focus = SettingsFocus(3);
+        focus.step(0, 5);
+        assert_eq!(focus.0, 3);
+    }
+
+    #[test]
+    fn scrolls_row_into_view() {
+        let view = Rect::new(0.0, 100.0, 200.0, 300.0);
+        let row = |top: f32| Rect::new(0.0, top, 200.0, top + 20.0);
+        assert_eq!(scroll_into_view(view, row(150.0)), 0.0);
+        assert_eq!(scroll_into_view(view, row(90.0)), -10.0);
+        assert_eq!(scroll_into_view(view, row(290.0)), 10.0);
+    }
+}
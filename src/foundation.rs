@@ -0,0 +1,74 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::cards::{Card, Rank, Suit};
+
+/// Resource that tracks the highest rank collected of each suit in [`GameMode::Foundation`](crate::mode::GameMode::Foundation).
+///
+/// Cards must be collected in ascending rank order per suit, like building foundations in solitaire.
+#[derive(Resource, Debug, Default)]
+pub struct Foundations([Option<Rank>; 4]);
+
+impl Foundations {
+    /// The top card of the given suit's foundation (if any cards of that suit were collected).
+    pub fn top(&self, suit: Suit) -> Option<Rank> {
+        self.0[suit.as_u8() as usize]
+    }
+
+    /// Can the given card be collected next?
+    pub fn accepts(&self, card: &Card) -> bool {
+        card.can_place_on(self.top(card.suit))
+    }
+
+    /// Places the given card on its suit's foundation.
+    pub fn place(&mut self, card: &Card) {
+        self.0[card.suit.as_u8() as usize] = Some(card.rank);
+    }
+}
+
+/// Shakes a card side to side (e.g. when it cannot be picked up), then restores its [`Transform`].
+#[derive(Component, Debug)]
+pub struct Shake {
+    /// Tracks the progress of the shake.
+    timer: Timer,
+    /// Where the card was before it started shaking.
+    origin: Vec3,
+}
+
+impl Shake {
+    /// How long a shake lasts (in seconds).
+    const DURATION: f32 = 0.35;
+    /// How far (in world units) the card moves to either side.
+    const AMPLITUDE: f32 = 6.0;
+    /// How many times the card moves back and forth.
+    const OSCILLATIONS: f32 = 4.0;
+
+    /// Starts shaking a card that is currently at the given `origin`.
+    pub fn new(origin: Vec3) -> Self {
+        Self {
+            timer: Timer::from_seconds(Self::DURATION, TimerMode::Once),
+            origin,
+        }
+    }
+}
+
+/// System that animates each [`Shake`], removing it once finished.
+pub fn shake_cards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Shake, &mut Transform)>,
+) {
+    for (entity, mut shake, mut transform) in &mut query {
+        shake.timer.tick(time.delta());
+        if shake.timer.is_finished() {
+            transform.translation = shake.origin;
+            commands.entity(entity).remove::<Shake>();
+            continue;
+        }
+        let fraction = shake.timer.fraction();
+        let offset =
+            (fraction * Shake::OSCILLATIONS * TAU).sin() * Shake::AMPLITUDE * (1.0 - fraction);
+        transform.translation = shake.origin + Vec3::X * offset;
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::{fade::FadeCommandsExt, settings::Settings, state::GameState};
+
+/// The longest [`FreezeFrame`] (in seconds) if [`Settings::reduced_motion`] is enabled.
+const REDUCED_MOTION_HOLD: f32 = 0.25;
+
+/// Resource that exists while the completed pile is held on screen before the
+/// [`GameState::Win`] menu is shown (see [`Settings::win_hold`]).
+#[derive(Resource, Debug)]
+pub struct FreezeFrame(pub Timer);
+
+impl FreezeFrame {
+    /// How long (in seconds) the completed pile is held on screen per the given `settings`.
+    ///
+    /// The hold is shortened if [`Settings::reduced_motion`] is enabled.
+    pub fn duration(settings: &Settings) -> f32 {
+        if settings.reduced_motion {
+            settings.win_hold.min(REDUCED_MOTION_HOLD)
+        } else {
+            settings.win_hold
+        }
+    }
+
+    /// Holds the completed pile on screen for `duration` seconds, or shows the
+    /// [`GameState::Win`] menu right away if there is no hold.
+    pub fn start(commands: &mut Commands, duration: f32) {
+        if duration > 0.0 {
+            commands.insert_resource(Self(Timer::from_seconds(duration, TimerMode::Once)));
+        } else {
+            commands.fade_to_state(GameState::Win);
+        }
+    }
+}
+
+/// System that runs while the [`FreezeFrame`] holds the completed pile on screen.
+///
+/// The hold is timed in real time (so it is not stretched by the final pickup's slow motion).
+/// When finished (or skipped with any key or mouse button), this sets the game state to [`GameState::Win`].
+pub fn hold_freeze_frame(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut freeze_frame: ResMut<FreezeFrame>,
+) {
+    freeze_frame.0.tick(time.delta());
+    let skipped =
+        keys.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some();
+    if freeze_frame.0.is_finished() || skipped {
+        commands.remove_resource::<FreezeFrame>();
+        commands.fade_to_state(GameState::Win);
+    }
+}
+
+/// System that ends the [`FreezeFrame`] when leaving [`GameState::Play`] early
+/// (e.g. when a new game is dealt during the hold).
+pub fn clear_freeze_frame(mut commands: Commands) {
+    commands.remove_resource::<FreezeFrame>();
+}
@@ -0,0 +1,165 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+
+use crate::{
+    cards::{Card, CardAspect, Rank, Suit},
+    menu::{ButtonAction, spawn_button},
+    settings::Settings,
+    state::GameState,
+};
+
+/// The width of each card in the gallery (in logical pixels).
+const GALLERY_CARD_WIDTH: f32 = 64.0;
+
+/// How far (in logical pixels) a single "line" of mouse wheel scrolling moves.
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
+
+/// Marks a UI node that scrolls (vertically) with the mouse wheel.
+#[derive(Component, Debug)]
+pub struct Scrollable;
+
+/// A gallery cell whose card face is loaded once the cell is scrolled into view.
+///
+/// This avoids loading all 52 card faces at once when the gallery is opened.
+#[derive(Component, Debug)]
+pub struct LazyCardImage(pub Card);
+
+/// System that spawns the card gallery when entering the [`GameState::Gallery`] state.
+///
+/// The gallery displays all 52 card faces (one suit per row) in a scrollable grid, which
+/// helps to verify that the card art loaded correctly.
+pub fn gallery_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    card_aspect: Res<CardAspect>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Gallery),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("All Cards"),
+                TextColor(theme.text()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+            ));
+            root.spawn((
+                Node {
+                    max_height: Val::Percent(70.0),
+                    overflow: Overflow::scroll_y(),
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::px(
+                        Rank::list().len() as u16,
+                        GALLERY_CARD_WIDTH,
+                    ),
+                    row_gap: Val::Px(8.0),
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..Default::default()
+                },
+                Scrollable,
+            ))
+            .with_children(|grid| {
+                for suit in Suit::list() {
+                    for rank in Rank::list() {
+                        grid.spawn((
+                            Node {
+                                width: Val::Px(GALLERY_CARD_WIDTH),
+                                height: Val::Px(GALLERY_CARD_WIDTH / card_aspect.ratio()),
+                                ..Default::default()
+                            },
+                            BackgroundColor(theme.text().with_alpha(0.1)),
+                            LazyCardImage(Card {
+                                rank,
+                                suit,
+                                face_up: true,
+                                playable: false,
+                            }),
+                        ));
+                    }
+                }
+            });
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome arrow-left icon via unicode code-point
+                "\u{F060} Back",
+                ButtonAction::OpenSettings,
+            );
+        });
+}
+
+/// System that loads the card face of each [`LazyCardImage`] once it is scrolled into view.
+pub fn load_visible_cards(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    containers: Query<(&ComputedNode, &UiGlobalTransform), With<Scrollable>>,
+    cells: Query<(
+        Entity,
+        &LazyCardImage,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &ChildOf,
+    )>,
+) {
+    for (entity, lazy, node, transform, parent) in &cells {
+        let Ok((container_node, container_transform)) = containers.get(parent.parent()) else {
+            continue;
+        };
+        let view = Rect::from_center_size(container_transform.translation, container_node.size());
+        let cell = Rect::from_center_size(transform.translation, node.size());
+        if node.size() != Vec2::ZERO && !view.intersect(cell).is_empty() {
+            commands.entity(entity).remove::<LazyCardImage>().insert((
+                ImageNode::new(asset_server.load(lazy.0.face_resource_name())),
+                BackgroundColor(Color::NONE),
+            ));
+        }
+    }
+}
+
+/// System that scrolls each [`Scrollable`] node with the mouse wheel.
+pub fn scroll_on_wheel(
+    mut wheel: MessageReader<MouseWheel>,
+    query: Query<&mut ScrollPosition, With<Scrollable>>,
+) {
+    let delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 {
+        return;
+    }
+    for mut position in query {
+        position.0.y -= delta;
+    }
+}
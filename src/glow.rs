@@ -0,0 +1,76 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{
+    animator::PickUpCard,
+    cards::{CARD_HALF_SIZE, Card},
+    settings::Settings,
+};
+
+/// How far (in world units) the glow reaches past each card's edges.
+const GLOW_MARGIN: f32 = 3.0;
+
+/// How far (in world units) the glow floats above the card's surface, so it is never hidden by it.
+const GLOW_LIFT: f32 = 0.5;
+
+/// How long (in seconds) each pulse of the glow takes.
+const GLOW_PERIOD: f32 = 1.6;
+
+/// The color of the glow at its brightest.
+const GLOW_COLOR: Color = Color::srgba(1.0, 0.9, 0.5, 0.8);
+
+/// Resource that tracks when (in seconds of game time) a card was last picked up.
+#[derive(Resource, Debug, Default)]
+pub struct LastPickup(pub f32);
+
+/// System that restarts the time since the last pickup when a game starts,
+/// so the glow doesn't show as soon as the cards are dealt.
+pub fn reset_last_pickup(time: Res<Time>, mut last_pickup: ResMut<LastPickup>) {
+    last_pickup.0 = time.elapsed_secs();
+}
+
+/// The observer that records the time of each [`PickUpCard`] in [`LastPickup`] (whether the card was
+/// pressed, or picked up with a key), which stops the glow.
+pub fn note_pickup(_event: On<PickUpCard>, time: Res<Time>, mut last_pickup: ResMut<LastPickup>) {
+    last_pickup.0 = time.elapsed_secs();
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to make each playable card
+/// glow after [`Settings::idle_glow`] seconds without a pickup, as a gentle hint to carry on.
+///
+/// The glow pulses (or, with [`Settings::reduced_motion`], stays steady) until the next pickup.
+/// This uses game time, so the glow waits while the game is paused.
+pub fn draw_idle_glow(
+    mut gizmos: Gizmos,
+    time: Res<Time>,
+    settings: Res<Settings>,
+    last_pickup: Res<LastPickup>,
+    cards: Query<(&Card, &GlobalTransform)>,
+) {
+    let idle = time.elapsed_secs() - last_pickup.0;
+    if settings.idle_glow <= 0.0 || idle < settings.idle_glow {
+        return;
+    }
+    let brightness = if settings.reduced_motion {
+        1.0
+    } else {
+        // each pulse starts dark, so the glow fades in
+        (1.0 - ((idle - settings.idle_glow) * TAU / GLOW_PERIOD).cos()) / 2.0
+    };
+    let color = GLOW_COLOR.with_alpha(GLOW_COLOR.alpha() * brightness);
+    for (card, transform) in &cards {
+        if !card.playable {
+            continue;
+        }
+        // a card of a grid deal is smaller (see [`grid_scale`](crate::layout::grid_scale))
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        gizmos
+            .rounded_rect(
+                Isometry3d::new(translation + Vec3::Y * GLOW_LIFT, rotation),
+                (CARD_HALF_SIZE * scale.x + GLOW_MARGIN) * 2.0,
+                color,
+            )
+            .corner_radius(settings.card_corner_radius * scale.x + GLOW_MARGIN);
+    }
+}
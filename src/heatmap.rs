@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cards::{Card, Rank, Suit},
+    deck::DECK_SIZE,
+    menu::{ButtonAction, spawn_button},
+    settings::Settings,
+    state::GameState,
+};
+
+/// Resource that records when (in seconds since the round started) each card was picked up.
+///
+/// This is reset whenever a new game is dealt.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct PickTimeline(Vec<Option<f32>>);
+
+impl Default for PickTimeline {
+    fn default() -> Self {
+        Self(vec![None; DECK_SIZE as usize])
+    }
+}
+
+impl PickTimeline {
+    /// The index of the given card in the timeline (grouped by suit, then ordered by rank).
+    fn index(suit: Suit, rank: Rank) -> usize {
+        suit.as_u8() as usize * Rank::list().len() + rank.as_u8() as usize - 1
+    }
+
+    /// Records that the given card was picked up at `time` (in seconds since the round started).
+    pub fn record(&mut self, card: &Card, time: f32) {
+        self.0[Self::index(card.suit, card.rank)] = Some(time);
+    }
+
+    /// When the given card was picked up (if it was).
+    pub fn get(&self, suit: Suit, rank: Rank) -> Option<f32> {
+        self.0[Self::index(suit, rank)]
+    }
+
+    /// The times at which cards were picked up (in the order they were picked up).
+    pub fn splits(&self) -> Vec<f32> {
+        let mut splits: Vec<f32> = self.0.iter().flatten().copied().collect();
+        splits.sort_by(f32::total_cmp);
+        splits
+    }
+
+    /// The earliest and latest times any card was picked up.
+    fn range(&self) -> Option<(f32, f32)> {
+        self.0.iter().flatten().fold(None, |range, &time| {
+            Some(range.map_or((time, time), |(min, max): (f32, f32)| {
+                (min.min(time), max.max(time))
+            }))
+        })
+    }
+}
+
+/// The color of a heatmap cell for a card picked up at the given fraction of the round
+/// (from `0` for the earliest pick to `1` for the latest).
+fn heat_color(fraction: f32) -> Color {
+    Color::oklch(0.6, 0.16, 250.0).mix(&Color::oklch(0.65, 0.2, 30.0), fraction)
+}
+
+/// System that spawns the heatmap screen when entering the [`GameState::Heatmap`] state.
+///
+/// Each card is displayed in a grid (one row per suit), colored by how early (blue) or late (red)
+/// it was picked up in the last round. Cards that were not picked up are grayed out.
+pub fn heatmap_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    timeline: Res<PickTimeline>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+    let cell_font = TextFont {
+        font: font.clone(),
+        font_size: 16.0,
+        ..Default::default()
+    };
+    let (earliest, latest) = timeline.range().unwrap_or_default();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(4.0),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Heatmap),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                // embedded the FontAwesome fire icon via unicode code-point
+                Text::new("\u{F06D} Pick Heatmap"),
+                TextColor(theme.text()),
+                TextFont {
+                    // nerd font required for code-point to render correctly
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+            ));
+            for suit in Suit::list() {
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Node {
+                            width: Val::Px(100.0),
+                            ..Default::default()
+                        },
+                        Text::new(suit.name()),
+                        TextColor(theme.text()),
+                        cell_font.clone(),
+                    ));
+                    for rank in Rank::list() {
+                        let background = match timeline.get(suit, rank) {
+                            Some(time) if latest > earliest => {
+                                heat_color((time - earliest) / (latest - earliest))
+                            }
+                            Some(_) => heat_color(0.0),
+                            None => theme.muted_text().with_alpha(0.25),
+                        };
+                        row.spawn((
+                            Node {
+                                width: Val::Px(40.0),
+                                height: Val::Px(40.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border_radius: BorderRadius::all(Val::Px(4.0)),
+                                ..Default::default()
+                            },
+                            BackgroundColor(background),
+                            children![(
+                                Text::new(rank.to_string()),
+                                TextColor(Color::WHITE),
+                                cell_font.clone(),
+                            )],
+                        ));
+                    }
+                });
+            }
+            root.spawn((
+                Text::new(format!(
+                    "Blue cards were picked up first (at {earliest:.1}s), red cards last (at {latest:.1}s)."
+                )),
+                TextColor(theme.muted_text()),
+                cell_font.clone(),
+            ));
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome arrow-left icon via unicode code-point
+                "\u{F060} Back",
+                ButtonAction::CloseHeatmap,
+            );
+        });
+}
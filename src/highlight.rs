@@ -0,0 +1,61 @@
+use bevy::{picking::mesh_picking::ray_cast::MeshRayCast, prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera::MainCamera,
+    cards::{CARD_HALF_SIZE, Card},
+    settings::Settings,
+};
+
+/// How far (in world units) the outline floats above the card's surface, so it is never hidden by it.
+const OUTLINE_LIFT: f32 = 0.5;
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to outline the
+/// topmost card under the mouse cursor, which is the card a click picks up.
+///
+/// All cards under the cursor are found with a ray cast, and the highest one is outlined
+/// (if it can be picked up). This helps avoid mis-clicks where cards overlap.
+pub fn highlight_topmost_card(
+    mut gizmos: Gizmos,
+    settings: Res<Settings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut ray_cast: MeshRayCast,
+    cards: Query<(&Card, &GlobalTransform)>,
+    parents: Query<&ChildOf>,
+) {
+    let (camera, camera_transform) = camera.into_inner();
+    let Some(ray) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor).ok())
+    else {
+        return;
+    };
+    let topmost = ray_cast
+        .cast_ray(ray, &MeshRayCastSettings::default().never_early_exit())
+        .iter()
+        .filter_map(|(entity, _)| {
+            // a card's face is a child of the card
+            cards.get(*entity).ok().or_else(|| {
+                parents
+                    .get(*entity)
+                    .ok()
+                    .and_then(|parent| cards.get(parent.parent()).ok())
+            })
+        })
+        .max_by(|(_, a), (_, b)| a.translation().y.total_cmp(&b.translation().y));
+    let Some((card, transform)) = topmost else {
+        return;
+    };
+    if !card.playable {
+        return;
+    }
+    // a card of a grid deal is smaller (see [`grid_scale`](crate::layout::grid_scale))
+    let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+    gizmos
+        .rounded_rect(
+            Isometry3d::new(translation + Vec3::Y * OUTLINE_LIFT, rotation),
+            CARD_HALF_SIZE * 2.0 * scale.x,
+            Color::WHITE.with_alpha(0.8),
+        )
+        .corner_radius(settings.card_corner_radius * scale.x);
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{
+    cards::{Card, CardAspect},
+    controls::{KeyAction, KeyBindings},
+};
+
+/// How far (in world units) the footprints float above the table, so they are not hidden by it.
+const FOOTPRINT_LIFT: f32 = 0.05;
+
+/// Resource that exists while each card's pick hitbox is drawn (see [`draw_hitboxes`]).
+///
+/// This is a debugging aid for picking issues, so it is only available in debug builds.
+#[derive(Resource, Debug, Default)]
+pub struct ShowHitboxes;
+
+/// System that shows (or hides) the hitboxes when the [`KeyAction::ToggleHitboxes`] key is pressed.
+pub fn toggle_hitboxes(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    shown: Option<Res<ShowHitboxes>>,
+) {
+    if !cfg!(debug_assertions) || !bindings.just_pressed(&keys, KeyAction::ToggleHitboxes) {
+        return;
+    }
+    if shown.is_some() {
+        commands.remove_resource::<ShowHitboxes>();
+    } else {
+        commands.init_resource::<ShowHitboxes>();
+    }
+}
+
+/// System that draws each card's pick hitbox (the card's mesh as a box) with [`Gizmos`],
+/// along with the box's footprint projected onto the table.
+///
+/// Playable cards are outlined in green and the others in gray.
+/// The outlines follow the cards as they move (and tilt).
+pub fn draw_hitboxes(
+    mut gizmos: Gizmos,
+    card_aspect: Res<CardAspect>,
+    cards: Query<(&Card, &GlobalTransform)>,
+) {
+    let half_size = card_aspect.mesh_half_size();
+    for (card, transform) in &cards {
+        let color = if card.playable {
+            Color::srgb(0.2, 1.0, 0.4)
+        } else {
+            Color::srgb(0.6, 0.6, 0.6)
+        };
+        let hitbox = transform.compute_transform();
+        gizmos.cube(hitbox.with_scale(hitbox.scale * half_size * 2.0), color);
+        let footprint = [
+            Vec3::new(-half_size.x, -half_size.y, 0.0),
+            Vec3::new(half_size.x, -half_size.y, 0.0),
+            Vec3::new(half_size.x, half_size.y, 0.0),
+            Vec3::new(-half_size.x, half_size.y, 0.0),
+            Vec3::new(-half_size.x, -half_size.y, 0.0),
+        ]
+        .map(|corner| transform.transform_point(corner).with_y(FOOTPRINT_LIFT));
+        gizmos.linestrip(footprint, color.with_alpha(0.5));
+    }
+}
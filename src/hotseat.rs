@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use crate::mode::GameMode;
+
+/// How many players take turns in [`GameMode::HotSeat`].
+pub const PLAYERS: usize = 2;
+
+/// Resource that tracks whose turn it is in [`GameMode::HotSeat`] (starting at `0` for "Player 1").
+///
+/// The turn passes to the next player after each card is picked up.
+#[derive(Resource, Debug, Default)]
+pub struct CurrentPlayer(pub usize);
+
+impl CurrentPlayer {
+    /// Passes the turn to the next player.
+    pub fn advance(&mut self) {
+        self.0 = (self.0 + 1) % PLAYERS;
+    }
+}
+
+/// Resource that counts how many cards each player picked up in [`GameMode::HotSeat`].
+#[derive(Resource, Debug, Default)]
+pub struct PlayerScores(pub [u8; PLAYERS]);
+
+impl PlayerScores {
+    /// Describes who won (or that it is a tie) and by how many cards.
+    pub fn result(&self) -> String {
+        let [first, second] = self.0;
+        match first.cmp(&second) {
+            std::cmp::Ordering::Greater => format!("Player 1 wins, {first} to {second}!"),
+            std::cmp::Ordering::Less => format!("Player 2 wins, {second} to {first}!"),
+            std::cmp::Ordering::Equal => format!("It's a tie, {first} each!"),
+        }
+    }
+}
+
+/// A marker for the HUD text that shows whose turn it is (in [`GameMode::HotSeat`] only).
+#[derive(Component, Debug)]
+pub struct TurnText;
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to update the [`TurnText`].
+pub fn update_turn_text(
+    mode: Res<GameMode>,
+    current: Res<CurrentPlayer>,
+    scores: Res<PlayerScores>,
+    query: Query<&mut Text, With<TurnText>>,
+) {
+    if *mode != GameMode::HotSeat {
+        return;
+    }
+    for mut text in query {
+        text.0 = format!(
+            "Player {}'s turn ({} : {})",
+            current.0 + 1,
+            scores.0[0],
+            scores.0[1]
+        );
+    }
+}
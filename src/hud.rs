@@ -0,0 +1,353 @@
+use bevy::prelude::*;
+
+use crate::{
+    cards::{Rank, Suit},
+    controls::{KeyAction, KeyBindings},
+    fade::FadeCommandsExt,
+    heatmap::PickTimeline,
+    hotseat::TurnText,
+    menu::{ButtonAction, spawn_button},
+    mode::{GameMode, RoundsCleared},
+    seed::GameSeed,
+    settings::Settings,
+    state::{CardsCollected, CardsDealt, GameState, SuitsCollected},
+    stats::{LifetimeStats, RoundClock},
+};
+
+/// Resource that exists while the player is asked to confirm restarting the game in progress.
+///
+/// Cards cannot be picked up while this resource exists.
+#[derive(Resource, Debug)]
+pub struct ConfirmRestart;
+
+impl ConfirmRestart {
+    /// Restarting skips the confirmation if fewer than this many cards were collected.
+    pub const MIN_CARDS_COLLECTED: u8 = 5;
+
+    /// Should restarting with the given number of collected cards ask for confirmation first?
+    pub fn is_needed(cards_collected: &CardsCollected) -> bool {
+        cards_collected.0 >= Self::MIN_CARDS_COLLECTED
+    }
+}
+
+/// System that dismisses the [`ConfirmRestart`] prompt when exiting [`GameState::Play`].
+pub fn clear_restart_confirm(mut commands: Commands) {
+    commands.remove_resource::<ConfirmRestart>();
+}
+
+/// System that deals a fresh game when the [`KeyAction::QuickRestart`] key (F5 by default) is pressed.
+///
+/// This intentionally bypasses the menus, so it works from any screen (like the main menu or the win screen).
+/// During play, the restart is confirmed first (see [`ConfirmRestart`]); pressing the key again confirms it.
+/// The per-game counters and timers are reset when entering [`GameState::Deal`].
+pub fn quick_restart(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    state: Res<State<GameState>>,
+    cards_collected: Res<CardsCollected>,
+    confirm: Option<Res<ConfirmRestart>>,
+    mut rounds_cleared: ResMut<RoundsCleared>,
+) {
+    if !bindings.just_pressed(&keys, KeyAction::QuickRestart) {
+        return;
+    }
+    match state.get() {
+        // there is no game to restart yet (or one is already being dealt)
+        GameState::Loading | GameState::Deal => return,
+        GameState::Play if confirm.is_none() && ConfirmRestart::is_needed(&cards_collected) => {
+            commands.insert_resource(ConfirmRestart);
+            return;
+        }
+        _ => {}
+    }
+    info!("Quick restarting");
+    commands.remove_resource::<ConfirmRestart>();
+    rounds_cleared.0 = 0;
+    commands.fade_to_state(GameState::Deal);
+}
+
+/// A marker for the HUD text that shows how many of the dealt cards were collected
+/// (in a short game only, see [`CardsDealt`]).
+#[derive(Component, Debug)]
+pub struct ProgressText;
+
+/// A marker for the HUD text that shows how many cards of each suit were collected
+/// (with [`Settings::suit_counts`] only).
+#[derive(Component, Debug)]
+pub struct SuitCountsText;
+
+/// A marker for the HUD text that compares the current game against the best one
+/// (see [`LifetimeStats::split_difference`]).
+#[derive(Component, Debug)]
+pub struct SplitText;
+
+/// A marker for the overlay that asks the player to confirm restarting (see [`ConfirmRestart`]).
+#[derive(Component, Debug)]
+pub struct RestartDialog;
+
+/// System that spawns the heads-up display when entering the [`GameState::Play`] state.
+///
+/// The HUD is despawned when exiting [`GameState::Play`]. No part of the HUD blocks the pointer
+/// from reaching the cards.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_hud(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    seed: Res<GameSeed>,
+    mode: Res<GameMode>,
+    rounds_cleared: Res<RoundsCleared>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    stats: Res<LifetimeStats>,
+    cards_dealt: Res<CardsDealt>,
+) {
+    let font_component = TextFont {
+        font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+        font_size: 16.0,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::End,
+                padding: UiRect::all(Val::Px(8.0)),
+                ..Default::default()
+            },
+            Pickable::IGNORE,
+            DespawnOnExit(GameState::Play),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.0),
+                    right: Val::Px(0.0),
+                    ..Default::default()
+                },
+                Pickable::IGNORE,
+            ))
+            .with_children(|corner| {
+                spawn_button(
+                    corner,
+                    font_component.clone(),
+                    settings.ui_theme,
+                    // embedded the FontAwesome refresh icon via unicode code-point
+                    "\u{F021} Restart",
+                    ButtonAction::Restart(false),
+                );
+            });
+            if !cards_dealt.is_full_deck() {
+                root.spawn((
+                    ProgressText,
+                    Text::default(),
+                    TextColor(Color::WHITE),
+                    font_component.clone(),
+                    Pickable::IGNORE,
+                ));
+            }
+            if settings.suit_counts {
+                root.spawn((
+                    SuitCountsText,
+                    Text::default(),
+                    TextColor(Color::WHITE.with_alpha(0.75)),
+                    font_component.clone(),
+                    Pickable::IGNORE,
+                ));
+            }
+            if *mode == GameMode::HotSeat {
+                root.spawn((
+                    TurnText,
+                    Text::new("Player 1's turn"),
+                    TextColor(Color::WHITE),
+                    font_component.clone(),
+                    Pickable::IGNORE,
+                ));
+            }
+            if *mode == GameMode::Zen {
+                root.spawn((
+                    Text::new(format!("Rounds cleared: {}", rounds_cleared.0)),
+                    TextColor(Color::WHITE.with_alpha(0.75)),
+                    font_component.clone(),
+                    Pickable::IGNORE,
+                ));
+            }
+            // without a best game, there is nothing to compare against
+            if !stats.best_splits.is_empty() {
+                root.spawn((
+                    SplitText,
+                    Text::default(),
+                    TextColor(Color::WHITE.with_alpha(0.75)),
+                    font_component.clone(),
+                    Pickable::IGNORE,
+                ));
+            }
+            root.spawn((
+                Text::new(format!(
+                    "Seed: {} ({:?} to copy)",
+                    seed.0,
+                    bindings.key(KeyAction::CopySeed)
+                )),
+                TextColor(Color::WHITE.with_alpha(0.75)),
+                font_component,
+                Pickable::IGNORE,
+            ));
+        });
+}
+
+/// System that runs during [`GameState::Play`] to update the [`ProgressText`] (e.g. `Collected 3/10`).
+pub fn update_progress_text(
+    cards_collected: Res<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+    query: Query<&mut Text, With<ProgressText>>,
+) {
+    for mut text in query {
+        text.0 = format!("Collected {}/{}", cards_collected.0, cards_dealt.0);
+    }
+}
+
+/// System that runs during [`GameState::Play`] to update the [`SuitCountsText`] (e.g. `♣ 3/13  ♦ 0/13 ...`).
+///
+/// In a short game (see [`CardsDealt`]), not every suit's cards were all dealt, so only the counts are shown.
+pub fn update_suit_counts(
+    suits_collected: Res<SuitsCollected>,
+    cards_dealt: Res<CardsDealt>,
+    query: Query<&mut Text, With<SuitCountsText>>,
+) {
+    let per_suit = Rank::list().len();
+    for mut text in query {
+        text.0 = Suit::list()
+            .iter()
+            .map(|suit| {
+                let collected = suits_collected.get(*suit);
+                if cards_dealt.is_full_deck() {
+                    format!("{} {collected}/{per_suit}", suit.glyph())
+                } else {
+                    format!("{} {collected}", suit.glyph())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+    }
+}
+
+/// System that runs during [`GameState::Play`] to show whether the player is ahead of (or behind)
+/// the best game in the [`SplitText`].
+pub fn update_split_text(
+    stats: Res<LifetimeStats>,
+    timeline: Res<PickTimeline>,
+    round_clock: Res<RoundClock>,
+    mut text: Single<(&mut Text, &mut TextColor), With<SplitText>>,
+) {
+    let Some(difference) = stats.split_difference(&timeline.splits(), round_clock.0.elapsed_secs())
+    else {
+        return;
+    };
+    let (ref mut text, ref mut color) = *text;
+    let (label, tint) = if difference > 0.0 {
+        ("BEHIND", Color::srgb(1.0, 0.6, 0.6))
+    } else {
+        ("AHEAD", Color::srgb(0.6, 1.0, 0.6))
+    };
+    text.0 = format!("{label} by {:.1}s", difference.abs());
+    color.0 = tint.with_alpha(0.75);
+}
+
+/// System that runs during [`GameState::Play`] to show (or hide) the [`RestartDialog`]
+/// while the [`ConfirmRestart`] resource exists.
+///
+/// The dialog covers the whole screen, so the cards cannot be pressed while it is shown.
+pub fn update_restart_dialog(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    cards_collected: Res<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+    confirm: Option<Res<ConfirmRestart>>,
+    dialog: Query<Entity, With<RestartDialog>>,
+) {
+    if confirm.is_none() {
+        for entity in dialog {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !dialog.is_empty() {
+        return;
+    }
+    let theme = settings.ui_theme;
+    let font_component = TextFont {
+        font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+        font_size: 24.0,
+        ..Default::default()
+    };
+    commands
+        .spawn((
+            RestartDialog,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            // this backdrop blocks the pointer from reaching the cards
+            BackgroundColor(Color::BLACK.with_alpha(0.5)),
+            GlobalZIndex(15),
+            DespawnOnExit(GameState::Play),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(16.0)),
+                    border_radius: BorderRadius::all(Val::Px(8.0)),
+                    ..Default::default()
+                },
+                BackgroundColor(theme.background()),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new("Restart? You'll lose current progress"),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                ));
+                panel.spawn((
+                    Text::new(format!(
+                        "You picked up {} of {} cards.",
+                        cards_collected.0, cards_dealt.0
+                    )),
+                    TextColor(theme.muted_text()),
+                    font_component.clone(),
+                ));
+                panel
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        ..Default::default()
+                    })
+                    .with_children(|row| {
+                        spawn_button(
+                            row,
+                            font_component.clone(),
+                            theme,
+                            "Yes",
+                            ButtonAction::Restart(true),
+                        );
+                        spawn_button(
+                            row,
+                            font_component.clone(),
+                            theme,
+                            "No",
+                            ButtonAction::CancelRestart,
+                        );
+                    });
+            });
+        });
+}
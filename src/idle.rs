@@ -0,0 +1,112 @@
+use bevy::{
+    input::{
+        keyboard::KeyboardInput, mouse::MouseButtonInput, mouse::MouseWheel, touch::TouchInput,
+    },
+    prelude::*,
+};
+
+use crate::{settings::Settings, state::GameState};
+
+/// Resource that tracks when (in real seconds since startup) the player last gave any input.
+#[derive(Resource, Debug, Default)]
+pub struct LastInput(pub f32);
+
+/// The overlay that dims the screen (and asks "Still playing?") while the player is idle.
+///
+/// The overlay never blocks the pointer, so clicking a card both picks it up and clears the overlay.
+#[derive(Component, Debug)]
+pub struct IdleDim;
+
+impl IdleDim {
+    /// How long (in seconds) the screen takes to dim.
+    const FADE_DURATION: f32 = 2.0;
+    /// How dark the screen gets (the overlay's opacity) when fully dimmed.
+    const MAX_OPACITY: f32 = 0.6;
+}
+
+/// System that records the time of any keyboard, mouse, or touch input in [`LastInput`].
+///
+/// This uses real time, so slow motion (or pausing) doesn't delay the dimming.
+pub fn track_input(
+    time: Res<Time<Real>>,
+    mut last_input: ResMut<LastInput>,
+    mut keys: MessageReader<KeyboardInput>,
+    mut buttons: MessageReader<MouseButtonInput>,
+    mut cursor: MessageReader<CursorMoved>,
+    mut wheel: MessageReader<MouseWheel>,
+    mut touches: MessageReader<TouchInput>,
+) {
+    // all readers must be drained, so they don't report stale input later
+    let any_input = keys.read().count()
+        + buttons.read().count()
+        + cursor.read().count()
+        + wheel.read().count()
+        + touches.read().count()
+        > 0;
+    if any_input {
+        last_input.0 = time.elapsed_secs();
+    }
+}
+
+/// System that restarts the idle time when a game starts, so a long stay in the menus doesn't count.
+pub fn reset_last_input(time: Res<Time<Real>>, mut last_input: ResMut<LastInput>) {
+    last_input.0 = time.elapsed_secs();
+}
+
+/// System that runs during [`GameState::Play`] to dim the screen after [`Settings::idle_timeout`]
+/// seconds without input.
+///
+/// The screen dims gradually, and the overlay is removed as soon as there is any input.
+/// Only the overlay changes, so animations in flight carry on underneath it.
+pub fn update_idle_dim(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time<Real>>,
+    settings: Res<Settings>,
+    last_input: Res<LastInput>,
+    mut overlay: Query<(Entity, &mut BackgroundColor, &Children), With<IdleDim>>,
+    mut prompts: Query<&mut TextColor>,
+) {
+    let idle = time.elapsed_secs() - last_input.0 - settings.idle_timeout;
+    if settings.idle_timeout <= 0.0 || idle < 0.0 {
+        for (entity, ..) in &overlay {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    let fraction = (idle / IdleDim::FADE_DURATION).min(1.0);
+    if let Ok((_, mut background, children)) = overlay.single_mut() {
+        background.0 = Color::BLACK.with_alpha(IdleDim::MAX_OPACITY * fraction);
+        for child in children {
+            if let Ok(mut color) = prompts.get_mut(*child) {
+                color.0 = Color::WHITE.with_alpha(fraction);
+            }
+        }
+        return;
+    }
+    commands.spawn((
+        IdleDim,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        Pickable::IGNORE,
+        GlobalZIndex(8),
+        DespawnOnExit(GameState::Play),
+        children![(
+            Text::new("Still playing?"),
+            TextFont {
+                font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+                font_size: 32.0,
+                ..default()
+            },
+            TextColor(Color::NONE),
+            Pickable::IGNORE,
+        )],
+    ));
+}
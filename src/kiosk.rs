@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{fade::FadeCommandsExt, state::GameState};
+
+/// The environment variable that enables the [`KioskMode`] (if set to anything other than `0`).
+const KIOSK_ENV_VAR: &str = "CARD_PICKUP_KIOSK";
+
+/// The command line flag that enables the [`KioskMode`].
+const KIOSK_FLAG: &str = "--kiosk";
+
+/// Resource that exists when the game runs unattended (like a demo on a kiosk).
+///
+/// The game skips the main menu and deals right away. When a game ends (won or given up),
+/// a new game is dealt after a short delay instead of waiting on the end screen.
+///
+/// This is enabled with the `--kiosk` command line flag or the `CARD_PICKUP_KIOSK=1` environment variable
+/// (neither is available on the web).
+#[derive(Resource, Debug)]
+pub struct KioskMode {
+    /// Tracks how long the end screen is shown before the next game is dealt.
+    redeal: Timer,
+}
+
+impl KioskMode {
+    /// How long (in seconds) the end screen is shown before the next game is dealt.
+    const REDEAL_DELAY: f32 = 5.0;
+
+    /// Is the kiosk mode requested by the command line or the environment?
+    fn is_requested() -> bool {
+        std::env::args().any(|arg| arg == KIOSK_FLAG)
+            || std::env::var(KIOSK_ENV_VAR).is_ok_and(|value| !value.is_empty() && value != "0")
+    }
+}
+
+impl Default for KioskMode {
+    fn default() -> Self {
+        Self {
+            redeal: Timer::from_seconds(Self::REDEAL_DELAY, TimerMode::Once),
+        }
+    }
+}
+
+/// System that enables the [`KioskMode`] at startup (if requested).
+pub fn detect_kiosk_mode(mut commands: Commands) {
+    if KioskMode::is_requested() {
+        info!("Running in kiosk mode");
+        commands.init_resource::<KioskMode>();
+    }
+}
+
+/// System that restarts the [`KioskMode`]'s delay when a game ends.
+pub fn reset_kiosk_redeal(mut kiosk: ResMut<KioskMode>) {
+    kiosk.redeal.reset();
+}
+
+/// System that runs on the end screens ([`GameState::Win`] and [`GameState::Lose`]) in [`KioskMode`]
+/// to deal a new game once the delay is over.
+pub fn kiosk_redeal(mut commands: Commands, time: Res<Time>, mut kiosk: ResMut<KioskMode>) {
+    if kiosk.redeal.tick(time.delta()).just_finished() {
+        info!("Kiosk mode is dealing a new game");
+        commands.fade_to_state(GameState::Deal);
+    }
+}
@@ -0,0 +1,791 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cards::{BOARD_HALF_SIZE, CARD_HALF_SIZE, CARD_THICKNESS, Card, Rank, Suit},
+    deck::DECK_SIZE,
+    settings::Settings,
+};
+
+/// Maps a point from the board's layout space onto the table.
+///
+/// The layout space is what the game is designed in (collected pile on the +X side).
+/// If [`Settings::mirror_board`] is enabled, the X axis is negated (for left-handed players).
+/// The layout is then turned by the [`Settings::board_rotation`].
+pub fn to_table(settings: &Settings, point: Vec3) -> Vec3 {
+    let point = if settings.mirror_board {
+        point.with_x(-point.x)
+    } else {
+        point
+    };
+    settings.board_rotation.quat() * point
+}
+
+/// Maps a point from the table back into the board's layout space (the inverse of [`to_table`]).
+pub fn from_table(settings: &Settings, point: Vec3) -> Vec3 {
+    let point = settings.board_rotation.quat().inverse() * point;
+    if settings.mirror_board {
+        point.with_x(-point.x)
+    } else {
+        point
+    }
+}
+
+/// How far the board's layout is turned (clockwise, as seen from the camera) on the table (see [`to_table`]).
+///
+/// This suits displays that are mounted in a table, or players seated at another side of it.
+/// Only the layout (where the cards, deck, and pile are placed) turns; the camera and the cards' faces do not.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BoardRotation {
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl BoardRotation {
+    /// All rotations, in the order they are cycled through.
+    pub fn list() -> &'static [BoardRotation] {
+        &[
+            BoardRotation::Deg0,
+            BoardRotation::Deg90,
+            BoardRotation::Deg180,
+            BoardRotation::Deg270,
+        ]
+    }
+
+    /// The human readable name of the rotation.
+    pub fn label(&self) -> &'static str {
+        match self {
+            BoardRotation::Deg0 => "0°",
+            BoardRotation::Deg90 => "90°",
+            BoardRotation::Deg180 => "180°",
+            BoardRotation::Deg270 => "270°",
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) rotation, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Self::list();
+        let index = list
+            .iter()
+            .position(|rotation| rotation == self)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        *self = list[next as usize];
+    }
+
+    /// The rotation (about the table's vertical axis) from layout space onto the table.
+    pub fn quat(&self) -> Quat {
+        let turns = Self::list()
+            .iter()
+            .position(|rotation| rotation == self)
+            .unwrap_or_default();
+        // the camera looks down with the table's -X axis pointing right, so a negative angle turns clockwise
+        Quat::from_rotation_y(-FRAC_PI_2 * turns as f32)
+    }
+
+    /// The half size (on the table) of an area with the given `half_size` in layout space.
+    ///
+    /// A quarter turn swaps the width and the depth.
+    pub fn extents(&self, half_size: Vec2) -> Vec2 {
+        match self {
+            BoardRotation::Deg0 | BoardRotation::Deg180 => half_size,
+            BoardRotation::Deg90 | BoardRotation::Deg270 => half_size.yx(),
+        }
+    }
+}
+
+/// Where the pile of collected cards sits (in the board's layout space, see [`to_table`]).
+///
+/// The pile can be moved from the [`GameState::PileLayout`](crate::state::GameState::PileLayout) screen.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PileConfig {
+    /// The position of the pile's center along the board's X axis.
+    pub x: f32,
+    /// The position of the pile's center along the board's Z axis.
+    pub z: f32,
+}
+
+impl Default for PileConfig {
+    /// Beside the board's top-left corner (as seen from the camera).
+    fn default() -> Self {
+        Self {
+            x: BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x,
+            z: BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y,
+        }
+    }
+}
+
+impl PileConfig {
+    /// The furthest the pile's center can be from the board's center, which keeps the pile in view.
+    const BOUNDS: Vec2 = Vec2 {
+        x: BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x,
+        y: BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y,
+    };
+
+    /// The pile's center (in layout space) as a point on the table's plane.
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.x, self.z)
+    }
+
+    /// Creates a pile at the given `point` (in layout space), clamped within the [`Self::BOUNDS`].
+    pub fn clamped(point: Vec2) -> Self {
+        let point = point.clamp(-Self::BOUNDS, Self::BOUNDS);
+        Self {
+            x: point.x,
+            z: point.y,
+        }
+    }
+
+    /// The pile beside the board on the side nearest the player (see [`Settings::collect_toward_player`]),
+    /// where `toward` is the direction (on the table's plane) pointing down the screen.
+    ///
+    /// The pile can only sit beside the board's left or right edge (see [`Self::BOUNDS`]), so if the player
+    /// is at the top or bottom edge, the pile goes to that edge's corner on the side of the [`Settings::pile`].
+    /// If that spot would cover the deck pile, the [`Settings::pile`] is used instead.
+    pub fn toward_player(settings: &Settings, toward: Vec2) -> Self {
+        let toward = from_table(settings, Vec3::new(toward.x, 0.0, toward.y)).xz();
+        let Some(toward) = toward.try_normalize() else {
+            return settings.pile;
+        };
+        // where the direction leaves the area the pile may occupy
+        let reach = (Self::BOUNDS / toward.abs()).min_element();
+        let mut point = toward * reach;
+        if point.x.abs() < Self::BOUNDS.x {
+            point.x = Self::BOUNDS.x.copysign(settings.pile.x);
+        }
+        let pile = Self::clamped(point);
+        if pile.is_valid(settings) {
+            pile
+        } else {
+            settings.pile
+        }
+    }
+
+    /// Is the pile clear of the areas that must stay uncovered?
+    ///
+    /// These are the board (where cards are dealt) and the deck pile (see [`deck_position`]).
+    pub fn is_valid(&self, settings: &Settings) -> bool {
+        let position = self.position();
+        let on_board = position.abs().cmplt(BOARD_HALF_SIZE + CARD_HALF_SIZE).all();
+        let deck = from_table(settings, deck_position(settings)).xz();
+        let on_deck = (position - deck).abs().cmplt(CARD_HALF_SIZE * 2.0).all();
+        !on_board && !on_deck
+    }
+}
+
+/// The position of a collected card in the pile, given the number of cards collected so far.
+///
+/// With a [`Settings::pile_fan`], each card is moved that far past the previous one, from the pile
+/// toward the other side of the board (along its Z axis).
+///
+/// Once a pile holds [`Settings::pile_cap`] cards, the next card starts a new pile further along the
+/// board's side. The piles and their fans stay within the board's Z span and skip the deck pile
+/// (see [`pile_distance`]), so a large fan is shrunk until the piles of a whole deck fit.
+pub fn pile_position(settings: &Settings, cards_collected: u8) -> Vec3 {
+    pile_position_at(settings, &settings.pile, cards_collected)
+}
+
+/// Like [`pile_position`], but for a pile at the given `pile` (instead of the [`Settings::pile`]).
+pub fn pile_position_at(settings: &Settings, pile: &PileConfig, cards_collected: u8) -> Vec3 {
+    let (overflow, height) = pile_place(settings.pile_cap, cards_collected);
+    let pile = pile.position();
+    let along = pile_distance(settings, pile, overflow, height.saturating_sub(1));
+    to_table(
+        settings,
+        Vec3::new(
+            pile.x,
+            height as f32 * CARD_THICKNESS,
+            pile.y + fan_direction(pile) * along,
+        ),
+    )
+}
+
+/// Which pile (starting at `0` for the first) the card collected as the `cards_collected`th goes on,
+/// and how many cards that pile holds with it, if each pile holds no more than `cap` (at least `1`) cards.
+fn pile_place(cap: u8, cards_collected: u8) -> (u8, u8) {
+    if cards_collected == 0 {
+        return (0, 0);
+    }
+    let index = cards_collected - 1;
+    (index / cap, index % cap + 1)
+}
+
+/// The gap between neighboring piles, and between a pile and the deck pile.
+const PILE_GAP: f32 = 4.0;
+
+/// How far along the board's side (see [`fan_direction`]) the card at `index` (starting at `0`) of the
+/// `overflow`th pile sits from the first pile at `pile`.
+///
+/// The piles are all beside the board, where the camera's framing ends at their outer edge
+/// (see [`FRAME_HALF_SIZE`](crate::camera::FRAME_HALF_SIZE)), so they share the board's Z span
+/// (see [`fan_span`]) without the part covered by the deck pile (see [`deck_gap`]).
+/// Each pile starts a card's height (and a gap) past where the fan of the pile before it ends.
+/// If the piles of a whole deck would not fit, the fans are shrunk, and then the piles are overlapped
+/// (evenly, so the top of each one still shows).
+fn pile_distance(settings: &Settings, pile: Vec2, overflow: u8, index: u8) -> f32 {
+    let cap = settings.pile_cap;
+    let (gap_start, gap_end) = deck_gap(settings, pile);
+    let span = fan_span(pile) - (gap_end - gap_start);
+    let piles = DECK_SIZE.div_ceil(cap) as f32;
+    let between = CARD_HALF_SIZE.y * 2.0 + PILE_GAP;
+    // how far each pile's fan reaches
+    let fits = |extent: f32| (piles - 1.0) * (extent + between) + extent <= span;
+    let extent = (cap - 1) as f32 * settings.pile_fan;
+    let extent = if fits(extent) {
+        extent
+    } else {
+        ((span - (piles - 1.0) * between) / piles).max(0.0)
+    };
+    let spacing = if piles > 1.0 {
+        (extent + between).min((span - extent) / (piles - 1.0))
+    } else {
+        0.0
+    };
+    let offset = if cap > 1 {
+        extent / (cap - 1) as f32
+    } else {
+        0.0
+    };
+    let along = (overflow as f32 * spacing + index as f32 * offset).min(span);
+    if along <= gap_start {
+        along
+    } else {
+        along + gap_end - gap_start
+    }
+}
+
+/// The part of the board's side (as distances from a pile at `pile`, see [`pile_distance`]) where
+/// a card would cover the deck pile (see [`deck_position`]), which is empty if the deck is elsewhere.
+fn deck_gap(settings: &Settings, pile: Vec2) -> (f32, f32) {
+    let deck = from_table(settings, deck_position(settings)).xz();
+    if (deck.x - pile.x).abs() >= CARD_HALF_SIZE.x * 2.0 {
+        return (0.0, 0.0);
+    }
+    let along = (deck.y - pile.y) * fan_direction(pile);
+    let reach = CARD_HALF_SIZE.y * 2.0 + PILE_GAP;
+    let span = fan_span(pile);
+    (
+        (along - reach).clamp(0.0, span),
+        (along + reach).clamp(0.0, span),
+    )
+}
+
+/// The direction (along the board's Z axis) that a pile at `pile` is fanned out in,
+/// which is toward (and past) the board's center.
+fn fan_direction(pile: Vec2) -> f32 {
+    if pile.y > 0.0 { -1.0 } else { 1.0 }
+}
+
+/// How far the cards' centers of a pile at `pile` can be fanned out without leaving the board's Z span.
+fn fan_span(pile: Vec2) -> f32 {
+    pile.y.abs() + BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y
+}
+
+/// Where the deck pile sits, which is where the cards are dealt from.
+///
+/// The corners are named as seen from the camera (before [`Settings::mirror_board`] is applied).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DealOrigin {
+    /// Beside the board's top-left corner, just below the pile of collected cards.
+    TopLeft,
+    /// Beside the board's top-right corner, opposite the pile of collected cards.
+    #[default]
+    TopRight,
+    /// Beside the board's bottom-left corner.
+    BottomLeft,
+    /// Beside the board's bottom-right corner.
+    BottomRight,
+    /// In the middle of the board.
+    Center,
+}
+
+impl DealOrigin {
+    /// All origins, in the order they are cycled through.
+    pub fn list() -> &'static [DealOrigin] {
+        &[
+            DealOrigin::TopLeft,
+            DealOrigin::TopRight,
+            DealOrigin::BottomLeft,
+            DealOrigin::BottomRight,
+            DealOrigin::Center,
+        ]
+    }
+
+    /// The human readable name of the origin.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DealOrigin::TopLeft => "Top left",
+            DealOrigin::TopRight => "Top right",
+            DealOrigin::BottomLeft => "Bottom left",
+            DealOrigin::BottomRight => "Bottom right",
+            DealOrigin::Center => "Center",
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) origin, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Self::list();
+        let index = list
+            .iter()
+            .position(|origin| origin == self)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        *self = list[next as usize];
+    }
+}
+
+/// The position of the deck pile's base, as chosen by [`Settings::deal_origin`].
+///
+/// The camera looks down with the board's +Z axis pointing up the screen,
+/// so the right side of the screen is the board's -X side.
+pub fn deck_position(settings: &Settings) -> Vec3 {
+    let side = BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x;
+    let top = BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y;
+    let point = match settings.deal_origin {
+        // the collected pile occupies this corner, so the deck sits just below it
+        DealOrigin::TopLeft => Vec3::new(side, 0.0, top - CARD_HALF_SIZE.y * 2.5),
+        DealOrigin::TopRight => Vec3::new(-side, 0.0, top),
+        DealOrigin::BottomLeft => Vec3::new(side, 0.0, -top),
+        DealOrigin::BottomRight => Vec3::new(-side, 0.0, -top),
+        DealOrigin::Center => Vec3::ZERO,
+    };
+    to_table(settings, point)
+}
+
+/// Applies the [`Settings::spread_bias`] to a (uniformly distributed) point on the board,
+/// where `point` is normalized to the board's size (each axis is in the range `-1..=1`).
+///
+/// A negative bias clusters points toward the board's center, and a positive bias spreads them
+/// toward the edges. The result is always within the same (normalized) bounds.
+pub fn apply_spread_bias(settings: &Settings, point: Vec2) -> Vec2 {
+    // the "radius" of a square: any point with the same radius is on the same square ring
+    let radius = point.abs().max_element();
+    if settings.spread_bias == 0.0 || radius == 0.0 {
+        return point;
+    }
+    let exponent = 4.0_f32.powf(-settings.spread_bias);
+    point * (radius.powf(exponent) / radius)
+}
+
+/// How the cards are laid out on the board when dealt.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DealPattern {
+    /// Cards are scattered randomly (see [`Settings::spread_bias`]), so they can pile up on each other.
+    #[default]
+    Scatter,
+    /// Cards are laid out in rows and columns (with a little jitter), shrunk so no card covers another.
+    Grid,
+}
+
+impl DealPattern {
+    /// The human readable name of the pattern.
+    pub fn label(&self) -> &'static str {
+        match self {
+            DealPattern::Scatter => "Scatter",
+            DealPattern::Grid => "Grid",
+        }
+    }
+
+    /// Changes to the other pattern.
+    pub fn toggle(&mut self) {
+        *self = match self {
+            DealPattern::Scatter => DealPattern::Grid,
+            DealPattern::Grid => DealPattern::Scatter,
+        };
+    }
+}
+
+/// How far (at most, either way) a card in a [`DealPattern::Grid`] is moved from its cell's center.
+pub const GRID_JITTER: f32 = 4.0;
+
+/// The most (in degrees, either way) a card in a [`DealPattern::Grid`] is turned (see [`Settings::tilt_range`]).
+pub const GRID_MAX_TILT: f32 = 3.0;
+
+/// The scale of the cards in a [`DealPattern::Grid`] of `size` (columns and rows) that tiles the board.
+///
+/// Each card (turned by up to [`GRID_MAX_TILT`] and moved by up to [`GRID_JITTER`]) must fit in its cell,
+/// so no two cards overlap. Cards are never scaled up.
+fn grid_cell_scale(size: UVec2) -> f32 {
+    let cell_half_size = BOARD_HALF_SIZE / size.as_vec2();
+    // the half size of the area that a full-size card covers at the most tilt
+    let (sin, cos) = GRID_MAX_TILT.to_radians().sin_cos();
+    let tilted = Vec2::new(
+        CARD_HALF_SIZE.x * cos + CARD_HALF_SIZE.y * sin,
+        CARD_HALF_SIZE.x * sin + CARD_HALF_SIZE.y * cos,
+    );
+    ((cell_half_size - GRID_JITTER) / tilted)
+        .min_element()
+        .clamp(0.0, 1.0)
+}
+
+/// How many columns and rows a [`DealPattern::Grid`] of `count` cards uses.
+///
+/// The board is too small to fit 52 full-size cards side by side, so this chooses the grid
+/// where the cards can be the biggest (see [`grid_scale`]).
+pub fn grid_size(count: usize) -> UVec2 {
+    let size = |columns: usize| UVec2::new(columns as u32, count.max(1).div_ceil(columns) as u32);
+    let columns = (1..=count.max(1))
+        .max_by(|&a, &b| grid_cell_scale(size(a)).total_cmp(&grid_cell_scale(size(b))))
+        .unwrap_or(1);
+    size(columns)
+}
+
+/// The scale of the cards in a [`DealPattern::Grid`] of `count` cards, which keeps them from overlapping.
+///
+/// The cards are scaled back up as they are collected.
+pub fn grid_scale(count: usize) -> f32 {
+    grid_cell_scale(grid_size(count))
+}
+
+/// The center of the cell (in the board's layout space) for the card at `index` in a [`DealPattern::Grid`]
+/// of `count` cards. The grid's cells tile the board, so a card (see [`grid_scale`]) stays within its cell.
+pub fn grid_position(index: usize, count: usize) -> Vec2 {
+    let size = grid_size(count);
+    let cell_size = BOARD_HALF_SIZE * 2.0 / size.as_vec2();
+    let cell = UVec2::new(index as u32 % size.x, index as u32 / size.x);
+    // the last row may be shorter, so it is centered by itself
+    let columns = if cell.y + 1 == size.y {
+        (count as u32 - cell.y * size.x).max(1)
+    } else {
+        size.x
+    };
+    let along = |cell: u32, cells: u32, cell_size: f32| {
+        (cell as f32 - (cells - 1) as f32 / 2.0) * cell_size
+    };
+    Vec2::new(
+        along(cell.x, columns, cell_size.x),
+        // the first row is at the top (as seen from the camera)
+        -along(cell.y, size.y, cell_size.y),
+    )
+}
+
+/// The center (in the board's layout space) of the given `card` in the sorted grid of
+/// [`GameMode::Learn`](crate::mode::GameMode::Learn): one row per suit, with the ranks in order (Ace first)
+/// from left to right (as seen from the camera). The grid spans the board, so it stays within the board's bounds.
+///
+/// Neighboring cards in a row overlap, but the corner of each card (with its rank and suit) stays uncovered
+/// if the cards are dealt in order.
+pub fn sorted_position(card: &Card) -> Vec2 {
+    let cap = BOARD_HALF_SIZE - CARD_HALF_SIZE;
+    let columns = Rank::list().len() as f32;
+    let rows = Suit::list().len() as f32;
+    let gap = Vec2::new(cap.x * 2.0 / (columns - 1.0), cap.y * 2.0 / (rows - 1.0));
+    let cell = Vec2::new((card.rank.as_u8() - 1) as f32, card.suit.as_u8() as f32);
+    // the table's +X axis points left (as seen from the camera), and the first row is at the top
+    cap - cell * gap
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::camera::FRAME_HALF_SIZE;
+
+    #[test]
+    fn mirror_negates_x() {
+        let point = Vec3::new(12.0, 3.0, -40.0);
+        let settings = Settings::default();
+        assert_eq!(to_table(&settings, point), point);
+        let mirrored = Settings {
+            mirror_board: true,
+            ..default()
+        };
+        assert_eq!(to_table(&mirrored, point), Vec3::new(-12.0, 3.0, -40.0));
+        assert_eq!(
+            deck_position(&mirrored),
+            deck_position(&settings) * Vec3::new(-1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn mirror_swaps_pile_and_deck_sides() {
+        let settings = Settings::default();
+        let mirrored = Settings {
+            mirror_board: true,
+            ..default()
+        };
+        // the pile is on the +X side (the left, as seen from the camera), and the deck opposite it
+        assert!(pile_position(&settings, 1).x > 0.0);
+        assert!(deck_position(&settings).x < 0.0);
+        assert!(pile_position(&mirrored, 1).x < 0.0);
+        assert!(deck_position(&mirrored).x > 0.0);
+        for cards_collected in [1, 10, DECK_SIZE] {
+            assert_eq!(
+                pile_position(&mirrored, cards_collected),
+                pile_position(&settings, cards_collected) * Vec3::new(-1.0, 1.0, 1.0)
+            );
+        }
+    }
+
+    #[test]
+    fn mirrored_deal_stays_on_board() {
+        let cap = BOARD_HALF_SIZE - CARD_HALF_SIZE;
+        for spread_bias in [-1.0, 0.0, 1.0] {
+            let settings = Settings {
+                spread_bias,
+                ..default()
+            };
+            let mirrored = Settings {
+                mirror_board: true,
+                ..settings.clone()
+            };
+            for x in -4..=4 {
+                for y in -4..=4 {
+                    // where the deal places a card (see `deal`), for a random point on the board
+                    let point = Vec2::new(x as f32, y as f32) / 4.0;
+                    let dealt = apply_spread_bias(&mirrored, point) * cap;
+                    let table = to_table(&mirrored, Vec3::new(dealt.x, 0.0, dealt.y));
+                    assert!(
+                        table.xz().abs().cmple(cap + 0.001).all(),
+                        "{point} is dealt at {table}"
+                    );
+                    let unmirrored = to_table(&settings, Vec3::new(dealt.x, 0.0, dealt.y));
+                    assert_eq!(table.x, -unmirrored.x);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn table_round_trip() {
+        let point = Vec3::new(120.0, 2.0, -75.0);
+        for &board_rotation in BoardRotation::list() {
+            for mirror_board in [false, true] {
+                let settings = Settings {
+                    board_rotation,
+                    mirror_board,
+                    ..default()
+                };
+                let back = from_table(&settings, to_table(&settings, point));
+                assert!(
+                    back.abs_diff_eq(point, 1e-3),
+                    "{board_rotation:?} (mirrored: {mirror_board}) maps {point} back to {back}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotation_turns_pile_and_deck_clockwise() {
+        let side = BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x;
+        let top = BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y;
+        // where the default pile (top-left of the screen) and deck (top-right) land on the table,
+        // remembering that the screen's right is the table's -X side and its top is the table's +Z side
+        let expected = [
+            (
+                BoardRotation::Deg0,
+                Vec2::new(side, top),
+                Vec2::new(-side, top),
+            ),
+            // the pile turns to the top-right, and the deck to the bottom-right
+            (
+                BoardRotation::Deg90,
+                Vec2::new(-top, side),
+                Vec2::new(-top, -side),
+            ),
+            // the pile turns to the bottom-right, and the deck to the bottom-left
+            (
+                BoardRotation::Deg180,
+                Vec2::new(-side, -top),
+                Vec2::new(side, -top),
+            ),
+            // the pile turns to the bottom-left, and the deck to the top-left
+            (
+                BoardRotation::Deg270,
+                Vec2::new(top, -side),
+                Vec2::new(top, side),
+            ),
+        ];
+        for (board_rotation, pile, deck) in expected {
+            let settings = Settings {
+                board_rotation,
+                ..default()
+            };
+            let placed = pile_position(&settings, 1).xz();
+            assert!(
+                placed.abs_diff_eq(pile, 1e-3),
+                "{board_rotation:?} puts the pile at {placed}"
+            );
+            let placed = deck_position(&settings).xz();
+            assert!(
+                placed.abs_diff_eq(deck, 1e-3),
+                "{board_rotation:?} puts the deck at {placed}"
+            );
+        }
+    }
+
+    /// Valid piles beside the board, at its top, middle and bottom (in layout space).
+    fn piles() -> Vec<Vec2> {
+        let bounds = Vec2::new(
+            BOARD_HALF_SIZE.x + CARD_HALF_SIZE.x,
+            BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y,
+        );
+        [-1.0, 1.0]
+            .into_iter()
+            .flat_map(|x| [-1.0, 0.0, 1.0].map(|z| bounds * Vec2::new(x, z)))
+            .collect()
+    }
+
+    #[test]
+    fn grid_cards_stay_on_board_without_overlap() {
+        let (sin, cos) = GRID_MAX_TILT.to_radians().sin_cos();
+        for count in [1, 5, 13, 26, 40, 52] {
+            let scale = grid_scale(count);
+            assert!(
+                scale > 0.5,
+                "cards of a grid of {count} are scaled by {scale}"
+            );
+            // the most a jittered, tilted card can cover
+            let half_size = Vec2::new(
+                CARD_HALF_SIZE.x * cos + CARD_HALF_SIZE.y * sin,
+                CARD_HALF_SIZE.x * sin + CARD_HALF_SIZE.y * cos,
+            ) * scale
+                + GRID_JITTER;
+            let positions = (0..count)
+                .map(|index| grid_position(index, count))
+                .collect::<Vec<_>>();
+            for (index, position) in positions.iter().enumerate() {
+                assert!(
+                    (position.abs() + half_size)
+                        .cmple(BOARD_HALF_SIZE + 0.001)
+                        .all(),
+                    "card {index} of a grid of {count} leaves the board at {position}"
+                );
+                for other in &positions[..index] {
+                    let apart = (*position - *other).abs();
+                    assert!(
+                        apart.cmpge(half_size * 2.0 - 0.001).any(),
+                        "cards of a grid of {count} at {position} and {other} overlap"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn full_fan_stays_in_bounds() {
+        let max = Vec2::new(FRAME_HALF_SIZE.x, BOARD_HALF_SIZE.y);
+        for pile in piles() {
+            for pile_fan in [1.0, 10.0, Settings::PILE_FAN_MAX] {
+                let settings = Settings {
+                    pile: PileConfig::clamped(pile),
+                    pile_fan,
+                    deal_origin: DealOrigin::Center,
+                    ..default()
+                };
+                for cards_collected in 1..=DECK_SIZE {
+                    let center = pile_position(&settings, cards_collected).xz();
+                    assert!(
+                        (center.abs() + CARD_HALF_SIZE).cmple(max + 0.001).all(),
+                        "card {cards_collected} of a fan of {pile_fan} at {pile} is at {center}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn small_fan_keeps_its_offset() {
+        for pile in piles() {
+            for pile_fan in [0.0, 1.0] {
+                let settings = Settings {
+                    pile: PileConfig::clamped(pile),
+                    pile_fan,
+                    deal_origin: DealOrigin::Center,
+                    ..default()
+                };
+                let step = pile_position(&settings, 2) - pile_position(&settings, 1);
+                assert!((step.xz().length() - pile_fan).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn collecting_past_the_cap_starts_new_piles() {
+        for pile_cap in [4, 30, 40] {
+            let places: Vec<_> = (1..=120).map(|n| pile_place(pile_cap, n)).collect();
+            assert!(
+                places
+                    .iter()
+                    .all(|(_, height)| (1..=pile_cap).contains(height))
+            );
+            let piles = places.last().unwrap().0 + 1;
+            assert_eq!(piles, 120_u8.div_ceil(pile_cap));
+        }
+    }
+
+    #[test]
+    fn overflow_piles_stay_in_bounds_off_the_deck() {
+        let max = Vec2::new(FRAME_HALF_SIZE.x, BOARD_HALF_SIZE.y);
+        for &deal_origin in DealOrigin::list() {
+            for pile in piles() {
+                let mut settings = Settings {
+                    pile: PileConfig::clamped(pile),
+                    deal_origin,
+                    ..default()
+                };
+                if !settings.pile.is_valid(&settings) {
+                    continue;
+                }
+                let deck = deck_position(&settings).xz();
+                // every cap the settings allow
+                for pile_cap in Settings::PILE_CAP_MIN..=DECK_SIZE {
+                    for pile_fan in [0.0, 10.0, Settings::PILE_FAN_MAX] {
+                        settings.pile_cap = pile_cap;
+                        settings.pile_fan = pile_fan;
+                        // how far along the board's side each pile starts
+                        let mut starts = Vec::new();
+                        for cards_collected in 1..=DECK_SIZE {
+                            let center = pile_position(&settings, cards_collected).xz();
+                            let case = format!(
+                                "card {cards_collected} (cap {pile_cap}, fan {pile_fan}, pile {pile}, \
+                                 deck {deal_origin:?}) is at {center}"
+                            );
+                            assert!(
+                                (center.abs() + CARD_HALF_SIZE).cmple(max + 0.001).all(),
+                                "{case}"
+                            );
+                            assert!(
+                                (center - deck)
+                                    .abs()
+                                    .cmpge(CARD_HALF_SIZE * 2.0 - 0.001)
+                                    .any(),
+                                "{case} on the deck at {deck}"
+                            );
+                            if pile_place(pile_cap, cards_collected).1 == 1 {
+                                starts.push((center.y - pile.y) * fan_direction(pile));
+                            }
+                        }
+                        assert_eq!(starts.len(), DECK_SIZE.div_ceil(pile_cap) as usize);
+                        // even overlapped, the top of each pile shows past the pile before it
+                        assert!(
+                            starts.windows(2).all(|pair| pair[0] < pair[1]),
+                            "cap {pile_cap}, fan {pile_fan}, pile {pile}: {starts:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn stacked_piles_that_fit_do_not_overlap() {
+        // the default pile has the whole side of the board (the deck is on the other side)
+        for pile_cap in [13, 26, 52] {
+            let settings = Settings {
+                pile_cap,
+                ..default()
+            };
+            let starts: Vec<_> = (0..DECK_SIZE.div_ceil(pile_cap))
+                .map(|pile| pile_position(&settings, pile * pile_cap + 1).z)
+                .collect();
+            for pair in starts.windows(2) {
+                assert!((pair[0] - pair[1]).abs() >= CARD_HALF_SIZE.y * 2.0);
+            }
+        }
+    }
+}
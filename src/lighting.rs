@@ -0,0 +1,124 @@
+use bevy::{
+    light::{NotShadowCaster, NotShadowReceiver},
+    prelude::*,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{camera::CAMERA_DISTANCE, settings::Settings};
+
+/// The color of the light shining on the table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LightTint {
+    /// Plain white light.
+    #[default]
+    Neutral,
+    /// A warm (evening) light.
+    Warm,
+    /// A cool (overcast) light.
+    Cool,
+}
+
+impl LightTint {
+    /// All tints, in the order they are cycled through.
+    pub fn list() -> &'static [LightTint] {
+        &[LightTint::Neutral, LightTint::Warm, LightTint::Cool]
+    }
+
+    /// The human readable name of the tint.
+    pub fn label(&self) -> &'static str {
+        match self {
+            LightTint::Neutral => "Neutral",
+            LightTint::Warm => "Warm",
+            LightTint::Cool => "Cool",
+        }
+    }
+
+    /// The light's color.
+    pub fn color(&self) -> Color {
+        match self {
+            LightTint::Neutral => Color::WHITE,
+            LightTint::Warm => Color::srgb(1.0, 0.85, 0.68),
+            LightTint::Cool => Color::srgb(0.8, 0.9, 1.0),
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) tint, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Self::list();
+        let index = list
+            .iter()
+            .position(|tint| tint == self)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        *self = list[next as usize];
+    }
+}
+
+/// The transform of the light for the direction chosen in [`Settings`].
+///
+/// The camera looks down with the board's +Z axis pointing up the screen,
+/// so an azimuth of `0` shines from the top of the screen and `90` from its right side (the board's -X side).
+pub fn light_transform(settings: &Settings) -> Transform {
+    let elevation = settings.light_elevation.to_radians();
+    let azimuth = settings.light_azimuth.to_radians();
+    let direction = Vec3::new(
+        -elevation.cos() * azimuth.sin(),
+        elevation.sin(),
+        elevation.cos() * azimuth.cos(),
+    );
+    Transform::from_translation(direction * CAMERA_DISTANCE).looking_at(Vec3::ZERO, Vec3::Z)
+}
+
+/// System that applies the light's direction and tint from [`Settings`] whenever they change.
+pub fn apply_light(
+    settings: Res<Settings>,
+    mut lights: Query<(&mut DirectionalLight, &mut Transform)>,
+) {
+    let transform = light_transform(&settings);
+    let color = settings.light_tint.color();
+    for (mut light, mut light_transform) in &mut lights {
+        if *light_transform != transform {
+            *light_transform = transform;
+        }
+        if light.color != color {
+            light.color = color;
+        }
+    }
+}
+
+/// System that applies [`Settings::shadows`] to the lights and all meshes (including newly spawned ones).
+///
+/// Without shadows, meshes neither cast nor receive them, which saves the shadow map passes entirely.
+pub fn apply_shadows(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut lights: Query<&mut DirectionalLight>,
+    all_meshes: Query<Entity, With<Mesh3d>>,
+    new_meshes: Query<Entity, Added<Mesh3d>>,
+) {
+    let meshes = if settings.is_changed() {
+        for mut light in &mut lights {
+            if light.shadows_enabled != settings.shadows {
+                light.shadows_enabled = settings.shadows;
+            }
+        }
+        all_meshes.iter().collect::<Vec<_>>()
+    } else if !settings.shadows {
+        new_meshes.iter().collect()
+    } else {
+        // new meshes cast and receive shadows by default
+        return;
+    };
+    for entity in meshes {
+        if settings.shadows {
+            commands
+                .entity(entity)
+                .try_remove::<(NotShadowCaster, NotShadowReceiver)>();
+        } else {
+            commands
+                .entity(entity)
+                .try_insert((NotShadowCaster, NotShadowReceiver));
+        }
+    }
+}
@@ -1,38 +1,508 @@
 use std::f32::consts::PI;
 
-use bevy::{
-    animation::AnimatedBy,
-    color::palettes::{css::WHITE, tailwind::GREEN_300},
-    prelude::*,
-};
-use rand::{RngExt, rng};
+use bevy::{animation::AnimatedBy, color::palettes::tailwind::GREEN_300, prelude::*};
+use rand::{RngExt, SeedableRng, rngs::StdRng, seq::index::sample};
 
+mod card_mesh;
+use card_mesh::{card_mesh, double_sided};
 mod cards;
+mod cheat;
 use cards::{
-    BOARD_HALF_SIZE, CARD_HALF_SIZE, CARD_THICKNESS, Card, CardBundle, CardMaterial, shuffle_deck,
+    BOARD_HALF_SIZE, CARD_HALF_SIZE, CARD_THICKNESS, Card, CardAspect, CardBack, CardBundle,
+    CardMaterial, DoubleSidedCardMaterial, load_card_aspect, measure_card_aspect,
+    shuffle_deck_seeded, sorted_deck,
+};
+use cheat::instant_win;
+mod analytics;
+use analytics::{
+    AnalyticsSession, log_deal, log_give_up, log_pickup, log_restart, log_settings, log_win,
 };
 mod animator;
-use animator::{collect_card, pressed_card};
+mod autocollect;
+use autocollect::{AutoCollect, auto_collect};
+mod backdrop;
+mod billboard;
+use backdrop::{drift_cards, spawn_drifting_cards};
+use billboard::billboard_cards;
+mod clipboard;
+mod console;
+mod controls;
+mod countdown;
+use animator::{
+    collect_card, pick_nearest, pick_up_card, pressed_card, quick_pick, restore_time_speed,
+    settle_collected_cards,
+};
+use console::{DebugConsole, console_input, toggle_console, update_console_text};
+use controls::{
+    KeyBindings, Rebinding, capture_rebinding, controls_menu, load_key_bindings, save_key_bindings,
+};
+use countdown::{Countdown, spawn_cursor_ring, tick_countdown, update_cursor_ring};
+mod audio;
+use audio::{load_sounds, play_last_card_chime, play_shuffle};
+mod camera;
+use camera::{
+    CameraController, MainCamera, apply_camera, apply_graphics_quality, edge_pan, frame_camera,
+    reset_camera_pan, scroll_zoom,
+};
+mod dealing;
+use dealing::{DealFlight, SlideSoundCooldown, fly_in};
+mod deck;
+use deck::{
+    DECK_SIZE, DeckRemaining, recover_empty_board, restock_deck, spawn_deck, update_deck,
+    verify_dealt_cards,
+};
+mod export;
+use export::{capture_board_snapshots, export_board};
+mod fade;
+use fade::{ScreenFade, spawn_fade_overlay, update_fade};
+mod fallback;
+mod finish;
+mod focus;
+mod foundation;
+mod freeze;
+use fallback::{
+    FallbackTexture, hold_loading_faces, show_loaded_faces, substitute_missing_font,
+    substitute_missing_textures,
+};
+use finish::{collect_rest, update_collect_rest_button};
+use focus::{SettingsFocus, navigate_settings, show_settings_focus};
+use foundation::{Foundations, shake_cards};
+use freeze::{FreezeFrame, clear_freeze_frame, hold_freeze_frame};
+mod gallery;
+use gallery::{gallery_menu, load_visible_cards, scroll_on_wheel};
+mod glow;
+use glow::{LastPickup, draw_idle_glow, note_pickup, reset_last_pickup};
+mod heatmap;
+use heatmap::{PickTimeline, heatmap_menu};
+mod highlight;
+mod hitbox;
+use highlight::highlight_topmost_card;
+use hitbox::{ShowHitboxes, draw_hitboxes, toggle_hitboxes};
+use hotseat::{CurrentPlayer, PlayerScores, update_turn_text};
+use kiosk::{KioskMode, detect_kiosk_mode, kiosk_redeal, reset_kiosk_redeal};
+mod hotseat;
+mod hud;
+mod idle;
+use hud::{
+    ConfirmRestart, clear_restart_confirm, quick_restart, spawn_hud, update_progress_text,
+    update_restart_dialog, update_split_text, update_suit_counts,
+};
+use idle::{LastInput, reset_last_input, track_input, update_idle_dim};
+mod kiosk;
+mod layout;
+use layout::{
+    DealPattern, GRID_JITTER, GRID_MAX_TILT, apply_spread_bias, deck_position, grid_position,
+    grid_scale, sorted_position, to_table,
+};
+mod lighting;
+use lighting::{apply_light, apply_shadows, light_transform};
 mod menu;
-use menu::{button_detector, hello_menu, win_menu};
+mod milestone;
+mod minimap;
+mod misclick;
+mod mode;
+use menu::{
+    ActivatedButtons, button_detector, button_feedback, hello_menu, lose_menu, settings_menu,
+    update_setting_values, win_menu,
+};
+use milestone::{celebrate_milestone, update_milestone_sparks};
+use minimap::{spawn_minimap, update_minimap};
+use misclick::{fade_penalty_indicators, penalize_misclick};
+use mode::{DrillSuit, GameMode, RoundsCleared, update_drill_suit_label, update_mode_label};
+mod pause;
+use pause::{FocusPause, auto_pause, clear_focus_pause, update_pause_overlay};
+mod persist;
+mod pile_layout;
+use pile_layout::{pile_layout_menu, update_pile_marker};
+mod read;
+use read::{released_read_card, update_read_holds};
+mod reveal;
+use reveal::reveal_cards;
+mod scatter;
+use scatter::{Scatter, clear_scatter, give_up, tumble};
+mod seed;
+mod select;
+use seed::{GameSeed, NextSeed, copy_seed, paste_seed};
+use select::{box_select, delayed_pickups};
+mod settings;
+mod shimmer;
+mod speech;
+use settings::{Settings, load_settings, save_settings};
+use shimmer::{ShimmerBack, ShimmerCard, animate_shimmer, build_shimmer_sheet, freeze_shimmer};
+use speech::{Announcer, speak_announcements};
 mod state;
-use state::{CardsCollected, GameState};
-
-const CAMERA_DISTANCE: f32 = 668.0;
+mod stats;
+use stats::{
+    LifetimeStats, RoundClock, load_stats, record_loss, save_stats, statistics_menu,
+    tick_round_clock,
+};
+mod table;
+mod theme;
+use state::{CardsCollected, CardsDealt, GameState, PileSlots, SuitsCollected, reset_round};
+use table::{apply_table_rails, spawn_table_rails};
+mod toast;
+use toast::expire_toasts;
+mod tooltip;
+use tooltip::update_tooltips;
+use trail::{emit_trails, fade_trail_ghosts};
+use tutorial::update_tutorial_overlay;
+use vignette::{apply_vignette, spawn_vignette};
+mod touch;
+mod trail;
+mod tutorial;
+mod vignette;
+use touch::{released_card, update_touch_holds};
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, MeshPickingPlugin))
+        .add_plugins((
+            DefaultPlugins,
+            MeshPickingPlugin,
+            MaterialPlugin::<CardMaterial>::default(),
+            MaterialPlugin::<DoubleSidedCardMaterial>::default(),
+        ))
         .init_state::<GameState>()
-        .add_systems(Startup, setup_world)
-        .init_resource::<Assets<CardMaterial>>()
+        .add_systems(
+            Startup,
+            (
+                (
+                    load_settings,
+                    load_stats,
+                    load_key_bindings,
+                    detect_kiosk_mode,
+                    load_card_aspect,
+                ),
+                (
+                    setup_world,
+                    spawn_deck,
+                    load_sounds,
+                    spawn_fade_overlay,
+                    spawn_vignette,
+                ),
+            )
+                .chain(),
+        )
         .init_resource::<CardsCollected>()
-        .add_systems(OnEnter(GameState::Deal), deal)
-        .add_systems(OnEnter(GameState::Win), win_menu)
-        .add_systems(OnEnter(GameState::Menu), hello_menu)
+        .init_resource::<CardsDealt>()
+        .init_resource::<AnalyticsSession>()
+        .init_resource::<ActivatedButtons>()
+        .init_resource::<SettingsFocus>()
+        .init_resource::<DeckRemaining>()
+        .init_resource::<Settings>()
+        .init_resource::<CameraController>()
+        .init_resource::<GameSeed>()
+        .init_resource::<NextSeed>()
+        .init_resource::<SlideSoundCooldown>()
+        .init_resource::<FallbackTexture>()
+        .init_resource::<AutoCollect>()
+        .init_resource::<LastPickup>()
+        .init_resource::<ShimmerBack>()
+        .init_resource::<GameMode>()
+        .init_resource::<DrillSuit>()
+        .init_resource::<RoundsCleared>()
+        .init_resource::<LifetimeStats>()
+        .init_resource::<RoundClock>()
+        .init_resource::<KeyBindings>()
+        .init_resource::<Countdown>()
+        .init_resource::<Foundations>()
+        .init_resource::<PickTimeline>()
+        .init_resource::<PileSlots>()
+        .init_resource::<SuitsCollected>()
+        .init_resource::<CurrentPlayer>()
+        .init_resource::<PlayerScores>()
+        .init_resource::<LastInput>()
+        .init_resource::<Announcer>()
+        .add_systems(
+            OnEnter(GameState::Deal),
+            (
+                restock_deck,
+                reset_camera_pan,
+                reset_round,
+                play_shuffle,
+                deal,
+                log_deal,
+            )
+                .chain(),
+        )
+        .add_systems(
+            OnTransition {
+                exited: GameState::Play,
+                entered: GameState::Win,
+            },
+            log_win,
+        )
+        .add_systems(
+            OnTransition {
+                exited: GameState::Play,
+                entered: GameState::Lose,
+            },
+            log_give_up,
+        )
+        .add_systems(
+            OnTransition {
+                exited: GameState::Play,
+                entered: GameState::Deal,
+            },
+            log_restart,
+        )
+        .add_systems(Update, (fly_in, update_deck).chain())
+        .add_systems(OnEnter(GameState::Win), (win_menu, spawn_drifting_cards))
+        .add_systems(OnEnter(GameState::Menu), (hello_menu, spawn_drifting_cards))
+        .add_systems(OnEnter(GameState::Settings), settings_menu)
+        .add_systems(OnEnter(GameState::Lose), (lose_menu, record_loss))
+        .add_systems(
+            OnEnter(GameState::Win),
+            reset_kiosk_redeal.run_if(resource_exists::<KioskMode>),
+        )
+        .add_systems(
+            OnEnter(GameState::Lose),
+            reset_kiosk_redeal.run_if(resource_exists::<KioskMode>),
+        )
+        .add_systems(
+            Update,
+            kiosk_redeal
+                .run_if(resource_exists::<KioskMode>)
+                .run_if(in_state(GameState::Win).or(in_state(GameState::Lose))),
+        )
+        .add_systems(OnEnter(GameState::Statistics), statistics_menu)
+        .add_systems(OnEnter(GameState::Gallery), gallery_menu)
+        .add_systems(OnEnter(GameState::Controls), controls_menu)
+        .add_systems(OnEnter(GameState::Heatmap), heatmap_menu)
+        .add_systems(OnEnter(GameState::PileLayout), pile_layout_menu)
+        .add_systems(
+            OnEnter(GameState::Play),
+            (
+                spawn_hud,
+                spawn_cursor_ring,
+                verify_dealt_cards,
+                reset_last_input,
+                reset_last_pickup,
+                spawn_minimap,
+            ),
+        )
         .add_observer(collect_card)
+        .add_observer(log_pickup)
+        .add_observer(collect_rest)
+        .add_observer(celebrate_milestone)
+        .add_observer(penalize_misclick)
+        .add_observer(pick_up_card)
+        .add_observer(note_pickup)
         .add_systems(Update, button_detector.run_if(in_state(GameState::Menu)))
+        .add_systems(
+            Update,
+            (
+                button_detector,
+                update_restart_dialog,
+                update_progress_text,
+                update_split_text,
+                update_suit_counts,
+                update_turn_text,
+                update_collect_rest_button,
+                update_minimap.run_if(|settings: Res<Settings>| settings.minimap),
+                // a fade may already be leaving the round
+                recover_empty_board.run_if(not(resource_exists::<ScreenFade>)),
+            )
+                .run_if(in_state(GameState::Play)),
+        )
         .add_systems(Update, button_detector.run_if(in_state(GameState::Win)))
+        .add_systems(Update, button_detector.run_if(in_state(GameState::Heatmap)))
+        .add_systems(
+            Update,
+            (button_detector, update_pile_marker).run_if(in_state(GameState::PileLayout)),
+        )
+        .add_systems(
+            Update,
+            (navigate_settings, button_detector, show_settings_focus)
+                .chain()
+                .run_if(in_state(GameState::Settings)),
+        )
+        .add_systems(Update, button_detector.run_if(in_state(GameState::Lose)))
+        .add_systems(Update, button_detector.run_if(in_state(GameState::Gallery)))
+        .add_systems(
+            Update,
+            button_detector.run_if(in_state(GameState::Statistics)),
+        )
+        .add_systems(
+            Update,
+            (
+                button_detector,
+                capture_rebinding.run_if(resource_exists::<Rebinding>),
+            )
+                .run_if(in_state(GameState::Controls)),
+        )
+        .add_systems(Update, (scroll_on_wheel, load_visible_cards))
+        .add_systems(
+            Update,
+            (
+                update_setting_values,
+                update_mode_label,
+                update_drill_suit_label,
+            ),
+        )
+        .add_systems(Update, (expire_toasts, update_tutorial_overlay))
+        .add_systems(Update, drift_cards)
+        .add_systems(Update, (emit_trails, fade_trail_ghosts))
+        .add_systems(Update, (update_milestone_sparks, fade_penalty_indicators))
+        .add_systems(
+            Update,
+            (
+                build_shimmer_sheet.run_if(|shimmer: Res<ShimmerBack>| !shimmer.is_checked()),
+                animate_shimmer,
+                freeze_shimmer,
+            ),
+        )
+        .add_systems(Update, apply_shadows)
+        .add_systems(Update, apply_light.run_if(resource_changed::<Settings>))
+        .add_systems(Update, log_settings.run_if(resource_changed::<Settings>))
+        .add_systems(Update, apply_vignette.run_if(resource_changed::<Settings>))
+        .add_systems(
+            Update,
+            apply_table_rails.run_if(resource_changed::<Settings>),
+        )
+        .add_systems(Update, speak_announcements)
+        .add_systems(Update, capture_board_snapshots)
+        .add_systems(Update, update_fade.run_if(resource_exists::<ScreenFade>))
+        .add_systems(
+            Update,
+            (
+                substitute_missing_textures,
+                substitute_missing_font,
+                (hold_loading_faces, show_loaded_faces).chain(),
+            ),
+        )
+        .add_systems(
+            Update,
+            measure_card_aspect.run_if(|aspect: Res<CardAspect>| !aspect.is_measured()),
+        )
+        .add_systems(
+            Update,
+            (
+                toggle_hitboxes.run_if(
+                    not(resource_exists::<Rebinding>).and(not(resource_exists::<DebugConsole>)),
+                ),
+                draw_hitboxes.run_if(resource_exists::<ShowHitboxes>),
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            (
+                // the console's key should be rebindable instead of toggling the console
+                toggle_console.run_if(not(resource_exists::<Rebinding>)),
+                (console_input, update_console_text)
+                    .chain()
+                    .run_if(resource_exists::<DebugConsole>),
+            )
+                .chain(),
+        )
+        .add_systems(
+            Update,
+            (
+                copy_seed,
+                quick_pick,
+                pick_nearest,
+                box_select,
+                delayed_pickups,
+                instant_win.run_if(not(resource_exists::<FreezeFrame>)),
+                export_board,
+                auto_collect.run_if(not(resource_exists::<FreezeFrame>)),
+            )
+                .run_if(in_state(GameState::Play))
+                .run_if(not(resource_exists::<DebugConsole>))
+                .run_if(not(resource_exists::<ConfirmRestart>))
+                .run_if(not(resource_exists::<FocusPause>)),
+        )
+        .add_systems(
+            Update,
+            quick_restart
+                .run_if(not(resource_exists::<Rebinding>))
+                .run_if(not(resource_exists::<DebugConsole>)),
+        )
+        .add_systems(
+            Update,
+            paste_seed
+                .run_if(in_state(GameState::Menu).or(in_state(GameState::Settings)))
+                .run_if(not(resource_exists::<DebugConsole>)),
+        )
+        .add_systems(Update, save_settings.run_if(resource_changed::<Settings>))
+        .add_systems(Last, save_settings.run_if(on_message::<AppExit>))
+        .add_systems(
+            Update,
+            apply_graphics_quality.run_if(resource_changed::<Settings>),
+        )
+        .add_systems(
+            Update,
+            save_key_bindings.run_if(resource_changed::<KeyBindings>),
+        )
+        .add_systems(
+            Update,
+            // avoid writing the file with every click during play
+            save_stats
+                .run_if(not(in_state(GameState::Play)).and(resource_changed::<LifetimeStats>)),
+        )
+        .add_systems(
+            Update,
+            (
+                update_tooltips,
+                update_touch_holds,
+                update_read_holds,
+                shake_cards,
+                play_last_card_chime,
+                tick_round_clock,
+                (
+                    tick_countdown.run_if(not(resource_exists::<ConfirmRestart>)),
+                    update_cursor_ring,
+                )
+                    .chain(),
+                update_idle_dim.after(track_input),
+                billboard_cards,
+                highlight_topmost_card,
+                draw_idle_glow,
+                reveal_cards,
+                settle_collected_cards,
+                (auto_pause, update_pause_overlay).chain(),
+            )
+                .run_if(in_state(GameState::Play)),
+        )
+        .add_systems(Update, track_input)
+        .add_systems(Update, button_feedback)
+        .add_systems(
+            Update,
+            (
+                give_up
+                    .run_if(not(resource_exists::<ConfirmRestart>))
+                    .run_if(not(resource_exists::<FocusPause>))
+                    .run_if(not(resource_exists::<FreezeFrame>)),
+                tumble.run_if(resource_exists::<Scatter>),
+                hold_freeze_frame.run_if(resource_exists::<FreezeFrame>),
+            )
+                .chain()
+                .run_if(in_state(GameState::Play))
+                .run_if(not(resource_exists::<DebugConsole>)),
+        )
+        .add_systems(
+            OnExit(GameState::Play),
+            (
+                clear_scatter,
+                clear_freeze_frame,
+                restore_time_speed,
+                clear_restart_confirm,
+                clear_focus_pause,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    edge_pan,
+                    scroll_zoom.run_if(not(resource_exists::<ConfirmRestart>)),
+                )
+                    .run_if(in_state(GameState::Play)),
+                frame_camera,
+                apply_camera,
+            )
+                .chain(),
+        )
         .run();
 }
 
@@ -44,19 +514,27 @@ fn setup_world(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut game_state: ResMut<NextState<GameState>>,
+    settings: Res<Settings>,
+    kiosk: Option<Res<KioskMode>>,
 ) {
+    let camera_controller = CameraController {
+        tilt: settings.camera_tilt.to_radians(),
+        ..default()
+    };
     commands.spawn((
+        MainCamera,
         Camera3d::default(),
-        Transform::from_xyz(0.0, CAMERA_DISTANCE, 0.0).looking_at(Vec3::ZERO, Vec3::Z),
+        camera_controller.projection(),
+        camera_controller.transform(),
     ));
     commands.spawn((
         DirectionalLight {
-            color: Color::from(WHITE),
+            color: settings.light_tint.color(),
             illuminance: 500.0,
             shadows_enabled: true,
             ..default()
         },
-        Transform::from_xyz(0.0, CAMERA_DISTANCE, 0.0).looking_at(Vec3::ZERO, Vec3::Z),
+        light_transform(&settings),
     ));
     let floor_mesh = meshes.add(Plane3d::new(Vec3::Y, BOARD_HALF_SIZE * 2.0));
     let table_material = StandardMaterial {
@@ -66,13 +544,31 @@ fn setup_world(
     };
     let floor_material = materials.add(table_material);
     commands.spawn((Mesh3d(floor_mesh), MeshMaterial3d(floor_material)));
-    game_state.set(GameState::Menu);
+    spawn_table_rails(&mut commands, &mut meshes, &mut materials, &settings);
+    // a kiosk skips the main menu
+    game_state.set(if kiosk.is_some() {
+        GameState::Deal
+    } else {
+        GameState::Menu
+    });
 }
 
 /// System that runs when entering the [`GameState::Deal`] state.
 ///
 /// This function shuffles the deck and spawns the cards in random positions on the board.
+/// The shuffle and the positions are derived from the [`GameSeed`], so a seed always reproduces the same board.
+/// Each card flies in from the deck pile (see [`DealFlight`]), one after another.
+/// In [`GameMode::Drill`], only the cards of the [`DrillSuit`] are dealt.
+/// In [`GameMode::Learn`], the whole deck is dealt unshuffled, face-up and untilted in a sorted grid
+/// (see [`sorted_position`]).
+/// Only the first [`Settings::deal_count`] cards of the shuffled deck are dealt (see [`CardsDealt`]).
+/// [`Settings::face_up_cards`] (randomly chosen) cards are dealt face-up.
+/// Each card is turned randomly, up to [`Settings::tilt_range`] either way.
+/// With [`DealPattern::Grid`], the cards are laid out in a grid instead (see [`grid_position`]).
+/// With [`Settings::double_sided_cards`] (and a plain [`CardBack`]), each card is a single mesh
+/// (see [`DoubleSidedCardMaterial`]) instead of a back mesh with a face mesh as its child.
 /// When finished, this also sets the game state to [`GameState::Play`].
+#[allow(clippy::too_many_arguments)]
 fn deal(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -81,61 +577,230 @@ fn deal(
     mut game_state: ResMut<NextState<GameState>>,
     mut animation_graphs: ResMut<Assets<AnimationGraph>>,
     mut animation_clips: ResMut<Assets<AnimationClip>>,
+    mut deck_remaining: ResMut<DeckRemaining>,
+    settings: Res<Settings>,
+    mut next_seed: ResMut<NextSeed>,
+    mut game_seed: ResMut<GameSeed>,
+    mut card_materials: ResMut<Assets<CardMaterial>>,
+    mut double_sided_materials: ResMut<Assets<DoubleSidedCardMaterial>>,
+    card_aspect: Res<CardAspect>,
+    shimmer: Res<ShimmerBack>,
+    // grouped to stay within the number of parameters a system may have
+    (mut cards_dealt, mode, drill_suit): (ResMut<CardsDealt>, Res<GameMode>, Res<DrillSuit>),
 ) {
-    let mut deck = shuffle_deck();
-    let mut rand_ng = rng();
+    game_seed.0 = next_seed.take_or_default(&settings);
+    info!("Dealing with seed {}", game_seed.0);
+    let learning = *mode == GameMode::Learn;
+    let mut deck = if learning {
+        // reversed, so the cards are dealt (popped) in order, each lying on the one before it
+        sorted_deck().into_iter().rev().collect()
+    } else {
+        shuffle_deck_seeded(game_seed.0)
+    };
+    if *mode == GameMode::Drill {
+        deck.retain(|card| card.suit == drill_suit.0);
+    }
+    // the undealt cards stay in the deck (the sorted grid is always the whole deck)
+    if !learning {
+        deck.truncate(settings.deal_count as usize);
+    }
+    cards_dealt.0 = deck.len() as u8;
+    let mut rand_ng = StdRng::seed_from_u64(game_seed.0);
+    let mut dealt_face_up = vec![learning; deck.len()];
+    if settings.face_up_cards > 0 {
+        let amount = (settings.face_up_cards as usize).min(deck.len());
+        for index in sample(&mut rand_ng, deck.len(), amount) {
+            dealt_face_up[index] = true;
+        }
+    }
 
-    let hover_back = asset_server.load("images/Back Red.png");
-    let hover_material = materials.add(StandardMaterial {
-        base_color_texture: Some(hover_back),
-        alpha_mode: AlphaMode::Mask(0.5),
-        ..default()
-    });
+    // a card shows the other back while hovered
+    let back_images: [Handle<Image>; 2] =
+        Card::back_resource_names().map(|path| asset_server.load(path));
+
+    // the foil back has its own shader, so it always uses a separate face mesh
+    let double_sided_cards = settings.double_sided_cards && settings.card_back == CardBack::Plain;
+    let mut card_mesh = card_mesh(card_aspect.mesh_half_size(), settings.card_corner_radius);
+    if double_sided_cards {
+        card_mesh = double_sided(card_mesh);
+    }
+    let card_mesh = meshes.add(card_mesh);
 
     let mut count = 0.0;
     let cap_x = BOARD_HALF_SIZE.x - CARD_HALF_SIZE.x;
     let cap_y = BOARD_HALF_SIZE.y - CARD_HALF_SIZE.y;
+    let deck_base = deck_position(&settings);
+    let deck_size = deck.len();
+    let mut dealt = 0;
     while let Some(mut card) = deck.pop() {
-        card.playable = true;
+        // cards fly in from the top of the deck, unless motion is reduced
+        let start =
+            Transform::from_translation(deck_base.with_y((deck.len() + 1) as f32 * CARD_THICKNESS))
+                .looking_to(Dir3::Y, Dir3::Z);
+        card.playable = settings.reduced_motion;
+        card.face_up = dealt_face_up[dealt];
         let x = rand_ng.random_range(-cap_x..cap_x);
         let y = rand_ng.random_range(-cap_y..cap_y);
-        let mut transform = Transform::from_xyz(x, count, y).looking_to(Dir3::Y, Dir3::Z);
-        let rand_skew = rand_ng.random_range(-PI..PI);
+        let Vec2 { x, y } = match settings.deal_pattern {
+            _ if learning => sorted_position(&card),
+            DealPattern::Scatter => {
+                apply_spread_bias(&settings, Vec2::new(x / cap_x, y / cap_y))
+                    * Vec2::new(cap_x, cap_y)
+            }
+            // the random point is reused as a small jitter, so a seed deals the same grid
+            DealPattern::Grid => {
+                grid_position(dealt, deck_size) + Vec2::new(x / cap_x, y / cap_y) * GRID_JITTER
+            }
+        };
+        let mut transform =
+            Transform::from_translation(to_table(&settings, Vec3::new(x, count, y)))
+                .looking_to(Dir3::Y, Dir3::Z);
+        if card.face_up {
+            // turn the card over (about the board's vertical axis)
+            transform.rotate_axis(Dir3::Z, PI);
+        }
+        let scale = match settings.deal_pattern {
+            _ if learning => 1.0,
+            DealPattern::Scatter => 1.0,
+            DealPattern::Grid => grid_scale(deck_size),
+        };
+        transform.scale = Vec3::splat(scale);
+        // scaling a full turn (instead of narrowing the range) keeps a seed's positions the same at any tilt
+        let max_tilt = match settings.deal_pattern {
+            // the sorted grid is laid out neatly
+            _ if learning => 0.0,
+            DealPattern::Scatter => settings.tilt_range,
+            // a small tilt keeps the grid's cards from covering each other
+            DealPattern::Grid => settings.tilt_range.min(GRID_MAX_TILT),
+        };
+        let rand_skew = rand_ng.random_range(-PI..PI) * max_tilt / 180.0;
         transform.rotate_axis(Dir3::Y, rand_skew);
-        let card_bundle = CardBundle::new(
+        let back = if settings.mixed_backs && settings.card_back != CardBack::Shimmer {
+            card.mixed_back(game_seed.0)
+        } else {
+            0
+        };
+        let hover = 1 - back;
+        // each card has its own materials, which carry its depth bias
+        let depth_bias = CardBundle::depth_bias(dealt);
+        let back_material = if settings.card_back == CardBack::Shimmer {
+            shimmer.material(depth_bias, &mut materials)
+        } else {
+            CardBundle::back_material(back_images[back].clone(), depth_bias, &mut materials)
+        };
+        let hover_material =
+            CardBundle::back_material(back_images[hover].clone(), depth_bias, &mut materials);
+        let foil_materials = (settings.card_back == CardBack::Foil).then(|| {
+            let mut foil = |texture| {
+                card_materials.add(CardMaterial {
+                    texture,
+                    color: LinearRgba::WHITE,
+                    foil_intensity: 0.6,
+                    depth_bias,
+                })
+            };
+            (foil(back_images[0].clone()), foil(back_images[1].clone()))
+        });
+        let mut card_bundle = CardBundle::new(
             card,
-            &asset_server,
-            &mut materials,
-            &mut meshes,
+            back_material,
+            card_mesh.clone(),
             transform,
             &mut animation_graphs,
             &mut animation_clips,
         );
-        let children = card_bundle.make_children(&asset_server, &mut materials, &mut meshes);
+        let children = (!double_sided_cards).then(|| {
+            card_bundle.make_children(
+                &asset_server,
+                &mut materials,
+                settings.glossy_cards,
+                depth_bias,
+            )
+        });
+        let double_sided = double_sided_cards.then(|| {
+            card_bundle.double_sided_materials(
+                &asset_server,
+                &mut double_sided_materials,
+                back_images[back].clone(),
+                back_images[hover].clone(),
+                settings.glossy_cards,
+                depth_bias,
+            )
+        });
         let card_back_material = card_bundle.material.0.clone();
-        let card_entity = commands
-            .spawn((DespawnOnExit(GameState::Play), card_bundle))
-            .with_children(|parent| {
+        if !settings.reduced_motion {
+            card_bundle.transform = start.with_scale(transform.scale);
+        }
+        let mut card_entity = commands.spawn((DespawnOnExit(GameState::Play), card_bundle));
+        if !settings.reduced_motion {
+            card_entity.insert(DealFlight::new(
+                dealt,
+                start,
+                transform,
+                settings.game_speed,
+            ));
+        }
+        if let Some(children) = children {
+            card_entity.with_children(|parent| {
                 parent.spawn(children);
-            })
-            .observe(update_material_on::<Pointer<Over>>(hover_material.clone()))
-            .observe(update_material_on::<Pointer<Out>>(card_back_material))
+            });
+        }
+        card_entity
             .observe(pressed_card)
-            .observe(update_material_on::<Pointer<Release>>(
-                hover_material.clone(),
-            ))
-            .id();
+            .observe(released_card)
+            .observe(released_read_card);
+        if settings.card_back == CardBack::Shimmer {
+            card_entity.insert(ShimmerCard(card_back_material.clone()));
+        }
+        match (&foil_materials, double_sided) {
+            (_, Some((double_sided, double_sided_hover))) => {
+                card_entity
+                    .remove::<MeshMaterial3d<StandardMaterial>>()
+                    .insert(MeshMaterial3d(double_sided.clone()))
+                    .observe(update_material_on::<Pointer<Over>, _>(
+                        double_sided_hover.clone(),
+                    ))
+                    .observe(update_material_on::<Pointer<Out>, _>(double_sided))
+                    .observe(update_material_on::<Pointer<Release>, _>(
+                        double_sided_hover,
+                    ));
+            }
+            (None, None) => {
+                card_entity
+                    .observe(update_material_on::<Pointer<Over>, _>(
+                        hover_material.clone(),
+                    ))
+                    .observe(update_material_on::<Pointer<Out>, _>(card_back_material))
+                    .observe(update_material_on::<Pointer<Release>, _>(hover_material));
+            }
+            (Some((foil, foil_hover)), None) => {
+                card_entity
+                    .remove::<MeshMaterial3d<StandardMaterial>>()
+                    .insert(MeshMaterial3d(foil.clone()))
+                    .observe(update_material_on::<Pointer<Over>, _>(foil_hover.clone()))
+                    .observe(update_material_on::<Pointer<Out>, _>(foil.clone()))
+                    .observe(update_material_on::<Pointer<Release>, _>(
+                        foil_hover.clone(),
+                    ));
+            }
+        }
+        let card_entity = card_entity.id();
         commands.entity(card_entity).insert(AnimatedBy(card_entity));
+        // each card lies higher than all cards dealt before it (see `CardBundle::DEPTH_BIAS_STEP`)
         count += CARD_THICKNESS;
+        dealt += 1;
+    }
+    if settings.reduced_motion {
+        deck_remaining.0 = DECK_SIZE - cards_dealt.0;
     }
     game_state.set(GameState::Play);
 }
 
 /// Returns an observer that updates the entity's material to the one specified.
 #[allow(clippy::type_complexity)]
-fn update_material_on<E: EntityEvent>(
-    new_material: Handle<StandardMaterial>,
-) -> impl Fn(On<E>, Query<(&Card, &mut MeshMaterial3d<StandardMaterial>)>) {
+fn update_material_on<E: EntityEvent, M: Material>(
+    new_material: Handle<M>,
+) -> impl Fn(On<E>, Query<(&Card, &mut MeshMaterial3d<M>)>) {
     // An observer closure that captures `new_material`. We do this to avoid needing to write four
     // versions of this observer, each triggered by a different event and with a different hardcoded
     // material. Instead, the event type is a generic, and the material is passed in.
@@ -149,3 +814,102 @@ fn update_material_on<E: EntityEvent>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::cards::Suit;
+
+    /// Deals a round with the given `settings`, leaving the spawned cards in the returned app.
+    fn deal_with(settings: Settings) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .init_asset::<CardMaterial>()
+            .init_asset::<DoubleSidedCardMaterial>()
+            .init_asset::<AnimationGraph>()
+            .init_asset::<AnimationClip>()
+            .insert_resource(settings)
+            .insert_resource(DeckRemaining(0))
+            .insert_resource(DrillSuit(Suit::Spades))
+            .init_resource::<NextSeed>()
+            .init_resource::<GameSeed>()
+            .init_resource::<CardsDealt>()
+            .init_resource::<GameMode>()
+            .init_resource::<NextState<GameState>>()
+            .init_resource::<ShimmerBack>();
+        let world = app.world_mut();
+        world.run_system_once(load_card_aspect).unwrap();
+        world.run_system_once(deal).unwrap();
+        app
+    }
+
+    /// The depth bias of each dealt card's back and face, from the lowest card to the highest.
+    fn dealt_biases(app: &mut App) -> Vec<(f32, f32)> {
+        let world = app.world_mut();
+        let mut cards = world.query::<(&Transform, &MeshMaterial3d<StandardMaterial>, &Children)>();
+        let mut biases: Vec<_> = cards
+            .iter(world)
+            .map(|(transform, back, children)| {
+                let materials = world.resource::<Assets<StandardMaterial>>();
+                let face = world.get::<MeshMaterial3d<StandardMaterial>>(children[0]);
+                (
+                    transform.translation.y,
+                    materials.get(back).unwrap().depth_bias,
+                    materials.get(face.unwrap()).unwrap().depth_bias,
+                )
+            })
+            .collect();
+        biases.sort_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+        biases
+            .into_iter()
+            .map(|(_, back, face)| (back, face))
+            .collect()
+    }
+
+    #[test]
+    fn dealt_backs_share_their_face_depth_bias() {
+        for card_back in [CardBack::Plain, CardBack::Shimmer] {
+            let mut app = deal_with(Settings {
+                card_back,
+                reduced_motion: true,
+                ..default()
+            });
+            let biases = dealt_biases(&mut app);
+            assert_eq!(biases.len(), DECK_SIZE as usize);
+            for (back, face) in &biases {
+                assert_eq!(back, face);
+            }
+            // each card's back is favored over the backs of the cards beneath it
+            assert!(biases.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn foil_backs_are_biased() {
+        let mut app = deal_with(Settings {
+            card_back: CardBack::Foil,
+            reduced_motion: true,
+            ..default()
+        });
+        let world = app.world_mut();
+        let mut cards = world.query::<(&Transform, &MeshMaterial3d<CardMaterial>)>();
+        let materials = world.resource::<Assets<CardMaterial>>();
+        let mut biases: Vec<_> = cards
+            .iter(world)
+            .map(|(transform, back)| {
+                (
+                    transform.translation.y,
+                    materials.get(back).unwrap().depth_bias,
+                )
+            })
+            .collect();
+        biases.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        assert_eq!(biases.len(), DECK_SIZE as usize);
+        assert!(biases.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+}
@@ -1,8 +1,97 @@
-use crate::state::GameState;
+use crate::{
+    audio::{Sounds, play_sfx},
+    backdrop::menu_background,
+    controls::{KeyAction, KeyBindings, Rebinding},
+    fade::FadeCommandsExt,
+    finish::CollectRest,
+    focus::{Focusable, SettingRow},
+    gallery::Scrollable,
+    hotseat::PlayerScores,
+    hud::ConfirmRestart,
+    layout::PileConfig,
+    mode::{DrillSuit, DrillSuitLabel, DrillSuitRow, GameMode, GameModeLabel, RoundsCleared},
+    settings::{SettingKind, Settings},
+    state::{CardsCollected, CardsDealt, GameState},
+    stats::{ConfirmStatsReset, LifetimeStats},
+    theme::UiTheme,
+    tutorial::ShowTutorial,
+};
 use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 
+/// Resource that queues the [`ButtonAction`]s of buttons activated without the mouse
+/// (see [`navigate_settings`](crate::focus::navigate_settings)).
+///
+/// The [`button_detector`] performs these like the actions of pressed buttons.
+#[derive(Resource, Debug, Default)]
+pub struct ActivatedButtons(Vec<ButtonAction>);
+
+impl ActivatedButtons {
+    /// Queues the `action` to be performed (as if its button was pressed).
+    pub fn activate(&mut self, action: ButtonAction) {
+        self.0.push(action);
+    }
+}
+
+/// The action performed when a menu button is pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub enum ButtonAction {
+    /// Deals a new game.
+    StartGame,
+    /// Opens the settings screen.
+    OpenSettings,
+    /// Opens the gallery of all card faces.
+    OpenGallery,
+    /// Opens the controls screen.
+    OpenControls,
+    /// Waits for a key press to bind to the given action.
+    Rebind(KeyAction),
+    /// Restores the default [`KeyBindings`].
+    ResetBindings,
+    /// Opens the lifetime statistics screen.
+    OpenStatistics,
+    /// Shows the "How to Play" tutorial over the current screen.
+    OpenTutorial,
+    /// Dismisses the "How to Play" tutorial.
+    CloseTutorial,
+    /// Resets the lifetime statistics if `true`; otherwise asks the player to confirm first.
+    ResetStats(bool),
+    /// Restarts the game in progress if `true`; otherwise asks the player to confirm first
+    /// (unless only a few cards were collected).
+    Restart(bool),
+    /// Dismisses the restart confirmation and resumes the game in progress.
+    CancelRestart,
+    /// Picks up all remaining cards (see [`CollectRest`]).
+    CollectRest,
+    /// Opens the heatmap of when each card was picked up (from the win screen).
+    OpenHeatmap,
+    /// Returns from the heatmap to the win screen.
+    CloseHeatmap,
+    /// Opens the screen where the pile of collected cards is dragged around.
+    OpenPileLayout,
+    /// Moves the pile of collected cards back to its default position.
+    ResetPile,
+    /// Returns to the main menu.
+    Back,
+    /// Adjusts a setting by one step (either `-1` or `1`).
+    Adjust(SettingKind, i8),
+    /// Cycles the [`GameMode`] by one step (either `-1` or `1`).
+    CycleMode(i8),
+    /// Cycles the [`DrillSuit`] by one step (either `-1` or `1`).
+    CycleDrillSuit(i8),
+}
+
 /// System that spawns the menu when entering the [`GameState::Win`] state.
-pub fn win_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+///
+/// In [`GameMode::HotSeat`], this compares the players' [`PlayerScores`].
+pub fn win_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    scores: Res<PlayerScores>,
+    cards_dealt: Res<CardsDealt>,
+) {
+    let theme = settings.ui_theme;
     let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
     let font_component = TextFont {
         font: font.clone(),
@@ -21,7 +110,7 @@ pub fn win_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 padding: UiRect::all(Val::Px(16.0)),
                 ..Default::default()
             },
-            BackgroundColor(Color::BLACK.with_alpha(0.75)),
+            BackgroundColor(menu_background(&settings)),
             DespawnOnExit(GameState::Win),
         ))
         .with_children(|root| {
@@ -41,7 +130,7 @@ pub fn win_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 parent.spawn((
                     // embedded the Octicons rocket icon via unicode code-point
                     Text::new("Congratulations! \u{f427}"),
-                    TextColor(Color::WHITE),
+                    TextColor(theme.text()),
                     TextFont {
                         // nerd font required for code-point to render correctly
                         font: font.clone(),
@@ -49,9 +138,13 @@ pub fn win_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                         ..Default::default()
                     },
                 ));
+                let summary = match *mode {
+                    GameMode::HotSeat => scores.result(),
+                    _ => format!("You picked up all {} cards!", cards_dealt.0),
+                };
                 parent.spawn((
-                    Text::new("You picked up all 52 cards!"),
-                    TextColor(Color::WHITE),
+                    Text::new(summary),
+                    TextColor(theme.text()),
                     TextFont {
                         font: font.clone(),
                         font_size: 32.0,
@@ -60,31 +153,208 @@ pub fn win_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 ));
                 parent.spawn((
                     Text::new("Now go and play a real game. NERD!"),
-                    TextColor(Color::WHITE),
+                    TextColor(theme.text()),
                     font_component.clone(),
                 ));
             });
-            spawn_button(root, font_component.clone());
+            spawn_start_button(root, font_component.clone(), theme);
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome fire icon via unicode code-point
+                "\u{F06D} Heatmap",
+                ButtonAction::OpenHeatmap,
+            );
+            spawn_settings_button(root, font_component.clone(), theme);
         });
 }
 
-/// System that runs during [`GameState::Win`] and [`GameState::Menu`] to detect when the "Start Game" button is pressed.
+/// System that spawns the summary screen when entering the [`GameState::Lose`] state.
+pub fn lose_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    cards_collected: Res<CardsCollected>,
+    cards_dealt: Res<CardsDealt>,
+    settings: Res<Settings>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Lose),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    width: Val::Auto,
+                    height: Val::Auto,
+                    padding: UiRect::all(Val::Px(16.0)),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..Default::default()
+                },
+                BackgroundColor(Color::NONE),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    // embedded the FontAwesome frown icon via unicode code-point
+                    Text::new("Game Over \u{F119}"),
+                    TextColor(theme.text()),
+                    TextFont {
+                        // nerd font required for code-point to render correctly
+                        font: font.clone(),
+                        font_size: 40.0,
+                        ..Default::default()
+                    },
+                ));
+                parent.spawn((
+                    Text::new(format!(
+                        "You picked up {} of {} cards.",
+                        cards_collected.0, cards_dealt.0
+                    )),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                ));
+            });
+            spawn_start_button(root, font_component.clone(), theme);
+            spawn_settings_button(root, font_component.clone(), theme);
+        });
+}
+
+/// System that runs during [`GameState::Play`] and each menu screen (e.g. [`GameState::Menu`] and [`GameState::Settings`])
+/// to detect when a menu button is pressed.
 ///
-/// When the button is pressed, this system sets the game state to [`GameState::Deal`], which starts the game.
+/// The pressed button's [`ButtonAction`] determines what happens (as do the [`ActivatedButtons`]).
+/// For example, the "Start Game" button sets the game state to [`GameState::Deal`], which starts the game.
+#[allow(clippy::too_many_arguments)]
 pub fn button_detector(
-    query: Query<&Interaction, (Changed<Interaction>, With<Button>)>,
+    mut commands: Commands,
+    query: Query<(&Interaction, &ButtonAction), Changed<Interaction>>,
     mut game_state: ResMut<NextState<GameState>>,
+    mut settings: ResMut<Settings>,
+    mut mode: ResMut<GameMode>,
+    mut drill_suit: ResMut<DrillSuit>,
+    mut rounds_cleared: ResMut<RoundsCleared>,
+    mut stats: ResMut<LifetimeStats>,
+    mut bindings: ResMut<KeyBindings>,
+    cards_collected: Res<CardsCollected>,
+    mut activated: ResMut<ActivatedButtons>,
 ) {
-    for interaction in query {
-        if *interaction == Interaction::Pressed {
-            info!("Restarting game");
-            game_state.set(GameState::Deal);
+    let pressed = query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, action)| *action);
+    let actions = pressed.chain(activated.0.drain(..)).collect::<Vec<_>>();
+    for action in &actions {
+        match action {
+            ButtonAction::StartGame => {
+                info!("Restarting game");
+                rounds_cleared.0 = 0;
+                commands.fade_to_state(GameState::Deal);
+            }
+            ButtonAction::OpenSettings => {
+                commands.remove_resource::<Rebinding>();
+                game_state.set(GameState::Settings);
+            }
+            ButtonAction::OpenGallery => game_state.set(GameState::Gallery),
+            ButtonAction::OpenControls => game_state.set(GameState::Controls),
+            ButtonAction::Rebind(key_action) => {
+                commands.insert_resource(Rebinding(*key_action));
+                // re-enter the controls screen to respawn it with the prompt
+                game_state.set(GameState::Controls);
+            }
+            ButtonAction::ResetBindings => {
+                info!("Resetting key bindings");
+                *bindings = KeyBindings::default();
+                commands.remove_resource::<Rebinding>();
+                game_state.set(GameState::Controls);
+            }
+            ButtonAction::OpenStatistics => {
+                commands.remove_resource::<ConfirmStatsReset>();
+                game_state.set(GameState::Statistics);
+            }
+            ButtonAction::OpenTutorial => commands.insert_resource(ShowTutorial),
+            ButtonAction::CloseTutorial => commands.remove_resource::<ShowTutorial>(),
+            ButtonAction::ResetStats(confirmed) => {
+                if *confirmed {
+                    info!("Resetting statistics");
+                    *stats = LifetimeStats::default();
+                    commands.remove_resource::<ConfirmStatsReset>();
+                } else {
+                    commands.insert_resource(ConfirmStatsReset);
+                }
+                // re-enter the statistics screen to respawn it
+                game_state.set(GameState::Statistics);
+            }
+            ButtonAction::Restart(confirmed) => {
+                if *confirmed || !ConfirmRestart::is_needed(&cards_collected) {
+                    info!("Restarting game in progress");
+                    commands.remove_resource::<ConfirmRestart>();
+                    rounds_cleared.0 = 0;
+                    commands.fade_to_state(GameState::Deal);
+                } else {
+                    commands.insert_resource(ConfirmRestart);
+                }
+            }
+            ButtonAction::CancelRestart => commands.remove_resource::<ConfirmRestart>(),
+            ButtonAction::CollectRest => commands.trigger(CollectRest),
+            ButtonAction::OpenHeatmap => game_state.set(GameState::Heatmap),
+            ButtonAction::CloseHeatmap => game_state.set(GameState::Win),
+            ButtonAction::OpenPileLayout => game_state.set(GameState::PileLayout),
+            ButtonAction::ResetPile => {
+                info!("Resetting pile position");
+                settings.pile = PileConfig::default();
+            }
+            ButtonAction::Back => game_state.set(GameState::Menu),
+            ButtonAction::Adjust(kind, step) => {
+                kind.adjust(&mut settings, *step);
+                info!("Changed setting {:?} to {}", kind, kind.value(&settings));
+                if *kind == SettingKind::UiTheme {
+                    // re-enter the settings screen to respawn it with the new theme
+                    game_state.set(GameState::Settings);
+                }
+            }
+            ButtonAction::CycleMode(step) => {
+                mode.cycle(*step);
+                info!("Changed game mode to {:?}", *mode);
+            }
+            ButtonAction::CycleDrillSuit(step) => {
+                drill_suit.cycle(*step);
+                info!("Changed drill suit to {}", drill_suit.0);
+            }
         }
     }
 }
 
 /// System that runs when entering the [`GameState::Menu`] state (on game startup only).
-pub fn hello_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+///
+/// This is where the [`GameMode`] (and the [`DrillSuit`]) is chosen.
+pub fn hello_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mode: Res<GameMode>,
+    drill_suit: Res<DrillSuit>,
+    settings: Res<Settings>,
+) {
+    let theme = settings.ui_theme;
     let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
     let font_component = TextFont {
         font: font.clone(),
@@ -103,7 +373,7 @@ pub fn hello_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 padding: UiRect::all(Val::Px(16.0)),
                 ..Default::default()
             },
-            BackgroundColor(Color::BLACK.with_alpha(0.75)),
+            BackgroundColor(menu_background(&settings)),
             DespawnOnExit(GameState::Menu),
         ))
         .with_children(|root| {
@@ -121,7 +391,7 @@ pub fn hello_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                 children![(
                     // embedded the MD hand wave emoji via unicode code-point
                     Text::new("\u{F1821} Welcome to 52 Card Pickup!"),
-                    TextColor(Color::WHITE),
+                    TextColor(theme.text()),
                     TextFont {
                         // nerd font required for code-point to render correctly
                         font: font.clone(),
@@ -130,29 +400,427 @@ pub fn hello_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                     },
                 )],
             ));
-            spawn_button(root, font_component.clone());
+            root.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..Default::default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("Mode"),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                ));
+                spawn_small_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    "<",
+                    ButtonAction::CycleMode(-1),
+                );
+                row.spawn((
+                    Node {
+                        width: Val::Px(120.0),
+                        justify_content: JustifyContent::Center,
+                        ..Default::default()
+                    },
+                    Text::new(mode.label()),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                    GameModeLabel,
+                ));
+                spawn_small_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    ">",
+                    ButtonAction::CycleMode(1),
+                );
+            });
+            root.spawn((
+                DrillSuitRow,
+                Node {
+                    display: if *mode == GameMode::Drill {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    },
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..Default::default()
+                },
+            ))
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("Suit"),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                ));
+                spawn_small_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    "<",
+                    ButtonAction::CycleDrillSuit(-1),
+                );
+                row.spawn((
+                    Node {
+                        width: Val::Px(120.0),
+                        justify_content: JustifyContent::Center,
+                        ..Default::default()
+                    },
+                    Text::new(drill_suit.label()),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                    DrillSuitLabel,
+                ));
+                spawn_small_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    ">",
+                    ButtonAction::CycleDrillSuit(1),
+                );
+            });
+            spawn_start_button(root, font_component.clone(), theme);
+            spawn_settings_button(root, font_component.clone(), theme);
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome bar-chart icon via unicode code-point
+                "\u{F080} Statistics",
+                ButtonAction::OpenStatistics,
+            );
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome question-circle icon via unicode code-point
+                "\u{F059} How to Play",
+                ButtonAction::OpenTutorial,
+            );
+            root.spawn((
+                Text::new("Tip: press Ctrl+V to play a copied seed"),
+                TextColor(theme.muted_text()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+/// System that spawns the settings screen when entering the [`GameState::Settings`] state.
+///
+/// Each setting is displayed as a row with buttons to decrease/increase (or toggle) its value.
+pub fn settings_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Settings),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                // embedded the FontAwesome cog icon via unicode code-point
+                Text::new("\u{F013} Settings"),
+                TextColor(theme.text()),
+                TextFont {
+                    // nerd font required for code-point to render correctly
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+            ));
+            // the list scrolls (with the mouse wheel) if it does not fit in the window
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    max_height: Val::Percent(65.0),
+                    overflow: Overflow::scroll_y(),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    ..Default::default()
+                },
+                Scrollable,
+            ))
+            .with_children(|list| {
+                for (index, kind) in SettingKind::list().iter().enumerate() {
+                    list.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(8.0),
+                            ..Default::default()
+                        },
+                        Focusable(index),
+                        SettingRow(*kind),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Node {
+                                width: Val::Px(280.0),
+                                ..Default::default()
+                            },
+                            Text::new(kind.label()),
+                            TextColor(theme.text()),
+                            font_component.clone(),
+                        ));
+                        spawn_small_button(
+                            row,
+                            font_component.clone(),
+                            theme,
+                            "-",
+                            ButtonAction::Adjust(*kind, -1),
+                        );
+                        row.spawn((
+                            Node {
+                                width: Val::Px(150.0),
+                                justify_content: JustifyContent::Center,
+                                ..Default::default()
+                            },
+                            Text::new(kind.value(&settings)),
+                            TextLayout::new_with_justify(Justify::Center),
+                            TextColor(theme.text()),
+                            font_component.clone(),
+                            *kind,
+                        ));
+                        spawn_small_button(
+                            row,
+                            font_component.clone(),
+                            theme,
+                            "+",
+                            ButtonAction::Adjust(*kind, 1),
+                        );
+                    });
+                }
+            });
+            // the buttons are focused after the settings (see `navigate_settings`)
+            let rows = SettingKind::list().len();
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome clone icon via unicode code-point
+                "\u{F24D} View All Cards",
+                ButtonAction::OpenGallery,
+            )
+            .insert(Focusable(rows));
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome keyboard icon via unicode code-point
+                "\u{F11C} Controls",
+                ButtonAction::OpenControls,
+            )
+            .insert(Focusable(rows + 1));
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome arrows icon via unicode code-point
+                "\u{F047} Pile Position",
+                ButtonAction::OpenPileLayout,
+            )
+            .insert(Focusable(rows + 2));
+            spawn_button(
+                root,
+                font_component.clone(),
+                theme,
+                // embedded the FontAwesome arrow-left icon via unicode code-point
+                "\u{F060} Back",
+                ButtonAction::Back,
+            )
+            .insert(Focusable(rows + 3));
         });
 }
 
+/// System that updates the displayed setting values when the [`Settings`] resource changes.
+pub fn update_setting_values(settings: Res<Settings>, query: Query<(&mut Text, &SettingKind)>) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (mut text, kind) in query {
+        text.0 = kind.value(&settings);
+    }
+}
+
 /// Spawns the "Start Game" button in the menu, which starts the game when pressed.
-fn spawn_button(commands: &mut RelatedSpawnerCommands<'_, ChildOf>, font_component: TextFont) {
+fn spawn_start_button(
+    commands: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    font_component: TextFont,
+    theme: UiTheme,
+) {
+    spawn_button(
+        commands,
+        font_component,
+        theme,
+        // embedded the FontAwesome Play icon (circle variant) via unicode code-point
+        "\u{F01D} Start Game",
+        ButtonAction::StartGame,
+    );
+}
+
+/// Spawns the "Settings" button in the menu, which opens the settings screen when pressed.
+fn spawn_settings_button(
+    commands: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    font_component: TextFont,
+    theme: UiTheme,
+) {
+    spawn_button(
+        commands,
+        font_component,
+        theme,
+        // embedded the FontAwesome cog icon via unicode code-point
+        "\u{F013} Settings",
+        ButtonAction::OpenSettings,
+    );
+}
+
+/// Spawns a small square button (used for adjusting settings and the game mode).
+fn spawn_small_button(
+    commands: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    font_component: TextFont,
+    theme: UiTheme,
+    label: &str,
+    action: ButtonAction,
+) {
     commands.spawn((
         Button,
+        action,
+        Node {
+            width: Val::Px(40.0),
+            height: Val::Px(40.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            ..Default::default()
+        },
+        BackgroundColor(theme.button()),
+        ButtonColors::new(theme),
+        children![(
+            Text::new(label),
+            TextColor(theme.button_text()),
+            font_component
+        )],
+    ));
+}
+
+/// The background colors of a menu button (see [`button_feedback`]).
+///
+/// These come from the [`UiTheme`] the button was spawned with.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ButtonColors {
+    idle: Color,
+    hovered: Color,
+    pressed: Color,
+}
+
+impl ButtonColors {
+    /// The button colors of the given `theme`.
+    fn new(theme: UiTheme) -> Self {
+        Self {
+            idle: theme.button(),
+            hovered: theme.button_hovered(),
+            pressed: theme.button_pressed(),
+        }
+    }
+}
+
+/// How much a menu button grows while the pointer is over it (see [`button_feedback`]).
+const BUTTON_HOVER_SCALE: f32 = 1.05;
+
+/// System that gives each menu button feedback when its [`Interaction`] changes.
+///
+/// The button's color follows its [`ButtonColors`], and (unless [`Settings::reduced_motion`] is enabled)
+/// the button grows a little while hovered or pressed. A click is played when the button is pressed
+/// (if [`Settings::button_sounds`] is enabled).
+#[allow(clippy::type_complexity)]
+pub fn button_feedback(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sounds: Res<Sounds>,
+    query: Query<
+        (
+            &Interaction,
+            &ButtonColors,
+            &mut BackgroundColor,
+            &mut UiTransform,
+        ),
+        Changed<Interaction>,
+    >,
+) {
+    for (interaction, colors, mut background, mut transform) in query {
+        background.0 = match interaction {
+            Interaction::Pressed => colors.pressed,
+            Interaction::Hovered => colors.hovered,
+            Interaction::None => colors.idle,
+        };
+        let grown = *interaction != Interaction::None && !settings.reduced_motion;
+        transform.scale = Vec2::splat(if grown { BUTTON_HOVER_SCALE } else { 1.0 });
+        if *interaction == Interaction::Pressed && settings.button_sounds {
+            play_sfx(&mut commands, &settings, &sounds.click);
+        }
+    }
+}
+
+/// Spawns a menu button with the given `label`, which performs the given `action` when pressed.
+///
+/// Returns the button's [`EntityCommands`], so more components can be inserted.
+pub fn spawn_button<'a>(
+    commands: &'a mut RelatedSpawnerCommands<'_, ChildOf>,
+    font_component: TextFont,
+    theme: UiTheme,
+    label: &str,
+    action: ButtonAction,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Button,
+        action,
         Node {
             width: Val::Auto,
             height: Val::Auto,
             padding: UiRect::all(Val::Px(16.0)),
+            margin: UiRect::all(Val::Px(8.0)),
             border_radius: BorderRadius::all(Val::Px(8.0)),
             ..Default::default()
         },
-        BackgroundColor(Color::srgb(0.125, 0.85, 0.125)),
+        BackgroundColor(theme.button()),
+        ButtonColors::new(theme),
         children![(
-            // embedded the FontAwesome Play icon (circle variant) via unicode code-point
-            Text::new("\u{F01D} Start Game"),
-            TextColor(Color::WHITE),
+            Text::new(label),
+            TextColor(theme.button_text()),
             // TextFont component (pointing to nerd font asset) must be in same bundle as
             // the Text component for the font to render the unicode code-point correctly.
             font_component,
         )],
-    ));
+    ))
 }
@@ -0,0 +1,98 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{
+    audio::{Sounds, play_sfx},
+    settings::Settings,
+    state::GameState,
+};
+
+/// How many [`MilestoneSpark`]s pop out of the pile at each milestone.
+const SPARK_COUNT: usize = 12;
+
+/// How long (in seconds) a [`MilestoneSpark`] lasts.
+const SPARK_LIFETIME: f32 = 0.6;
+
+/// How fast a [`MilestoneSpark`] flies away from the pile.
+const SPARK_SPEED: f32 = 80.0;
+
+/// The radius of a new [`MilestoneSpark`].
+const SPARK_RADIUS: f32 = 2.5;
+
+/// Event that celebrates collecting another [`Settings::milestone_every`] cards
+/// with a chime and (without reduced motion) a pop of [`MilestoneSpark`]s at the pile.
+///
+/// This is not triggered for the final card, which has its own celebration.
+#[derive(Event, Debug)]
+pub struct Milestone {
+    /// Where the sparks pop out (the top of the pile).
+    pub position: Vec3,
+}
+
+/// Is the given number of collected cards a milestone (see [`Settings::milestone_every`])?
+pub fn is_milestone(settings: &Settings, cards_collected: u8) -> bool {
+    settings.milestone_every > 0 && cards_collected.is_multiple_of(settings.milestone_every)
+}
+
+/// A decorative spark that flies out of the pile and shrinks away (see [`Milestone`]).
+#[derive(Component, Debug)]
+pub struct MilestoneSpark {
+    /// The direction (and speed) the spark flies.
+    velocity: Vec3,
+    /// Tracks how far the spark has shrunk away.
+    timer: Timer,
+}
+
+/// The observer that plays the [`Milestone`] cue.
+pub fn celebrate_milestone(
+    event: On<Milestone>,
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sounds: Res<Sounds>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    play_sfx(&mut commands, &settings, &sounds.last_card);
+    // the sparks are decorative motion
+    if settings.reduced_motion {
+        return;
+    }
+    let mesh = meshes.add(Sphere::new(SPARK_RADIUS));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 0.85, 0.3),
+        unlit: true,
+        ..Default::default()
+    });
+    for i in 0..SPARK_COUNT {
+        let angle = TAU * i as f32 / SPARK_COUNT as f32;
+        let velocity = Vec3::new(angle.cos(), 1.0, angle.sin()).normalize() * SPARK_SPEED;
+        commands.spawn((
+            MilestoneSpark {
+                velocity,
+                timer: Timer::from_seconds(SPARK_LIFETIME, TimerMode::Once),
+            },
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(event.position),
+            Pickable::IGNORE,
+            DespawnOnExit(GameState::Play),
+        ));
+    }
+}
+
+/// System that moves and shrinks each [`MilestoneSpark`], and despawns it once it has vanished.
+pub fn update_milestone_sparks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut sparks: Query<(Entity, &mut MilestoneSpark, &mut Transform)>,
+) {
+    for (entity, mut spark, mut transform) in &mut sparks {
+        if spark.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += spark.velocity * time.delta_secs();
+        transform.scale = Vec3::splat(spark.timer.fraction_remaining());
+    }
+}
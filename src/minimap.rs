@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use crate::{
+    cards::{BOARD_HALF_SIZE, Card},
+    settings::Settings,
+    state::GameState,
+};
+
+/// The width (in pixels) of the [`Minimap`]; its height follows the board's aspect ratio.
+const MINIMAP_WIDTH: f32 = 160.0;
+
+/// The size (in pixels) of each [`MinimapDot`].
+const DOT_SIZE: f32 = 5.0;
+
+/// A marker for the small panel that shows where the remaining cards are on the board
+/// (with [`Settings::minimap`] only).
+#[derive(Component, Debug)]
+pub struct Minimap;
+
+/// A dot on the [`Minimap`] for the held card entity.
+#[derive(Component, Debug)]
+pub struct MinimapDot(Entity);
+
+/// System that spawns the [`Minimap`] when entering [`GameState::Play`] (if [`Settings::minimap`] is enabled).
+pub fn spawn_minimap(mut commands: Commands, settings: Res<Settings>) {
+    if !settings.minimap {
+        return;
+    }
+    commands.spawn((
+        Minimap,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(8.0),
+            width: Val::Px(MINIMAP_WIDTH),
+            height: Val::Px(minimap_size(&settings).y),
+            border_radius: BorderRadius::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.4)),
+        Pickable::IGNORE,
+        DespawnOnExit(GameState::Play),
+    ));
+}
+
+/// System that runs during [`GameState::Play`] to keep a [`MinimapDot`] on the [`Minimap`]
+/// for each playable card (and remove the dots of cards that were picked up).
+///
+/// The map is drawn as the board is seen from the camera (so the table's -X axis points right).
+pub fn update_minimap(
+    mut commands: Commands,
+    minimap: Single<Entity, With<Minimap>>,
+    settings: Res<Settings>,
+    cards: Query<(Entity, &Card, &GlobalTransform)>,
+    mut dots: Query<(Entity, &MinimapDot, &mut Node)>,
+) {
+    let mut shown = Vec::new();
+    for (dot_entity, dot, mut node) in &mut dots {
+        match cards.get(dot.0) {
+            Ok((_, card, transform)) if card.playable => {
+                (node.left, node.top) = dot_position(&settings, transform.translation());
+                shown.push(dot.0);
+            }
+            _ => commands.entity(dot_entity).despawn(),
+        }
+    }
+    for (entity, card, transform) in &cards {
+        if !card.playable || shown.contains(&entity) {
+            continue;
+        }
+        let (left, top) = dot_position(&settings, transform.translation());
+        commands.entity(*minimap).with_child((
+            MinimapDot(entity),
+            Node {
+                position_type: PositionType::Absolute,
+                left,
+                top,
+                width: Val::Px(DOT_SIZE),
+                height: Val::Px(DOT_SIZE),
+                border_radius: BorderRadius::MAX,
+                ..Default::default()
+            },
+            BackgroundColor(Color::WHITE),
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+/// The size (in pixels) of the [`Minimap`], which follows the board's shape on the table
+/// (as turned by [`Settings::board_rotation`]).
+fn minimap_size(settings: &Settings) -> Vec2 {
+    let board = settings.board_rotation.extents(BOARD_HALF_SIZE);
+    Vec2::new(MINIMAP_WIDTH, MINIMAP_WIDTH * board.y / board.x)
+}
+
+/// Where the dot for a card at the given `position` (on the table) goes on the [`Minimap`].
+fn dot_position(settings: &Settings, position: Vec3) -> (Val, Val) {
+    let board = settings.board_rotation.extents(BOARD_HALF_SIZE);
+    // the fraction of the board (from the top-left corner, as seen from the camera)
+    let fraction =
+        ((Vec2::new(-position.x, -position.z) / board + 1.0) / 2.0).clamp(Vec2::ZERO, Vec2::ONE);
+    let center = fraction * minimap_size(settings) - DOT_SIZE / 2.0;
+    (Val::Px(center.x), Val::Px(center.y))
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    cards::Card, countdown::Countdown, mode::GameMode, settings::Settings, state::GameState,
+};
+
+/// How long (in seconds) a [`PenaltyIndicator`] takes to float up and fade out.
+const INDICATOR_LIFETIME: f32 = 0.8;
+
+/// How far (in logical pixels) a [`PenaltyIndicator`] floats up while it fades out.
+const INDICATOR_RISE: f32 = 24.0;
+
+/// A floating "+Ns" text near the cursor, which shows the time lost to a mis-click.
+#[derive(Component, Debug)]
+pub struct PenaltyIndicator {
+    /// Where (in logical pixels) the indicator started.
+    origin: Vec2,
+    /// Tracks how far the indicator has faded out.
+    timer: Timer,
+}
+
+/// The observer that takes [`Settings::misclick_penalty`] seconds off the [`Countdown`]
+/// for a press that cannot pick up a card (in [`GameMode::Timed`] only).
+///
+/// A press on a playable card (or its face) is never penalized, nor is a press on the UI.
+/// Presses on the table are not penalized with [`Settings::box_select`], since they may start a selection.
+#[allow(clippy::too_many_arguments)]
+pub fn penalize_misclick(
+    event: On<Pointer<Press>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mode: Res<GameMode>,
+    state: Res<State<GameState>>,
+    mut countdown: ResMut<Countdown>,
+    cards: Query<&Card>,
+    parents: Query<&ChildOf>,
+    ui_nodes: Query<(), With<Node>>,
+) {
+    // the press bubbles up from a card's face to the card, but is only counted once
+    let entity = event.event_target();
+    if entity != event.original_event_target()
+        || settings.misclick_penalty <= 0.0
+        || *mode != GameMode::Timed
+        || *state.get() != GameState::Play
+        || countdown.0.is_finished()
+        || ui_nodes.contains(entity)
+    {
+        return;
+    }
+    // a card's face is a child of the card
+    let card = cards.get(entity).ok().or_else(|| {
+        parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| cards.get(parent.parent()).ok())
+    });
+    match card {
+        Some(card) if card.playable => return,
+        None if settings.box_select => return,
+        _ => {}
+    }
+    countdown
+        .0
+        .tick(Duration::from_secs_f32(settings.misclick_penalty));
+    info!("Mis-click cost {}s", settings.misclick_penalty);
+    let origin = event.pointer_location.position;
+    commands.spawn((
+        PenaltyIndicator {
+            origin,
+            timer: Timer::from_seconds(INDICATOR_LIFETIME, TimerMode::Once),
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(origin.x + 12.0),
+            top: Val::Px(origin.y),
+            ..default()
+        },
+        Text::new(format!("+{}s", settings.misclick_penalty)),
+        TextColor(Color::srgb(1.0, 0.4, 0.4)),
+        TextFont {
+            font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+            font_size: 20.0,
+            ..default()
+        },
+        // the indicator should never block the pointer from reaching the cards
+        Pickable::IGNORE,
+        GlobalZIndex(10),
+        DespawnOnExit(GameState::Play),
+    ));
+}
+
+/// System that floats each [`PenaltyIndicator`] up while fading it out, and despawns it once invisible.
+pub fn fade_penalty_indicators(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<(Entity, &mut PenaltyIndicator, &mut Node, &mut TextColor)>,
+) {
+    for (entity, mut indicator, mut node, mut color) in query {
+        if indicator.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        node.top = Val::Px(indicator.origin.y - INDICATOR_RISE * indicator.timer.fraction());
+        color.0.set_alpha(indicator.timer.fraction_remaining());
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::cards::Suit;
+
+/// Resource that holds the game mode, which is chosen from the main menu.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    /// Collect all cards to win.
+    #[default]
+    Classic,
+    /// There is no win; the board is re-dealt whenever all cards are collected.
+    Zen,
+    /// Collect all cards before the [`Countdown`](crate::countdown::Countdown) runs out.
+    Timed,
+    /// Collect the cards of each suit in ascending rank order (Ace first), like building foundations.
+    Foundation,
+    /// Two players take turns picking up cards; whoever picks up more cards wins.
+    HotSeat,
+    /// Only the 13 cards of the [`DrillSuit`] are dealt, to practice finding a single suit.
+    Drill,
+    /// All cards are dealt face-up and sorted (one row per suit), to learn to recognize the cards.
+    Learn,
+}
+
+impl GameMode {
+    /// All modes, in the order they are cycled through.
+    pub fn list() -> &'static [GameMode] {
+        &[
+            GameMode::Classic,
+            GameMode::Zen,
+            GameMode::Timed,
+            GameMode::Foundation,
+            GameMode::HotSeat,
+            GameMode::Drill,
+            GameMode::Learn,
+        ]
+    }
+
+    /// The human readable name of the mode.
+    pub fn label(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "Classic",
+            GameMode::Zen => "Zen",
+            GameMode::Timed => "Timed",
+            GameMode::Foundation => "Foundation",
+            GameMode::HotSeat => "Hot Seat",
+            GameMode::Drill => "Suit Drill",
+            GameMode::Learn => "Learn",
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) mode, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Self::list();
+        let index = list
+            .iter()
+            .position(|mode| mode == self)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        *self = list[next as usize];
+    }
+}
+
+/// Resource that holds the suit dealt in [`GameMode::Drill`], which is chosen from the main menu.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrillSuit(pub Suit);
+
+impl Default for DrillSuit {
+    fn default() -> Self {
+        Self(Suit::Clubs)
+    }
+}
+
+impl DrillSuit {
+    /// Changes to the next (or previous if `step` is negative) suit, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Suit::list();
+        let index = list
+            .iter()
+            .position(|suit| *suit == self.0)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        self.0 = list[next as usize];
+    }
+
+    /// The human readable name of the suit (with its glyph).
+    pub fn label(&self) -> String {
+        format!("{} {}", self.0.glyph(), self.0.name())
+    }
+}
+
+/// Resource that counts how many rounds were cleared in a row (in [`GameMode::Zen`]).
+///
+/// This is reset when a game is started from a menu.
+#[derive(Resource, Debug, Default)]
+pub struct RoundsCleared(pub u32);
+
+/// A marker for the text that displays the selected [`GameMode`].
+#[derive(Component, Debug)]
+pub struct GameModeLabel;
+
+/// A marker for the main menu's row that chooses the [`DrillSuit`] (shown in [`GameMode::Drill`] only).
+#[derive(Component, Debug)]
+pub struct DrillSuitRow;
+
+/// A marker for the text that displays the selected [`DrillSuit`].
+#[derive(Component, Debug)]
+pub struct DrillSuitLabel;
+
+/// System that updates the displayed [`DrillSuit`] (and hides its row unless in [`GameMode::Drill`])
+/// when either changes.
+pub fn update_drill_suit_label(
+    mode: Res<GameMode>,
+    drill_suit: Res<DrillSuit>,
+    labels: Query<&mut Text, With<DrillSuitLabel>>,
+    rows: Query<&mut Node, With<DrillSuitRow>>,
+) {
+    if !mode.is_changed() && !drill_suit.is_changed() {
+        return;
+    }
+    for mut text in labels {
+        text.0 = drill_suit.label();
+    }
+    for mut node in rows {
+        node.display = if *mode == GameMode::Drill {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+/// System that updates the displayed [`GameMode`] when it changes.
+pub fn update_mode_label(mode: Res<GameMode>, query: Query<&mut Text, With<GameModeLabel>>) {
+    if !mode.is_changed() {
+        return;
+    }
+    for mut text in query {
+        text.0 = mode.label().to_string();
+    }
+}
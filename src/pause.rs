@@ -0,0 +1,128 @@
+use bevy::{prelude::*, window::WindowFocused};
+
+use crate::{settings::Settings, state::GameState};
+
+/// Resource that exists while the game is paused because the window lost focus
+/// (see [`Settings::auto_pause`]).
+///
+/// Virtual time is paused meanwhile, which freezes the animations and the clocks (like the
+/// [`Countdown`](crate::countdown::Countdown)). Cards cannot be picked up while this resource exists.
+#[derive(Resource, Debug, Default)]
+pub struct FocusPause {
+    /// Counts down (in real time) to resuming, once the window is focused again.
+    resume: Option<Timer>,
+}
+
+impl FocusPause {
+    /// How long (in seconds) the countdown to resuming takes.
+    const RESUME_DELAY: f32 = 3.0;
+}
+
+/// A marker for the overlay displayed while the game is paused (see [`FocusPause`]).
+#[derive(Component, Debug)]
+pub struct PauseOverlay;
+
+/// A marker for the [`PauseOverlay`]'s text (which counts down to resuming).
+#[derive(Component, Debug)]
+pub struct PauseText;
+
+/// System that runs during [`GameState::Play`] to pause the game when the window loses focus
+/// (or is minimized), and to start the countdown to resuming when it regains focus.
+///
+/// Only focus changes are reacted to, so this never fires while the window stays focused.
+pub fn auto_pause(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mut focus: MessageReader<WindowFocused>,
+    mut time: ResMut<Time<Virtual>>,
+    pause: Option<ResMut<FocusPause>>,
+) {
+    let Some(focused) = focus.read().last().map(|event| event.focused) else {
+        return;
+    };
+    match pause {
+        Some(mut pause) if focused => {
+            pause.resume = Some(Timer::from_seconds(
+                FocusPause::RESUME_DELAY,
+                TimerMode::Once,
+            ));
+        }
+        // focus was lost again during the countdown
+        Some(mut pause) => pause.resume = None,
+        None if !focused && settings.auto_pause => {
+            info!("Pausing while the window is not focused");
+            time.pause();
+            commands.init_resource::<FocusPause>();
+        }
+        None => {}
+    }
+}
+
+/// System that runs during [`GameState::Play`] to display the [`PauseOverlay`] (while the game is paused)
+/// and count down to resuming.
+pub fn update_pause_overlay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    real_time: Res<Time<Real>>,
+    mut time: ResMut<Time<Virtual>>,
+    pause: Option<ResMut<FocusPause>>,
+    overlay: Query<Entity, With<PauseOverlay>>,
+    mut text: Query<&mut Text, With<PauseText>>,
+) {
+    let Some(mut pause) = pause else {
+        for entity in overlay {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+    let label = match pause.resume.as_mut() {
+        Some(resume) => {
+            resume.tick(real_time.delta());
+            if resume.is_finished() {
+                info!("Resuming after the window was focused");
+                time.unpause();
+                commands.remove_resource::<FocusPause>();
+                return;
+            }
+            format!("{}", resume.remaining_secs().ceil())
+        }
+        None => "Paused".to_string(),
+    };
+    if let Ok(mut text) = text.single_mut() {
+        if text.0 != label {
+            text.0 = label;
+        }
+        return;
+    }
+    commands.spawn((
+        PauseOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..Default::default()
+        },
+        // this backdrop blocks the pointer from reaching the cards
+        BackgroundColor(Color::BLACK.with_alpha(0.5)),
+        GlobalZIndex(18),
+        DespawnOnExit(GameState::Play),
+        children![(
+            PauseText,
+            Text::new(label),
+            TextFont {
+                font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+                font_size: 48.0,
+                ..Default::default()
+            },
+            TextColor(Color::WHITE),
+        )],
+    ));
+}
+
+/// System that resumes time (and removes the [`FocusPause`]) when exiting [`GameState::Play`].
+pub fn clear_focus_pause(mut commands: Commands, mut time: ResMut<Time<Virtual>>) {
+    commands.remove_resource::<FocusPause>();
+    time.unpause();
+}
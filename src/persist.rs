@@ -0,0 +1,116 @@
+//! Persists data (like [`Settings`](crate::settings::Settings)) to files in the user's config directory.
+//! Logs (like the [analytics](crate::analytics)) are appended to files in the user's data directory instead,
+//! where [exported](crate::export) images are also saved.
+//!
+//! Nothing is persisted on the web; loading always returns [`None`] there.
+
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::prelude::*;
+use serde::{Serialize, de::DeserializeOwned};
+
+/// The path to the persisted file with the given `file_name`.
+#[cfg(not(target_arch = "wasm32"))]
+fn path(file_name: &str) -> Option<std::path::PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(env!("CARGO_PKG_NAME")).join(file_name))
+}
+
+/// Loads the data persisted in the file with the given `file_name`.
+///
+/// Returns [`None`] if there is no persisted data (or it could not be read),
+/// in which case the caller should fall back to defaults.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let path = path(file_name)?;
+    let contents = std::fs::read_to_string(&path).ok()?;
+    match ron::from_str(&contents) {
+        Ok(data) => Some(data),
+        Err(e) => {
+            warn!(
+                "Failed to parse {} (using defaults instead): {e}",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load<T: DeserializeOwned>(_file_name: &str) -> Option<T> {
+    None
+}
+
+/// Persists the `data` to the file with the given `file_name`.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn save<T: Serialize>(file_name: &str, data: &T) {
+    let Some(path) = path(file_name) else {
+        return;
+    };
+    let result = ron::ser::to_string_pretty(data, ron::ser::PrettyConfig::default())
+        .map_err(|e| e.to_string())
+        .and_then(|contents| {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+            }
+            std::fs::write(&path, contents).map_err(|e| e.to_string())
+        });
+    if let Err(e) = result {
+        warn!("Failed to save {}: {e}", path.display());
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn save<T: Serialize>(_file_name: &str, _data: &T) {}
+
+/// The path to the file with the given `file_name` in the user's data directory.
+#[cfg(not(target_arch = "wasm32"))]
+fn data_path(file_name: &str) -> Option<std::path::PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(env!("CARGO_PKG_NAME")).join(file_name))
+}
+
+/// Appends a `line` to the log file with the given `file_name` in the user's data directory
+/// (creating the file if needed).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn append_line(file_name: &str, line: &str) {
+    use std::io::Write;
+
+    let Some(path) = data_path(file_name) else {
+        return;
+    };
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+        })
+        .and_then(|mut file| writeln!(file, "{line}"));
+    if let Err(e) = result {
+        warn!("Failed to append to {}: {e}", path.display());
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn append_line(_file_name: &str, _line: &str) {}
+
+/// The path to save an exported file with the given `file_name` to (in the user's data directory).
+///
+/// The directory is created if needed. Returns [`None`] if there is no data directory (or it could not be created).
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_path(file_name: &str) -> Option<std::path::PathBuf> {
+    let path = data_path(file_name)?;
+    if let Some(dir) = path.parent()
+        && let Err(e) = std::fs::create_dir_all(dir)
+    {
+        warn!("Failed to create {}: {e}", dir.display());
+        return None;
+    }
+    Some(path)
+}
+
+/// On the web, an exported file is downloaded by the browser under the given `file_name`.
+#[cfg(target_arch = "wasm32")]
+pub fn export_path(file_name: &str) -> Option<std::path::PathBuf> {
+    Some(file_name.into())
+}
@@ -0,0 +1,203 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera::MainCamera,
+    card_mesh::card_mesh,
+    cards::{Card, CardBundle},
+    layout::{PileConfig, from_table, pile_position, to_table},
+    menu::{ButtonAction, spawn_button},
+    settings::Settings,
+    state::GameState,
+};
+
+/// A translucent card that marks where the collected cards are piled, dragged to move the pile.
+#[derive(Component, Debug)]
+pub struct PileMarker {
+    /// The material shown while the marker is in a valid spot.
+    valid_material: Handle<StandardMaterial>,
+    /// The material shown while the marker overlaps an area that must stay uncovered.
+    invalid_material: Handle<StandardMaterial>,
+}
+
+impl PileMarker {
+    /// How high above the table the marker floats, so it is drawn over the deck pile.
+    const HEIGHT: f32 = 2.0;
+
+    /// The marker's transform for the pile position in [`Settings`].
+    fn transform(settings: &Settings) -> Transform {
+        Transform::from_translation(pile_position(settings, 0).with_y(Self::HEIGHT))
+            .looking_to(Dir3::Y, Dir3::Z)
+    }
+}
+
+/// System that spawns the pile layout screen when entering the [`GameState::PileLayout`] state.
+///
+/// The board is left uncovered, with a [`PileMarker`] where the collected cards are piled.
+pub fn pile_layout_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+    let marker_material = |color: Color| StandardMaterial {
+        base_color: color.with_alpha(0.6),
+        base_color_texture: Some(asset_server.load(Card::back_resource_name())),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    };
+    let marker = PileMarker {
+        valid_material: materials.add(marker_material(Color::WHITE)),
+        invalid_material: materials.add(marker_material(Color::srgb(1.0, 0.3, 0.3))),
+    };
+    commands
+        .spawn((
+            Mesh3d(meshes.add(card_mesh(
+                CardBundle::MESH_HALF_SIZE,
+                settings.card_corner_radius,
+            ))),
+            MeshMaterial3d(marker.valid_material.clone()),
+            PileMarker::transform(&settings),
+            marker,
+            DespawnOnExit(GameState::PileLayout),
+        ))
+        .observe(drag_pile_marker)
+        .observe(drop_pile_marker);
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                ..Default::default()
+            },
+            // the board must stay reachable for dragging the marker
+            Pickable::IGNORE,
+            DespawnOnExit(GameState::PileLayout),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("Drag the pile beside the board to move it"),
+                TextColor(theme.text()),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+                BackgroundColor(theme.background().with_alpha(0.8)),
+                Pickable::IGNORE,
+            ));
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                Pickable::IGNORE,
+            ))
+            .with_children(|row| {
+                spawn_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    // embedded the FontAwesome rotate-left icon via unicode code-point
+                    "\u{F0E2} Reset",
+                    ButtonAction::ResetPile,
+                );
+                spawn_button(
+                    row,
+                    font_component.clone(),
+                    theme,
+                    // embedded the FontAwesome check icon via unicode code-point
+                    "\u{F00C} Done",
+                    ButtonAction::OpenSettings,
+                );
+            });
+        });
+}
+
+/// The observer that moves the [`PileMarker`] to the point on the table under the cursor.
+///
+/// The marker is clamped within the table's visible bounds (see [`PileConfig::clamped`]).
+/// While it is in a valid spot (see [`PileConfig::is_valid`]), the pile is moved with it.
+/// Otherwise, the marker is tinted red.
+fn drag_pile_marker(
+    event: On<Pointer<Drag>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut settings: ResMut<Settings>,
+    mut marker: Query<(
+        &PileMarker,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    let Ok((marker, mut transform, mut material)) = marker.get_mut(event.event_target()) else {
+        return;
+    };
+    let (camera, camera_transform) = camera.into_inner();
+    let Some(point) = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor).ok())
+        .and_then(|ray| {
+            ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y))
+                .map(|distance| ray.get_point(distance))
+        })
+    else {
+        return;
+    };
+    let pile = PileConfig::clamped(from_table(&settings, point).xz());
+    let position = pile.position();
+    transform.translation = to_table(
+        &settings,
+        Vec3::new(position.x, PileMarker::HEIGHT, position.y),
+    );
+    material.0 = if pile.is_valid(&settings) {
+        if settings.pile != pile {
+            settings.pile = pile;
+        }
+        marker.valid_material.clone()
+    } else {
+        marker.invalid_material.clone()
+    };
+}
+
+/// The observer that puts a dropped [`PileMarker`] back where the pile is (in case it was dropped in an invalid spot).
+fn drop_pile_marker(
+    event: On<Pointer<DragEnd>>,
+    settings: Res<Settings>,
+    mut marker: Query<(
+        &PileMarker,
+        &mut Transform,
+        &mut MeshMaterial3d<StandardMaterial>,
+    )>,
+) {
+    if let Ok((marker, mut transform, mut material)) = marker.get_mut(event.event_target()) {
+        *transform = PileMarker::transform(&settings);
+        material.0 = marker.valid_material.clone();
+    }
+}
+
+/// System that moves the [`PileMarker`] to the pile's position whenever it changes (like when it is reset).
+pub fn update_pile_marker(
+    settings: Res<Settings>,
+    mut marker: Query<&mut Transform, With<PileMarker>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut transform in &mut marker {
+        *transform = PileMarker::transform(&settings);
+    }
+}
@@ -0,0 +1,117 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    animator::{AnimationInfo, AnimatorNodeId, PressableCards, press_card},
+    audio::{Sounds, play_flip},
+    cards::Card,
+    settings::Settings,
+    stats::LifetimeStats,
+};
+
+/// A card that is being held (with [`Settings::hold_to_read`]), which is flipped face-up and lifted
+/// so the player can read it.
+///
+/// Releasing the press over the card picks it up. Releasing it anywhere else (like back onto the table)
+/// puts the card back down, face-down, without picking it up.
+#[derive(Component, Debug)]
+pub struct ReadHold {
+    /// Where the card was lying face-down.
+    origin: Transform,
+    /// Has the card been lifted (see [`update_read_holds`])?
+    lifted: bool,
+}
+
+impl ReadHold {
+    /// How high (in world units) a held card is lifted.
+    const LIFT: f32 = 10.0;
+
+    /// Starts holding a card lying face-down at the given `origin`.
+    pub fn new(origin: Transform) -> Self {
+        Self {
+            origin,
+            lifted: false,
+        }
+    }
+
+    /// Where the card lies face-up in place (like a card flipped with [`Settings::two_stage_pickup`]).
+    fn face_up(&self) -> Transform {
+        let mut face_up = self.origin;
+        face_up.rotate_axis(Dir3::Z, PI);
+        face_up
+    }
+}
+
+/// System that flips each newly held card face-up (lifted above the cards around it),
+/// and puts it back down if the press was released away from it.
+///
+/// A card that was picked up some other way (like with [`Settings::quick_pick`]) while held is let go.
+pub fn update_read_holds(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    sounds: Res<Sounds>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut query: Query<(Entity, &mut ReadHold, &mut Transform, &Card)>,
+) {
+    for (entity, mut hold, mut transform, card) in &mut query {
+        if !card.playable {
+            commands.entity(entity).remove::<ReadHold>();
+            continue;
+        }
+        if buttons.get_pressed().next().is_none() {
+            // the press ended without a release over this card
+            *transform = hold.origin;
+            commands.entity(entity).remove::<ReadHold>();
+            continue;
+        }
+        if !hold.lifted {
+            hold.lifted = true;
+            *transform = hold.face_up();
+            transform.translation.y += ReadHold::LIFT;
+            play_flip(&mut commands, &settings, &sounds);
+        }
+    }
+}
+
+/// System that runs when a press on a held card (see [`ReadHold`]) is released over it.
+///
+/// The card is put down face-up in place (so its pick up animation starts there), and then picked up
+/// (see [`press_card`]). Only this counts the card as collected.
+#[allow(clippy::type_complexity)]
+pub fn released_read_card(
+    entity_event: On<Pointer<Release>>,
+    mut commands: Commands,
+    mut stats: ResMut<LifetimeStats>,
+    settings: Res<Settings>,
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>,
+    mut cards: ParamSet<(
+        PressableCards,
+        Query<(&ReadHold, &mut Transform, &mut Card)>,
+    )>,
+) {
+    let entity = entity_event.event_target();
+    let mut holds = cards.p1();
+    let Ok((hold, mut transform, mut card)) = holds.get_mut(entity) else {
+        return;
+    };
+    commands.entity(entity).remove::<ReadHold>();
+    if !card.playable {
+        return;
+    }
+    *transform = hold.face_up();
+    card.face_up = true;
+    let AnimationInfo {
+        graph, node_index, ..
+    } = AnimationInfo::create(
+        &transform,
+        &card,
+        &mut animation_graphs,
+        &mut animation_clips,
+    );
+    commands
+        .entity(entity)
+        .insert((AnimationGraphHandle(graph), AnimatorNodeId(node_index)));
+    press_card(&mut commands, &mut stats, &settings, &cards.p0(), entity);
+}
@@ -0,0 +1,81 @@
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::{
+    animator::{AnimationInfo, AnimatorNodeId, GameSpeed},
+    cards::{CARD_HALF_SIZE, Card},
+};
+
+/// The time a card takes to flip face-up in place.
+pub const REVEAL_DURATION: f32 = 0.4;
+
+/// Animates a card flipping face-up in place (see [`Settings::two_stage_pickup`](crate::settings::Settings::two_stage_pickup)).
+///
+/// The card is not playable until it lands face-up; another press then picks it up.
+#[derive(Component, Debug)]
+pub struct Reveal {
+    /// The time the card spends flipping.
+    pub timer: Timer,
+    /// Where the card was lying face-down.
+    pub start: Transform,
+    /// Where the card lies face-up.
+    pub end: Transform,
+}
+
+impl Reveal {
+    /// Creates a flip (at the given `speed`) for a card lying face-down at `start`.
+    pub fn new(start: Transform, speed: GameSpeed) -> Self {
+        let mut end = start;
+        // turn the card over (like a card dealt face-up)
+        end.rotate_axis(Dir3::Z, PI);
+        Self {
+            timer: Timer::from_seconds(REVEAL_DURATION / speed.factor(), TimerMode::Once),
+            start,
+            end,
+        }
+    }
+
+    /// Skips to the end of the flip (like when [`Settings::reduced_motion`](crate::settings::Settings::reduced_motion)
+    /// is enabled).
+    pub fn finish(&mut self) {
+        self.timer.finish();
+    }
+}
+
+/// System that flips each card along its [`Reveal`].
+///
+/// The card rises (by half its height, so it never clips the cards around it) while it turns over.
+/// When it lands, its pick up animation is rebuilt from the face-up transform, and it becomes playable again.
+pub fn reveal_cards(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>,
+    mut query: Query<(Entity, &mut Reveal, &mut Transform, &mut Card)>,
+) {
+    for (entity, mut reveal, mut transform, mut card) in &mut query {
+        reveal.timer.tick(time.delta());
+        let fraction = reveal.timer.fraction();
+        let progress = EaseFunction::SmoothStep.sample_clamped(fraction);
+        let lift = CARD_HALF_SIZE.y * (fraction * PI).sin();
+        transform.translation = reveal.start.translation + Vec3::Y * lift;
+        transform.rotation = reveal.start.rotation.slerp(reveal.end.rotation, progress);
+        if reveal.timer.is_finished() {
+            *transform = reveal.end;
+            card.playable = true;
+            let AnimationInfo {
+                graph, node_index, ..
+            } = AnimationInfo::create(
+                &transform,
+                &card,
+                &mut animation_graphs,
+                &mut animation_clips,
+            );
+            commands
+                .entity(entity)
+                .remove::<Reveal>()
+                .insert((AnimationGraphHandle(graph), AnimatorNodeId(node_index)));
+        }
+    }
+}
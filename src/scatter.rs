@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use rand::{RngExt, rng};
+
+use crate::{
+    cards::Card,
+    controls::{KeyAction, KeyBindings},
+    dealing::DealFlight,
+    fade::FadeCommandsExt,
+    settings::Settings,
+    state::GameState,
+};
+
+/// The acceleration (in world units per second squared) pulling tumbling cards down.
+const GRAVITY: f32 = 980.0;
+
+/// Gives a card a velocity so it can tumble off the board.
+#[derive(Component, Debug)]
+pub struct Tumbling {
+    /// The linear velocity (in world units per second).
+    pub velocity: Vec3,
+    /// The angular velocity (as a scaled axis, in radians per second).
+    pub angular_velocity: Vec3,
+}
+
+/// Resource that exists while the remaining cards are scattering off the board.
+#[derive(Resource, Debug)]
+pub struct Scatter(pub Timer);
+
+impl Scatter {
+    /// How long the cards scatter before the summary is shown.
+    pub const DURATION: f32 = 2.0;
+}
+
+/// System that runs during [`GameState::Play`] to detect when the player gives up (by pressing the
+/// [`KeyAction::GiveUp`] key, Escape by default).
+///
+/// All remaining (uncollected) cards are scattered off the board before the
+/// [`GameState::Lose`] summary is shown. If [`Settings::reduced_motion`] is enabled,
+/// the summary is shown immediately.
+pub fn give_up(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    settings: Res<Settings>,
+    scatter: Option<Res<Scatter>>,
+    mut query: Query<(Entity, &mut Card, &Transform, Has<DealFlight>)>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    if scatter.is_some() || !bindings.just_pressed(&keys, KeyAction::GiveUp) {
+        return;
+    }
+    info!("Giving up");
+    if settings.reduced_motion {
+        game_state.set(GameState::Lose);
+        return;
+    }
+    let mut rand_ng = rng();
+    for (entity, mut card, transform, is_dealing) in &mut query {
+        if !card.playable && !is_dealing {
+            continue;
+        }
+        card.playable = false;
+        commands.entity(entity).remove::<DealFlight>();
+        // fling cards away from the board's center
+        let outward = transform
+            .translation
+            .with_y(0.0)
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        let velocity = outward * rand_ng.random_range(300.0..600.0)
+            + Vec3::Y * rand_ng.random_range(200.0..400.0);
+        let angular_velocity = Vec3::new(
+            rand_ng.random_range(-10.0..10.0),
+            rand_ng.random_range(-10.0..10.0),
+            rand_ng.random_range(-10.0..10.0),
+        );
+        commands.entity(entity).insert(Tumbling {
+            velocity,
+            angular_velocity,
+        });
+    }
+    commands.insert_resource(Scatter(Timer::from_seconds(
+        Scatter::DURATION,
+        TimerMode::Once,
+    )));
+}
+
+/// System that stops the cards from scattering when leaving [`GameState::Play`] early
+/// (e.g. when a new game is dealt mid-scatter).
+pub fn clear_scatter(mut commands: Commands) {
+    commands.remove_resource::<Scatter>();
+}
+
+/// System that moves the [`Tumbling`] cards (with simple gravity) while the cards [`Scatter`].
+///
+/// When finished (or skipped with any key or mouse button), this sets the game state to [`GameState::Lose`].
+pub fn tumble(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut scatter: ResMut<Scatter>,
+    mut query: Query<(&mut Tumbling, &mut Transform)>,
+) {
+    let delta = time.delta_secs();
+    for (mut tumbling, mut transform) in &mut query {
+        tumbling.velocity.y -= GRAVITY * delta;
+        transform.translation += tumbling.velocity * delta;
+        transform.rotation =
+            Quat::from_scaled_axis(tumbling.angular_velocity * delta) * transform.rotation;
+    }
+    scatter.0.tick(time.delta());
+    let skipped =
+        keys.get_just_pressed().next().is_some() || mouse.get_just_pressed().next().is_some();
+    if scatter.0.is_finished() || skipped {
+        commands.remove_resource::<Scatter>();
+        commands.fade_to_state(GameState::Lose);
+    }
+}
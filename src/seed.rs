@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use rand::{RngExt, rng};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    clipboard,
+    controls::{KeyAction, KeyBindings},
+    settings::Settings,
+    toast::Toast,
+};
+
+/// How the seed of each new game is chosen (see [`Settings::seed_mode`]).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeedMode {
+    /// Each game is dealt with a fresh random seed.
+    #[default]
+    Random,
+    /// Each game is dealt with the [`Settings::fixed_seed`], so every game has the same board.
+    Fixed,
+}
+
+impl SeedMode {
+    /// All modes, in the order they are cycled through.
+    pub fn list() -> &'static [SeedMode] {
+        &[SeedMode::Random, SeedMode::Fixed]
+    }
+
+    /// The human readable name of the mode.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SeedMode::Random => "Random",
+            SeedMode::Fixed => "Fixed",
+        }
+    }
+
+    /// Changes to the next (or previous if `step` is negative) mode, wrapping around.
+    pub fn cycle(&mut self, step: i8) {
+        let list = Self::list();
+        let index = list
+            .iter()
+            .position(|mode| mode == self)
+            .unwrap_or_default();
+        let next = (index as isize + step as isize).rem_euclid(list.len() as isize);
+        *self = list[next as usize];
+    }
+}
+
+/// Resource that holds the seed used to deal the current game.
+///
+/// Dealing with the same seed reproduces the exact same board.
+#[derive(Resource, Debug, Default)]
+pub struct GameSeed(pub u64);
+
+/// Resource that holds a seed chosen by the player for the next deal.
+///
+/// If [`None`], a random seed is used.
+#[derive(Resource, Debug, Default)]
+pub struct NextSeed(pub Option<u64>);
+
+impl NextSeed {
+    /// Takes the chosen seed (if any), or else the seed that the [`Settings::seed_mode`] calls for
+    /// (a random one, or the [`Settings::fixed_seed`]).
+    ///
+    /// A seed chosen for the next deal (like one pasted from the clipboard) is only used once,
+    /// even with [`SeedMode::Fixed`].
+    pub fn take_or_default(&mut self, settings: &Settings) -> u64 {
+        self.0.take().unwrap_or_else(|| match settings.seed_mode {
+            SeedMode::Random => rng().random(),
+            SeedMode::Fixed => settings.fixed_seed,
+        })
+    }
+}
+
+/// System that runs during [`GameState::Play`](crate::state::GameState::Play) to copy the
+/// current [`GameSeed`] to the clipboard when the [`KeyAction::CopySeed`] key (C by default) is pressed.
+pub fn copy_seed(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    seed: Res<GameSeed>,
+) {
+    if !bindings.just_pressed(&keys, KeyAction::CopySeed) {
+        return;
+    }
+    let message = match clipboard::set_text(&seed.0.to_string()) {
+        Ok(()) => format!("Copied seed {}", seed.0),
+        Err(e) => {
+            warn!("Failed to copy seed to clipboard: {e}");
+            "Could not access the clipboard".to_string()
+        }
+    };
+    Toast::spawn(&mut commands, &asset_server, message);
+}
+
+/// System that runs during [`GameState::Menu`](crate::state::GameState::Menu) and
+/// [`GameState::Settings`](crate::state::GameState::Settings) to paste a seed from the clipboard
+/// (with Ctrl or Cmd and the [`KeyAction::PasteSeed`] key, V by default) for the next deal.
+///
+/// With [`SeedMode::Fixed`], the pasted seed becomes the [`Settings::fixed_seed`] (for every game) instead.
+pub fn paste_seed(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    keys: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut settings: ResMut<Settings>,
+    mut next_seed: ResMut<NextSeed>,
+) {
+    let modifier = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+    if !modifier || !bindings.just_pressed(&keys, KeyAction::PasteSeed) {
+        return;
+    }
+    let message = match clipboard::get_text() {
+        Ok(text) => match text.trim().parse::<u64>() {
+            Ok(seed) if settings.seed_mode == SeedMode::Fixed => {
+                settings.fixed_seed = seed;
+                format!("Every game will use seed {seed}")
+            }
+            Ok(seed) => {
+                next_seed.0 = Some(seed);
+                format!("Next game will use seed {seed}")
+            }
+            Err(_) => "The clipboard does not hold a valid seed".to_string(),
+        },
+        Err(e) => {
+            warn!("Failed to paste seed from clipboard: {e}");
+            "Could not access the clipboard".to_string()
+        }
+    };
+    Toast::spawn(&mut commands, &asset_server, message);
+}
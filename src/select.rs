@@ -0,0 +1,130 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    animator::PickUpCard, camera::MainCamera, cards::Card, settings::Settings, state::GameState,
+};
+
+/// The rectangle drawn while the player drags to select cards (see [`Settings::box_select`]).
+#[derive(Component, Debug)]
+pub struct SelectionBox {
+    /// Where (in logical pixels) the drag started.
+    start: Vec2,
+}
+
+impl SelectionBox {
+    /// How far (in logical pixels) the cursor must move before a press becomes a drag.
+    ///
+    /// This keeps simple clicks from selecting anything.
+    const DRAG_THRESHOLD: f32 = 8.0;
+}
+
+/// A card waiting to be picked up (e.g. after being box selected) once the timer finishes.
+#[derive(Component, Debug)]
+pub struct DelayedPickup(Timer);
+
+impl DelayedPickup {
+    /// The delay (in seconds) between the pickups of consecutive cards in a group.
+    pub const STAGGER: f32 = 0.06;
+
+    /// Delays the pickup of the `index`th card in a group, so the group is picked up in a cascade.
+    pub fn nth(index: usize) -> Self {
+        Self(Timer::from_seconds(
+            Self::STAGGER * index as f32,
+            TimerMode::Once,
+        ))
+    }
+}
+
+/// System that runs during [`GameState::Play`] to pick up all cards inside a dragged rectangle.
+///
+/// Only playable face-down cards whose centers fall inside the rectangle (when the mouse button is released)
+/// are picked up, topmost first. This only happens if [`Settings::box_select`] is enabled.
+#[allow(clippy::too_many_arguments)]
+pub fn box_select(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut press: Local<Option<Vec2>>,
+    mut selection: Query<(Entity, &SelectionBox, &mut Node)>,
+    cards: Query<(Entity, &Card, &GlobalTransform)>,
+) {
+    if !settings.box_select {
+        return;
+    }
+    let cursor = window.cursor_position();
+    if mouse.just_pressed(MouseButton::Left) {
+        *press = cursor;
+    }
+    if let (Some(start), Some(cursor)) = (*press, cursor)
+        && mouse.pressed(MouseButton::Left)
+    {
+        let rect = Rect::from_corners(start, cursor);
+        if let Ok((_, _, mut node)) = selection.single_mut() {
+            node.left = Val::Px(rect.min.x);
+            node.top = Val::Px(rect.min.y);
+            node.width = Val::Px(rect.width());
+            node.height = Val::Px(rect.height());
+        } else if start.distance(cursor) > SelectionBox::DRAG_THRESHOLD {
+            commands.spawn((
+                SelectionBox { start },
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(rect.min.x),
+                    top: Val::Px(rect.min.y),
+                    width: Val::Px(rect.width()),
+                    height: Val::Px(rect.height()),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::WHITE.with_alpha(0.1)),
+                BorderColor::all(Color::WHITE.with_alpha(0.6)),
+                // the rectangle should never block the pointer from reaching the cards
+                Pickable::IGNORE,
+                DespawnOnExit(GameState::Play),
+            ));
+        }
+        return;
+    }
+    *press = None;
+    let Ok((entity, selection_box, _)) = selection.single() else {
+        return;
+    };
+    commands.entity(entity).despawn();
+    // the rectangle ends where the cursor was released (or left the window)
+    let Some(end) = cursor else {
+        return;
+    };
+    let rect = Rect::from_corners(selection_box.start, end);
+    let (camera, camera_transform) = *camera;
+    let mut selected = cards
+        .iter()
+        .filter(|(_, card, _)| card.playable && !card.face_up)
+        .filter(|(_, _, transform)| {
+            camera
+                .world_to_viewport(camera_transform, transform.translation())
+                .is_ok_and(|position| rect.contains(position))
+        })
+        .collect::<Vec<_>>();
+    selected.sort_by(|(_, _, a), (_, _, b)| b.translation().y.total_cmp(&a.translation().y));
+    info!("Box selected {} cards", selected.len());
+    for (i, (entity, _, _)) in selected.into_iter().enumerate() {
+        commands.entity(entity).insert(DelayedPickup::nth(i));
+    }
+}
+
+/// System that picks up each card with a [`DelayedPickup`] once its timer finishes.
+pub fn delayed_pickups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DelayedPickup)>,
+) {
+    for (entity, mut delayed) in &mut query {
+        delayed.0.tick(time.delta());
+        if delayed.0.is_finished() {
+            commands.entity(entity).remove::<DelayedPickup>();
+            commands.trigger(PickUpCard { entity });
+        }
+    }
+}
@@ -0,0 +1,952 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    animator::{CollectPath, GameSpeed, PickupMode},
+    audio::FLIP_VARIANTS,
+    camera::GraphicsQuality,
+    cards::{CARD_HALF_SIZE, CardBack},
+    deck::DECK_SIZE,
+    layout::{BoardRotation, DealOrigin, DealPattern, PileConfig},
+    lighting::LightTint,
+    persist,
+    seed::SeedMode,
+    theme::UiTheme,
+    vignette::Vignette,
+};
+
+/// Player preferences that can be changed from the [`GameState::Settings`](crate::state::GameState::Settings) screen.
+///
+/// These preferences are persisted to a file in the user's config directory (except on the web).
+/// Fields missing from the file (like ones added since it was saved) keep their default values.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Pan the camera when the cursor nears a window edge during play.
+    pub edge_pan: bool,
+    /// How fast (in world units per second) the camera pans at a window edge.
+    pub edge_pan_speed: f32,
+    /// Show a tooltip with the card's name when it is flipped face-up.
+    pub card_tooltips: bool,
+    /// Mirror the board layout (on the X axis) for left-handed players.
+    pub mirror_board: bool,
+    /// Skip (or hold static) purely decorative motion.
+    pub reduced_motion: bool,
+    /// The camera's vertical field of view (in degrees).
+    pub camera_fov: f32,
+    /// The volume of sound effects (from `0` to `1`); `0` mutes them.
+    pub sfx_volume: f32,
+    /// Flip collected cards face-down as they land on the pile (instead of leaving them face-up).
+    pub collect_face_down: bool,
+    /// How many (randomly chosen) cards are dealt face-up.
+    pub face_up_cards: u8,
+    /// How long (in seconds) the screen takes to fade out (and back in) between screens; `0` disables fading.
+    pub fade_duration: f32,
+    /// The radius (in world units) of the cards' rounded corners; `0` uses square corners.
+    pub card_corner_radius: f32,
+    /// The color theme of the menus.
+    pub ui_theme: UiTheme,
+    /// Pick up the topmost face-down card by pressing Space.
+    pub quick_pick: bool,
+    /// Give the card faces a glossy (laminated) finish instead of a matte one.
+    pub glossy_cards: bool,
+    /// Bias the dealt cards toward the board's center (if negative) or edges (if positive); `0` is uniform.
+    pub spread_bias: f32,
+    /// The style of the cards' backs.
+    pub card_back: CardBack,
+    /// How fast time passes (as a fraction of normal speed) while the last card is picked up; `1` disables slow motion.
+    pub final_slow_motion: f32,
+    /// Pick up all cards inside a rectangle dragged with the mouse.
+    pub box_select: bool,
+    /// Where the deck sits, which is where the cards are dealt from.
+    pub deal_origin: DealOrigin,
+    /// How pressing a card picks up cards.
+    pub pickup_mode: PickupMode,
+    /// How much GPU-heavy rendering (like contact shadows under the cards) is applied.
+    pub graphics_quality: GraphicsQuality,
+    /// Show face-down cards drifting behind the main menu and win screen.
+    pub menu_cards: bool,
+    /// Render shadows cast by the cards (disable for better performance on weaker GPUs).
+    pub shadows: bool,
+    /// How long (in seconds) a card must be touched to peek at it instead of picking it up.
+    pub long_press_duration: f32,
+    /// How fast the animations, dealing, and countdowns play.
+    pub game_speed: GameSpeed,
+    /// Draw each card as one mesh with both its back and face (instead of a separate face mesh).
+    pub double_sided_cards: bool,
+    /// Where the collected cards are piled (changed by dragging the pile on the layout screen).
+    pub pile: PileConfig,
+    /// How long (in seconds) without input before the screen dims during play; `0` disables dimming.
+    pub idle_timeout: f32,
+    /// The path a collected card takes to the pile.
+    pub collect_path: CollectPath,
+    /// Tilt face-up (and hovered) cards toward the camera so they are easier to read.
+    pub billboard_cards: bool,
+    /// Announce the name of each picked up card with text-to-speech.
+    pub announce_cards: bool,
+    /// How high (in degrees above the table) the light shines from; `90` is straight overhead.
+    pub light_elevation: f32,
+    /// The compass direction (in degrees, clockwise from the top of the screen) the light shines from.
+    pub light_azimuth: f32,
+    /// The color of the light, which sets the mood of the table.
+    pub light_tint: LightTint,
+    /// Pause the game while the window is not focused (or is minimized).
+    pub auto_pause: bool,
+    /// Flip a pressed card face-up in place, and only pick it up when it is pressed again.
+    pub two_stage_pickup: bool,
+    /// The most (in degrees, either way) a dealt card is turned from upright; `180` turns cards any which way.
+    pub tilt_range: f32,
+    /// Should collected cards leave a fading trail behind them while they fly to the pile?
+    ///
+    /// This is skipped with [`Self::reduced_motion`].
+    pub card_trails: bool,
+    /// How far apart (in table units) collected cards are fanned out on the pile; `0` stacks them.
+    ///
+    /// A fan that would not fit beside the board is shrunk (see [`pile_position`](crate::layout::pile_position)).
+    pub pile_fan: f32,
+    /// Should the HUD show how many cards of each suit were collected?
+    pub suit_counts: bool,
+    /// How much the corners of the screen are darkened (see [`Vignette`]).
+    pub vignette: Vignette,
+    /// Can the playable card nearest the cursor be picked up with a key press
+    /// (see [`KeyAction::PickNearest`](crate::controls::KeyAction::PickNearest))?
+    pub pick_nearest: bool,
+    /// How the cards are laid out on the board when dealt.
+    pub deal_pattern: DealPattern,
+    /// A "Collect Rest" button (which picks up all remaining cards) is shown once no more than this many
+    /// cards remain to be picked up; `0` never shows it.
+    pub collect_rest_below: u8,
+    /// Should a small map of where the remaining cards are on the board be shown during play?
+    pub minimap: bool,
+    /// Should each card get a randomly chosen back (the same for the whole round), for a mixed deck look?
+    ///
+    /// This does not apply to the [`CardBack::Foil`] or [`CardBack::Shimmer`] backs.
+    pub mixed_backs: bool,
+    /// Frame the board with wooden rails?
+    pub table_rails: bool,
+    /// A chime (and a pop of sparks at the pile) celebrates every this many collected cards
+    /// (except the final card); `0` never celebrates.
+    pub milestone_every: u8,
+    /// How far (in degrees) the camera is tilted from looking straight down at the table.
+    pub camera_tilt: f32,
+    /// How many cards of the shuffled deck are dealt (the rest stay in the deck), for a shorter game.
+    ///
+    /// Only games with the whole deck dealt count toward the lifetime statistics.
+    pub deal_count: u8,
+    /// How many seconds a press that cannot pick up a card costs in the timed mode; `0` turns the penalty off.
+    pub misclick_penalty: f32,
+    /// How many of the flip sound variants are picked from at random when a card is flipped
+    /// (see [`Sounds::flip`](crate::audio::Sounds::flip)); `1` always plays the same sound.
+    pub flip_variants: u8,
+    /// How much the pitch of each flip sound may randomly vary (as a fraction of its normal speed); `0` disables it.
+    pub flip_pitch_variation: f32,
+    /// How long (in seconds) the completed pile is held on screen before the win menu is shown; `0` disables it.
+    pub win_hold: f32,
+    /// Collect the cards to a pile beside the board on the side nearest the player (the bottom of the screen)
+    /// instead of the [`Settings::pile`].
+    pub collect_toward_player: bool,
+    /// Log playtest analytics (deals, pickups, wins, restarts, and settings changes) to a file
+    /// in the user's data directory (see the [`analytics`](crate::analytics) module). Nothing is logged unless this is enabled.
+    pub analytics: bool,
+    /// Play a click when a menu button is pressed (at the [`Settings::sfx_volume`]).
+    pub button_sounds: bool,
+    /// How far the board's layout is turned on the table (for displays mounted in a table, or other seating).
+    pub board_rotation: BoardRotation,
+    /// Pick up the cards automatically, one at a time (for unattended demos like the
+    /// [`KioskMode`](crate::kiosk::KioskMode)); see [`auto_collect`](crate::autocollect::auto_collect).
+    pub auto_collect: bool,
+    /// How long (in seconds) [`Settings::auto_collect`] waits between pickups (after the previous card's animation).
+    pub auto_collect_delay: f32,
+    /// Flip a pressed (face-down) card face-up and lift it for as long as the press is held, and only pick it up
+    /// when the press is released over it (see [`ReadHold`](crate::read::ReadHold)).
+    pub hold_to_read: bool,
+    /// How the seed of each new game is chosen (a random one, or the [`Settings::fixed_seed`]).
+    pub seed_mode: SeedMode,
+    /// The seed that every game is dealt with (with [`SeedMode::Fixed`] only).
+    /// It is stepped through in the settings, or pasted from the clipboard (see [`paste_seed`](crate::seed::paste_seed)).
+    pub fixed_seed: u64,
+    /// How long (in seconds) without a pickup before the playable cards glow as a hint (see [`draw_idle_glow`](crate::glow::draw_idle_glow));
+    /// `0` disables it.
+    pub idle_glow: f32,
+    /// The most cards stacked on one pile; the next card starts a new pile beside it
+    /// (see [`pile_position`](crate::layout::pile_position)). By default, a whole deck fits on one pile.
+    pub pile_cap: u8,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            edge_pan: false,
+            edge_pan_speed: 300.0,
+            card_tooltips: false,
+            mirror_board: false,
+            reduced_motion: false,
+            camera_fov: 45.0,
+            sfx_volume: 0.8,
+            collect_face_down: false,
+            face_up_cards: 0,
+            fade_duration: 0.3,
+            card_corner_radius: 0.0,
+            ui_theme: UiTheme::Dark,
+            quick_pick: false,
+            glossy_cards: false,
+            spread_bias: 0.0,
+            card_back: CardBack::Plain,
+            final_slow_motion: 1.0,
+            box_select: false,
+            deal_origin: DealOrigin::TopRight,
+            pickup_mode: PickupMode::Single,
+            graphics_quality: GraphicsQuality::Standard,
+            menu_cards: false,
+            shadows: true,
+            long_press_duration: 0.5,
+            game_speed: GameSpeed::Normal,
+            double_sided_cards: false,
+            pile: PileConfig::default(),
+            idle_timeout: 60.0,
+            collect_path: CollectPath::Straight,
+            billboard_cards: false,
+            announce_cards: false,
+            light_elevation: 90.0,
+            light_azimuth: 0.0,
+            light_tint: LightTint::Neutral,
+            auto_pause: true,
+            two_stage_pickup: false,
+            tilt_range: 180.0,
+            card_trails: true,
+            pile_fan: 0.0,
+            suit_counts: false,
+            vignette: Vignette::Off,
+            pick_nearest: false,
+            deal_pattern: DealPattern::Scatter,
+            collect_rest_below: 0,
+            minimap: false,
+            mixed_backs: false,
+            table_rails: false,
+            milestone_every: 13,
+            camera_tilt: 0.0,
+            deal_count: DECK_SIZE,
+            misclick_penalty: 0.0,
+            flip_variants: FLIP_VARIANTS,
+            flip_pitch_variation: 0.1,
+            win_hold: 1.0,
+            collect_toward_player: false,
+            analytics: false,
+            button_sounds: true,
+            board_rotation: BoardRotation::Deg0,
+            auto_collect: false,
+            auto_collect_delay: 0.2,
+            hold_to_read: false,
+            seed_mode: SeedMode::Random,
+            fixed_seed: 0,
+            idle_glow: 15.0,
+            pile_cap: DECK_SIZE,
+        }
+    }
+}
+
+impl Settings {
+    pub const EDGE_PAN_SPEED_STEP: f32 = 50.0;
+    pub const EDGE_PAN_SPEED_MIN: f32 = 50.0;
+    pub const EDGE_PAN_SPEED_MAX: f32 = 1000.0;
+    pub const CAMERA_FOV_STEP: f32 = 5.0;
+    pub const CAMERA_FOV_MIN: f32 = 20.0;
+    pub const CAMERA_FOV_MAX: f32 = 100.0;
+    pub const SFX_VOLUME_STEP: f32 = 0.1;
+    pub const FADE_DURATION_STEP: f32 = 0.1;
+    pub const FADE_DURATION_MAX: f32 = 1.0;
+    pub const CARD_CORNER_RADIUS_STEP: f32 = 2.0;
+    pub const CARD_CORNER_RADIUS_MAX: f32 = 16.0;
+    pub const SPREAD_BIAS_STEP: f32 = 0.25;
+    pub const FINAL_SLOW_MOTION_STEP: f32 = 0.25;
+    pub const FINAL_SLOW_MOTION_MIN: f32 = 0.25;
+    pub const LONG_PRESS_DURATION_STEP: f32 = 0.1;
+    pub const LONG_PRESS_DURATION_MIN: f32 = 0.2;
+    pub const LONG_PRESS_DURATION_MAX: f32 = 1.5;
+    pub const IDLE_TIMEOUT_STEP: f32 = 15.0;
+    pub const IDLE_TIMEOUT_MAX: f32 = 300.0;
+    pub const LIGHT_ELEVATION_STEP: f32 = 10.0;
+    pub const LIGHT_ELEVATION_MIN: f32 = 20.0;
+    pub const LIGHT_AZIMUTH_STEP: f32 = 30.0;
+    pub const TILT_RANGE_STEP: f32 = 15.0;
+    pub const PILE_FAN_STEP: f32 = 10.0;
+    /// Half a card's height, so the corner of each fanned card stays visible.
+    pub const PILE_FAN_MAX: f32 = CARD_HALF_SIZE.y;
+    pub const COLLECT_REST_MAX: u8 = 20;
+    /// The largest [`Settings::milestone_every`] (half of the deck).
+    pub const MILESTONE_MAX: u8 = DECK_SIZE / 2;
+    pub const CAMERA_TILT_STEP: f32 = 5.0;
+    pub const CAMERA_TILT_MAX: f32 = 70.0;
+    /// The choices for [`Settings::deal_count`].
+    pub const DEAL_COUNTS: [u8; 5] = [10, 13, 26, 39, DECK_SIZE];
+    pub const MISCLICK_PENALTY_STEP: f32 = 0.5;
+    pub const MISCLICK_PENALTY_MAX: f32 = 5.0;
+    pub const FLIP_PITCH_STEP: f32 = 0.05;
+    pub const FLIP_PITCH_MAX: f32 = 0.3;
+    pub const WIN_HOLD_STEP: f32 = 0.5;
+    pub const WIN_HOLD_MAX: f32 = 3.0;
+    pub const AUTO_COLLECT_DELAY_STEP: f32 = 0.1;
+    pub const AUTO_COLLECT_DELAY_MAX: f32 = 2.0;
+    pub const IDLE_GLOW_STEP: f32 = 5.0;
+    pub const IDLE_GLOW_MAX: f32 = 60.0;
+    /// The smallest [`Settings::pile_cap`], which keeps the piles of a whole deck few enough to tell apart.
+    pub const PILE_CAP_MIN: u8 = 4;
+    pub const PILE_CAP_STEP: u8 = 4;
+
+    /// The name of the persisted settings file.
+    const FILE_NAME: &str = "settings.ron";
+
+    /// Brings every setting back within the bounds that [`SettingKind::adjust`] keeps it in,
+    /// since an edited (or older) settings file could hold any value.
+    pub fn sanitize(&mut self) {
+        self.edge_pan_speed = self
+            .edge_pan_speed
+            .clamp(Self::EDGE_PAN_SPEED_MIN, Self::EDGE_PAN_SPEED_MAX);
+        self.camera_fov = self
+            .camera_fov
+            .clamp(Self::CAMERA_FOV_MIN, Self::CAMERA_FOV_MAX);
+        self.sfx_volume = self.sfx_volume.clamp(0.0, 1.0);
+        self.face_up_cards = self.face_up_cards.min(DECK_SIZE);
+        self.fade_duration = self.fade_duration.clamp(0.0, Self::FADE_DURATION_MAX);
+        self.final_slow_motion = self
+            .final_slow_motion
+            .clamp(Self::FINAL_SLOW_MOTION_MIN, 1.0);
+        self.spread_bias = self.spread_bias.clamp(-1.0, 1.0);
+        self.card_corner_radius = self
+            .card_corner_radius
+            .clamp(0.0, Self::CARD_CORNER_RADIUS_MAX);
+        self.long_press_duration = self
+            .long_press_duration
+            .clamp(Self::LONG_PRESS_DURATION_MIN, Self::LONG_PRESS_DURATION_MAX);
+        self.pile = PileConfig::clamped(self.pile.position());
+        self.idle_timeout = self.idle_timeout.clamp(0.0, Self::IDLE_TIMEOUT_MAX);
+        self.light_elevation = self.light_elevation.clamp(Self::LIGHT_ELEVATION_MIN, 90.0);
+        self.light_azimuth = self.light_azimuth.rem_euclid(360.0);
+        self.tilt_range = self.tilt_range.clamp(0.0, 180.0);
+        self.pile_fan = self.pile_fan.clamp(0.0, Self::PILE_FAN_MAX);
+        self.collect_rest_below = self.collect_rest_below.min(Self::COLLECT_REST_MAX);
+        self.milestone_every = self.milestone_every.min(Self::MILESTONE_MAX);
+        self.camera_tilt = self.camera_tilt.clamp(0.0, Self::CAMERA_TILT_MAX);
+        if !Self::DEAL_COUNTS.contains(&self.deal_count) {
+            self.deal_count = DECK_SIZE;
+        }
+        self.misclick_penalty = self.misclick_penalty.clamp(0.0, Self::MISCLICK_PENALTY_MAX);
+        self.flip_variants = self.flip_variants.clamp(1, FLIP_VARIANTS);
+        self.flip_pitch_variation = self.flip_pitch_variation.clamp(0.0, Self::FLIP_PITCH_MAX);
+        self.win_hold = self.win_hold.clamp(0.0, Self::WIN_HOLD_MAX);
+        self.auto_collect_delay = self
+            .auto_collect_delay
+            .clamp(0.0, Self::AUTO_COLLECT_DELAY_MAX);
+        self.idle_glow = self.idle_glow.clamp(0.0, Self::IDLE_GLOW_MAX);
+        self.pile_cap = self.pile_cap.clamp(Self::PILE_CAP_MIN, DECK_SIZE);
+    }
+}
+
+/// System that loads the persisted [`Settings`] at startup.
+pub fn load_settings(mut settings: ResMut<Settings>) {
+    if let Some(mut loaded) = persist::load::<Settings>(Settings::FILE_NAME) {
+        loaded.sanitize();
+        *settings = loaded;
+    }
+}
+
+/// System that persists the [`Settings`] whenever they change (and when the app exits).
+pub fn save_settings(settings: Res<Settings>) {
+    persist::save(Settings::FILE_NAME, settings.as_ref());
+}
+
+/// The individual settings that are listed on the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub enum SettingKind {
+    EdgePan,
+    EdgePanSpeed,
+    CardTooltips,
+    MirrorBoard,
+    ReducedMotion,
+    CameraFov,
+    SfxVolume,
+    CollectFaceDown,
+    FaceUpCards,
+    FadeDuration,
+    CardCornerRadius,
+    UiTheme,
+    QuickPick,
+    GlossyCards,
+    SpreadBias,
+    CardBack,
+    FinalSlowMotion,
+    BoxSelect,
+    DealOrigin,
+    PickupMode,
+    GraphicsQuality,
+    MenuCards,
+    Shadows,
+    LongPressDuration,
+    GameSpeed,
+    DoubleSidedCards,
+    IdleTimeout,
+    CollectPath,
+    BillboardCards,
+    AnnounceCards,
+    LightElevation,
+    LightAzimuth,
+    LightTint,
+    AutoPause,
+    TwoStagePickup,
+    TiltRange,
+    CardTrails,
+    PileFan,
+    SuitCounts,
+    Vignette,
+    PickNearest,
+    DealPattern,
+    CollectRest,
+    Minimap,
+    MixedBacks,
+    TableRails,
+    Milestones,
+    CameraTilt,
+    DealCount,
+    MisclickPenalty,
+    FlipVariants,
+    FlipPitchVariation,
+    WinHold,
+    CollectTowardPlayer,
+    Analytics,
+    ButtonSounds,
+    BoardRotation,
+    AutoCollect,
+    AutoCollectDelay,
+    HoldToRead,
+    SeedMode,
+    FixedSeed,
+    IdleGlow,
+    PileCap,
+}
+
+impl SettingKind {
+    /// All settings, in the order they are displayed.
+    pub fn list() -> &'static [SettingKind] {
+        &[
+            SettingKind::EdgePan,
+            SettingKind::EdgePanSpeed,
+            SettingKind::CardTooltips,
+            SettingKind::MirrorBoard,
+            SettingKind::ReducedMotion,
+            SettingKind::CameraFov,
+            SettingKind::SfxVolume,
+            SettingKind::CollectFaceDown,
+            SettingKind::FaceUpCards,
+            SettingKind::FadeDuration,
+            SettingKind::CardCornerRadius,
+            SettingKind::UiTheme,
+            SettingKind::QuickPick,
+            SettingKind::GlossyCards,
+            SettingKind::SpreadBias,
+            SettingKind::CardBack,
+            SettingKind::FinalSlowMotion,
+            SettingKind::BoxSelect,
+            SettingKind::DealOrigin,
+            SettingKind::PickupMode,
+            SettingKind::GraphicsQuality,
+            SettingKind::MenuCards,
+            SettingKind::Shadows,
+            SettingKind::LongPressDuration,
+            SettingKind::GameSpeed,
+            SettingKind::DoubleSidedCards,
+            SettingKind::IdleTimeout,
+            SettingKind::CollectPath,
+            SettingKind::BillboardCards,
+            SettingKind::AnnounceCards,
+            SettingKind::LightElevation,
+            SettingKind::LightAzimuth,
+            SettingKind::LightTint,
+            SettingKind::AutoPause,
+            SettingKind::TwoStagePickup,
+            SettingKind::TiltRange,
+            SettingKind::CardTrails,
+            SettingKind::PileFan,
+            SettingKind::SuitCounts,
+            SettingKind::Vignette,
+            SettingKind::PickNearest,
+            SettingKind::DealPattern,
+            SettingKind::CollectRest,
+            SettingKind::Minimap,
+            SettingKind::MixedBacks,
+            SettingKind::TableRails,
+            SettingKind::Milestones,
+            SettingKind::CameraTilt,
+            SettingKind::DealCount,
+            SettingKind::MisclickPenalty,
+            SettingKind::FlipVariants,
+            SettingKind::FlipPitchVariation,
+            SettingKind::WinHold,
+            SettingKind::CollectTowardPlayer,
+            SettingKind::Analytics,
+            SettingKind::ButtonSounds,
+            SettingKind::BoardRotation,
+            SettingKind::AutoCollect,
+            SettingKind::AutoCollectDelay,
+            SettingKind::HoldToRead,
+            SettingKind::SeedMode,
+            SettingKind::FixedSeed,
+            SettingKind::IdleGlow,
+            SettingKind::PileCap,
+        ]
+    }
+
+    /// The human readable name of the setting.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingKind::EdgePan => "Edge panning",
+            SettingKind::EdgePanSpeed => "Edge pan speed",
+            SettingKind::CardTooltips => "Card name tooltips",
+            SettingKind::MirrorBoard => "Left-handed layout",
+            SettingKind::ReducedMotion => "Reduced motion",
+            SettingKind::CameraFov => "Field of view",
+            SettingKind::SfxVolume => "Sound effects volume",
+            SettingKind::CollectFaceDown => "Collected cards",
+            SettingKind::FaceUpCards => "Cards dealt face-up",
+            SettingKind::FadeDuration => "Screen fade",
+            SettingKind::CardCornerRadius => "Card corner rounding",
+            SettingKind::UiTheme => "Menu theme",
+            SettingKind::QuickPick => "Space picks top card",
+            SettingKind::GlossyCards => "Card finish",
+            SettingKind::SpreadBias => "Deal spread",
+            SettingKind::CardBack => "Card back",
+            SettingKind::FinalSlowMotion => "Final pickup speed",
+            SettingKind::BoxSelect => "Drag to box select",
+            SettingKind::DealOrigin => "Deal from",
+            SettingKind::PickupMode => "Pick up",
+            SettingKind::GraphicsQuality => "Graphics",
+            SettingKind::MenuCards => "Menu backdrop cards",
+            SettingKind::Shadows => "Shadows",
+            SettingKind::LongPressDuration => "Touch peek delay",
+            SettingKind::GameSpeed => "Game speed",
+            SettingKind::DoubleSidedCards => "Double-sided cards",
+            SettingKind::IdleTimeout => "Dim when idle",
+            SettingKind::CollectPath => "Collect path",
+            SettingKind::BillboardCards => "Tilt cards to camera",
+            SettingKind::AnnounceCards => "Speak card names",
+            SettingKind::LightElevation => "Light height",
+            SettingKind::LightAzimuth => "Light direction",
+            SettingKind::LightTint => "Light color",
+            SettingKind::AutoPause => "Pause when unfocused",
+            SettingKind::TwoStagePickup => "Reveal before pick up",
+            SettingKind::TiltRange => "Card tilt",
+            SettingKind::CardTrails => "Card trails",
+            SettingKind::PileFan => "Pile fan",
+            SettingKind::SuitCounts => "Suit counts",
+            SettingKind::Vignette => "Vignette",
+            SettingKind::PickNearest => "Pick nearest key",
+            SettingKind::DealPattern => "Deal pattern",
+            SettingKind::CollectRest => "Collect rest at",
+            SettingKind::Minimap => "Mini-map",
+            SettingKind::MixedBacks => "Mixed backs",
+            SettingKind::TableRails => "Table rails",
+            SettingKind::Milestones => "Milestones",
+            SettingKind::CameraTilt => "Camera tilt",
+            SettingKind::DealCount => "Cards dealt",
+            SettingKind::MisclickPenalty => "Mis-click penalty",
+            SettingKind::FlipVariants => "Flip sounds",
+            SettingKind::FlipPitchVariation => "Flip pitch variation",
+            SettingKind::WinHold => "Win freeze frame",
+            SettingKind::CollectTowardPlayer => "Collect toward player",
+            SettingKind::Analytics => "Playtest analytics",
+            SettingKind::ButtonSounds => "Button sounds",
+            SettingKind::BoardRotation => "Board rotation",
+            SettingKind::AutoCollect => "Auto collect",
+            SettingKind::AutoCollectDelay => "Auto collect delay",
+            SettingKind::HoldToRead => "Hold to read",
+            SettingKind::SeedMode => "New game seed",
+            SettingKind::FixedSeed => "Fixed seed",
+            SettingKind::IdleGlow => "Glow when idle",
+            SettingKind::PileCap => "Pile height cap",
+        }
+    }
+
+    /// The human readable value of the setting.
+    pub fn value(&self, settings: &Settings) -> String {
+        match self {
+            SettingKind::EdgePan => on_off(settings.edge_pan),
+            SettingKind::EdgePanSpeed => format!("{:.0}", settings.edge_pan_speed),
+            SettingKind::CardTooltips => on_off(settings.card_tooltips),
+            SettingKind::MirrorBoard => on_off(settings.mirror_board),
+            SettingKind::ReducedMotion => on_off(settings.reduced_motion),
+            SettingKind::CameraFov => format!("{:.0}°", settings.camera_fov),
+            SettingKind::SfxVolume => format!("{:.0}%", settings.sfx_volume * 100.0),
+            SettingKind::CollectFaceDown => if settings.collect_face_down {
+                "Face down"
+            } else {
+                "Face up"
+            }
+            .to_string(),
+            SettingKind::FaceUpCards => settings.face_up_cards.to_string(),
+            SettingKind::SpreadBias if settings.spread_bias < 0.0 => {
+                format!("Center {:.0}%", -settings.spread_bias * 100.0)
+            }
+            SettingKind::SpreadBias if settings.spread_bias > 0.0 => {
+                format!("Edges {:.0}%", settings.spread_bias * 100.0)
+            }
+            SettingKind::SpreadBias => "Uniform".to_string(),
+            SettingKind::FadeDuration if settings.fade_duration <= 0.0 => on_off(false),
+            SettingKind::FadeDuration => format!("{:.1}s", settings.fade_duration),
+            SettingKind::CardCornerRadius if settings.card_corner_radius <= 0.0 => on_off(false),
+            SettingKind::CardCornerRadius => format!("{:.0}", settings.card_corner_radius),
+            SettingKind::UiTheme => settings.ui_theme.label().to_string(),
+            SettingKind::CardBack => settings.card_back.label().to_string(),
+            SettingKind::FinalSlowMotion if settings.final_slow_motion >= 1.0 => {
+                "Normal".to_string()
+            }
+            SettingKind::FinalSlowMotion => format!("{:.0}%", settings.final_slow_motion * 100.0),
+            SettingKind::QuickPick => on_off(settings.quick_pick),
+            SettingKind::GlossyCards => if settings.glossy_cards {
+                "Glossy"
+            } else {
+                "Matte"
+            }
+            .to_string(),
+            SettingKind::BoxSelect => on_off(settings.box_select),
+            SettingKind::DealOrigin => settings.deal_origin.label().to_string(),
+            SettingKind::PickupMode => settings.pickup_mode.label().to_string(),
+            SettingKind::GraphicsQuality => settings.graphics_quality.label().to_string(),
+            SettingKind::MenuCards => on_off(settings.menu_cards),
+            SettingKind::Shadows => on_off(settings.shadows),
+            SettingKind::LongPressDuration => format!("{:.1}s", settings.long_press_duration),
+            SettingKind::GameSpeed => settings.game_speed.label().to_string(),
+            SettingKind::DoubleSidedCards => on_off(settings.double_sided_cards),
+            SettingKind::IdleTimeout if settings.idle_timeout <= 0.0 => on_off(false),
+            SettingKind::IdleTimeout => format!("{:.0}s", settings.idle_timeout),
+            SettingKind::CollectPath => settings.collect_path.label().to_string(),
+            SettingKind::BillboardCards => on_off(settings.billboard_cards),
+            SettingKind::AnnounceCards => on_off(settings.announce_cards),
+            SettingKind::LightElevation => format!("{:.0}°", settings.light_elevation),
+            SettingKind::LightAzimuth => format!("{:.0}°", settings.light_azimuth),
+            SettingKind::LightTint => settings.light_tint.label().to_string(),
+            SettingKind::AutoPause => on_off(settings.auto_pause),
+            SettingKind::TwoStagePickup => on_off(settings.two_stage_pickup),
+            SettingKind::TiltRange => format!("±{:.0}°", settings.tilt_range),
+            SettingKind::CardTrails => on_off(settings.card_trails),
+            SettingKind::PileFan => {
+                if settings.pile_fan == 0.0 {
+                    "Off".to_string()
+                } else {
+                    format!("{:.0}", settings.pile_fan)
+                }
+            }
+            SettingKind::SuitCounts => on_off(settings.suit_counts),
+            SettingKind::Vignette => settings.vignette.label().to_string(),
+            SettingKind::PickNearest => on_off(settings.pick_nearest),
+            SettingKind::DealPattern => settings.deal_pattern.label().to_string(),
+            SettingKind::CollectRest => match settings.collect_rest_below {
+                0 => "Off".to_string(),
+                below => below.to_string(),
+            },
+            SettingKind::Minimap => on_off(settings.minimap),
+            SettingKind::MixedBacks => on_off(settings.mixed_backs),
+            SettingKind::TableRails => on_off(settings.table_rails),
+            SettingKind::Milestones => match settings.milestone_every {
+                0 => "Off".to_string(),
+                every => format!("Every {every}"),
+            },
+            SettingKind::CameraTilt => format!("{:.0}°", settings.camera_tilt),
+            SettingKind::DealCount => match settings.deal_count {
+                DECK_SIZE => "All".to_string(),
+                count => count.to_string(),
+            },
+            SettingKind::MisclickPenalty => match settings.misclick_penalty {
+                0.0 => "Off".to_string(),
+                seconds => format!("{seconds}s"),
+            },
+            SettingKind::FlipVariants => settings.flip_variants.to_string(),
+            SettingKind::FlipPitchVariation => match settings.flip_pitch_variation {
+                0.0 => "Off".to_string(),
+                variation => format!("±{:.0}%", variation * 100.0),
+            },
+            SettingKind::WinHold => match settings.win_hold {
+                0.0 => "Off".to_string(),
+                seconds => format!("{seconds}s"),
+            },
+            SettingKind::CollectTowardPlayer => on_off(settings.collect_toward_player),
+            SettingKind::Analytics => on_off(settings.analytics),
+            SettingKind::ButtonSounds => on_off(settings.button_sounds),
+            SettingKind::BoardRotation => settings.board_rotation.label().to_string(),
+            SettingKind::AutoCollect => on_off(settings.auto_collect),
+            SettingKind::AutoCollectDelay => format!("{:.1}s", settings.auto_collect_delay),
+            SettingKind::HoldToRead => on_off(settings.hold_to_read),
+            SettingKind::SeedMode => settings.seed_mode.label().to_string(),
+            SettingKind::FixedSeed => match settings.seed_mode {
+                SeedMode::Random => "-".to_string(),
+                SeedMode::Fixed => settings.fixed_seed.to_string(),
+            },
+            SettingKind::IdleGlow if settings.idle_glow <= 0.0 => on_off(false),
+            SettingKind::IdleGlow => format!("{:.0}s", settings.idle_glow),
+            SettingKind::PileCap => settings.pile_cap.to_string(),
+        }
+    }
+
+    /// Adjust the setting by one `step` (either `-1` or `1`).
+    ///
+    /// Toggles ignore the sign of `step`; numbers are clamped to their sane bounds.
+    pub fn adjust(&self, settings: &mut Settings, step: i8) {
+        match self {
+            SettingKind::EdgePan => settings.edge_pan = !settings.edge_pan,
+            SettingKind::CardTooltips => settings.card_tooltips = !settings.card_tooltips,
+            SettingKind::MirrorBoard => settings.mirror_board = !settings.mirror_board,
+            SettingKind::ReducedMotion => settings.reduced_motion = !settings.reduced_motion,
+            SettingKind::QuickPick => settings.quick_pick = !settings.quick_pick,
+            SettingKind::GlossyCards => settings.glossy_cards = !settings.glossy_cards,
+            SettingKind::CardBack => settings.card_back.cycle(step),
+            SettingKind::UiTheme => {
+                settings.ui_theme = match settings.ui_theme {
+                    UiTheme::Dark => UiTheme::Light,
+                    UiTheme::Light => UiTheme::Dark,
+                }
+            }
+            SettingKind::CollectFaceDown => {
+                settings.collect_face_down = !settings.collect_face_down
+            }
+            SettingKind::EdgePanSpeed => {
+                settings.edge_pan_speed = (settings.edge_pan_speed
+                    + Settings::EDGE_PAN_SPEED_STEP * step as f32)
+                    .clamp(Settings::EDGE_PAN_SPEED_MIN, Settings::EDGE_PAN_SPEED_MAX);
+            }
+            SettingKind::CameraFov => {
+                settings.camera_fov = (settings.camera_fov
+                    + Settings::CAMERA_FOV_STEP * step as f32)
+                    .clamp(Settings::CAMERA_FOV_MIN, Settings::CAMERA_FOV_MAX);
+            }
+            SettingKind::SfxVolume => {
+                settings.sfx_volume =
+                    (settings.sfx_volume + Settings::SFX_VOLUME_STEP * step as f32).clamp(0.0, 1.0);
+            }
+            SettingKind::FaceUpCards => {
+                settings.face_up_cards = settings
+                    .face_up_cards
+                    .saturating_add_signed(step)
+                    .min(DECK_SIZE);
+            }
+            SettingKind::FadeDuration => {
+                // round to avoid accumulating floating point error
+                let steps = (settings.fade_duration / Settings::FADE_DURATION_STEP).round();
+                settings.fade_duration = ((steps + step as f32) * Settings::FADE_DURATION_STEP)
+                    .clamp(0.0, Settings::FADE_DURATION_MAX);
+            }
+            SettingKind::FinalSlowMotion => {
+                settings.final_slow_motion = (settings.final_slow_motion
+                    + Settings::FINAL_SLOW_MOTION_STEP * step as f32)
+                    .clamp(Settings::FINAL_SLOW_MOTION_MIN, 1.0);
+            }
+            SettingKind::SpreadBias => {
+                settings.spread_bias = (settings.spread_bias
+                    + Settings::SPREAD_BIAS_STEP * step as f32)
+                    .clamp(-1.0, 1.0);
+            }
+            SettingKind::CardCornerRadius => {
+                settings.card_corner_radius = (settings.card_corner_radius
+                    + Settings::CARD_CORNER_RADIUS_STEP * step as f32)
+                    .clamp(0.0, Settings::CARD_CORNER_RADIUS_MAX);
+            }
+            SettingKind::BoxSelect => settings.box_select = !settings.box_select,
+            SettingKind::DealOrigin => settings.deal_origin.cycle(step),
+            SettingKind::PickupMode => {
+                settings.pickup_mode = match settings.pickup_mode {
+                    PickupMode::Single => PickupMode::Suit,
+                    PickupMode::Suit => PickupMode::Single,
+                }
+            }
+            SettingKind::GraphicsQuality => {
+                settings.graphics_quality = match settings.graphics_quality {
+                    GraphicsQuality::Standard => GraphicsQuality::High,
+                    GraphicsQuality::High => GraphicsQuality::Standard,
+                }
+            }
+            SettingKind::MenuCards => settings.menu_cards = !settings.menu_cards,
+            SettingKind::Shadows => settings.shadows = !settings.shadows,
+            SettingKind::LongPressDuration => {
+                // round to avoid accumulating floating point error
+                let steps =
+                    (settings.long_press_duration / Settings::LONG_PRESS_DURATION_STEP).round();
+                settings.long_press_duration =
+                    ((steps + step as f32) * Settings::LONG_PRESS_DURATION_STEP).clamp(
+                        Settings::LONG_PRESS_DURATION_MIN,
+                        Settings::LONG_PRESS_DURATION_MAX,
+                    );
+            }
+            SettingKind::GameSpeed => settings.game_speed.step(step),
+            SettingKind::DoubleSidedCards => {
+                settings.double_sided_cards = !settings.double_sided_cards
+            }
+            SettingKind::IdleTimeout => {
+                settings.idle_timeout = (settings.idle_timeout
+                    + Settings::IDLE_TIMEOUT_STEP * step as f32)
+                    .clamp(0.0, Settings::IDLE_TIMEOUT_MAX);
+            }
+            SettingKind::CollectPath => {
+                settings.collect_path = match settings.collect_path {
+                    CollectPath::Straight => CollectPath::Arc,
+                    CollectPath::Arc => CollectPath::Straight,
+                }
+            }
+            SettingKind::BillboardCards => settings.billboard_cards = !settings.billboard_cards,
+            SettingKind::AnnounceCards => settings.announce_cards = !settings.announce_cards,
+            SettingKind::LightElevation => {
+                settings.light_elevation = (settings.light_elevation
+                    + Settings::LIGHT_ELEVATION_STEP * step as f32)
+                    .clamp(Settings::LIGHT_ELEVATION_MIN, 90.0);
+            }
+            SettingKind::LightAzimuth => {
+                settings.light_azimuth = (settings.light_azimuth
+                    + Settings::LIGHT_AZIMUTH_STEP * step as f32)
+                    .rem_euclid(360.0);
+            }
+            SettingKind::LightTint => settings.light_tint.cycle(step),
+            SettingKind::AutoPause => settings.auto_pause = !settings.auto_pause,
+            SettingKind::TwoStagePickup => settings.two_stage_pickup = !settings.two_stage_pickup,
+            SettingKind::TiltRange => {
+                settings.tilt_range = (settings.tilt_range
+                    + Settings::TILT_RANGE_STEP * step as f32)
+                    .clamp(0.0, 180.0);
+            }
+            SettingKind::CardTrails => settings.card_trails = !settings.card_trails,
+            SettingKind::PileFan => {
+                settings.pile_fan = (settings.pile_fan + Settings::PILE_FAN_STEP * step as f32)
+                    .clamp(0.0, Settings::PILE_FAN_MAX);
+            }
+            SettingKind::SuitCounts => settings.suit_counts = !settings.suit_counts,
+            SettingKind::Vignette => settings.vignette.step(step),
+            SettingKind::PickNearest => settings.pick_nearest = !settings.pick_nearest,
+            SettingKind::DealPattern => settings.deal_pattern.toggle(),
+            SettingKind::CollectRest => {
+                settings.collect_rest_below = settings
+                    .collect_rest_below
+                    .saturating_add_signed(step)
+                    .min(Settings::COLLECT_REST_MAX);
+            }
+            SettingKind::Minimap => settings.minimap = !settings.minimap,
+            SettingKind::MixedBacks => settings.mixed_backs = !settings.mixed_backs,
+            SettingKind::TableRails => settings.table_rails = !settings.table_rails,
+            SettingKind::Milestones => {
+                settings.milestone_every = settings
+                    .milestone_every
+                    .saturating_add_signed(step)
+                    .min(Settings::MILESTONE_MAX);
+            }
+            SettingKind::CameraTilt => {
+                settings.camera_tilt = (settings.camera_tilt
+                    + Settings::CAMERA_TILT_STEP * step as f32)
+                    .clamp(0.0, Settings::CAMERA_TILT_MAX);
+            }
+            SettingKind::DealCount => {
+                let index = Settings::DEAL_COUNTS
+                    .iter()
+                    .position(|count| *count == settings.deal_count)
+                    .unwrap_or(Settings::DEAL_COUNTS.len() - 1);
+                let next = (index as isize + step as isize)
+                    .clamp(0, Settings::DEAL_COUNTS.len() as isize - 1);
+                settings.deal_count = Settings::DEAL_COUNTS[next as usize];
+            }
+            SettingKind::MisclickPenalty => {
+                settings.misclick_penalty = (settings.misclick_penalty
+                    + Settings::MISCLICK_PENALTY_STEP * step as f32)
+                    .clamp(0.0, Settings::MISCLICK_PENALTY_MAX);
+            }
+            SettingKind::FlipVariants => {
+                settings.flip_variants = settings
+                    .flip_variants
+                    .saturating_add_signed(step)
+                    .clamp(1, FLIP_VARIANTS);
+            }
+            SettingKind::FlipPitchVariation => {
+                settings.flip_pitch_variation = (settings.flip_pitch_variation
+                    + Settings::FLIP_PITCH_STEP * step as f32)
+                    .clamp(0.0, Settings::FLIP_PITCH_MAX);
+            }
+            SettingKind::WinHold => {
+                settings.win_hold = (settings.win_hold + Settings::WIN_HOLD_STEP * step as f32)
+                    .clamp(0.0, Settings::WIN_HOLD_MAX);
+            }
+            SettingKind::CollectTowardPlayer => {
+                settings.collect_toward_player = !settings.collect_toward_player
+            }
+            SettingKind::Analytics => settings.analytics = !settings.analytics,
+            SettingKind::ButtonSounds => settings.button_sounds = !settings.button_sounds,
+            SettingKind::BoardRotation => settings.board_rotation.cycle(step),
+            SettingKind::AutoCollect => settings.auto_collect = !settings.auto_collect,
+            SettingKind::AutoCollectDelay => {
+                settings.auto_collect_delay = (settings.auto_collect_delay
+                    + Settings::AUTO_COLLECT_DELAY_STEP * step as f32)
+                    .clamp(0.0, Settings::AUTO_COLLECT_DELAY_MAX);
+            }
+            SettingKind::HoldToRead => settings.hold_to_read = !settings.hold_to_read,
+            SettingKind::SeedMode => settings.seed_mode.cycle(step),
+            SettingKind::FixedSeed => {
+                // the seed is only shown (and changed) while it is used
+                if settings.seed_mode == SeedMode::Fixed {
+                    settings.fixed_seed = settings.fixed_seed.wrapping_add_signed(step as i64);
+                }
+            }
+            SettingKind::IdleGlow => {
+                settings.idle_glow = (settings.idle_glow + Settings::IDLE_GLOW_STEP * step as f32)
+                    .clamp(0.0, Settings::IDLE_GLOW_MAX);
+            }
+            SettingKind::PileCap => {
+                settings.pile_cap = settings
+                    .pile_cap
+                    .saturating_add_signed(Settings::PILE_CAP_STEP as i8 * step)
+                    .clamp(Settings::PILE_CAP_MIN, DECK_SIZE);
+            }
+        }
+    }
+}
+
+fn on_off(value: bool) -> String {
+    if value { "On" } else { "Off" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_clamps_edited_file() {
+        let mut settings: Settings = ron::from_str(
+            "(
+                camera_fov: 500.0,
+                sfx_volume: -2.0,
+                face_up_cards: 200,
+                light_azimuth: -30.0,
+                pile: (x: 1000.0, z: -1000.0),
+                pile_fan: 400.0,
+                deal_count: 7,
+                flip_variants: 0,
+                win_hold: 60.0,
+                pile_cap: 0,
+            )",
+        )
+        .unwrap();
+        settings.sanitize();
+        assert_eq!(settings.camera_fov, Settings::CAMERA_FOV_MAX);
+        assert_eq!(settings.sfx_volume, 0.0);
+        assert_eq!(settings.face_up_cards, DECK_SIZE);
+        assert_eq!(settings.light_azimuth, 330.0);
+        // beside the board's bottom-left corner, across from the default pile
+        let corner = PileConfig::default();
+        assert_eq!((settings.pile.x, settings.pile.z), (corner.x, -corner.z));
+        assert_eq!(settings.pile_fan, Settings::PILE_FAN_MAX);
+        assert_eq!(settings.deal_count, DECK_SIZE);
+        assert_eq!(settings.flip_variants, 1);
+        assert_eq!(settings.win_hold, Settings::WIN_HOLD_MAX);
+        assert_eq!(settings.pile_cap, Settings::PILE_CAP_MIN);
+    }
+
+    #[test]
+    fn sanitize_keeps_defaults() {
+        let mut settings = Settings::default();
+        settings.sanitize();
+        assert_eq!(
+            ron::to_string(&settings).unwrap(),
+            ron::to_string(&Settings::default()).unwrap()
+        );
+    }
+}
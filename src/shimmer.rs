@@ -0,0 +1,212 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    math::Affine2,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+
+use crate::{
+    cards::{Card, CardBack, CardBundle},
+    settings::Settings,
+};
+
+/// How many frames are in the shimmer's spritesheet (side by side).
+const SHIMMER_FRAMES: u32 = 6;
+
+/// How long (in seconds) each frame of the shimmer is shown.
+const SHIMMER_FRAME_TIME: f32 = 0.12;
+
+/// How wide the band of light that sweeps across the back is (as a fraction of the card's diagonal).
+const SHIMMER_BAND_WIDTH: f32 = 0.12;
+
+/// How much the band of light brightens the back at its center (from `0` to `1`).
+const SHIMMER_STRENGTH: f32 = 0.5;
+
+/// Resource that animates the backs of the face-down cards with the [`CardBack::Shimmer`] back.
+///
+/// Each card has its own back material (see [`ShimmerBack::material`]), whose texture is a spritesheet of
+/// the back (see [`build_shimmer_sheet`]); [`animate_shimmer`] cycles through its frames by moving the
+/// materials' UV offset.
+#[derive(Resource, Debug)]
+pub struct ShimmerBack {
+    /// The back texture that the spritesheet is made from.
+    source: Handle<Image>,
+    /// Has the back texture been checked (whether or not the spritesheet could be made)?
+    checked: bool,
+    /// The spritesheet, once it is made.
+    sheet: Option<Handle<Image>>,
+    /// The frame that is shown.
+    frame: u32,
+    /// Tracks when the next frame is shown.
+    timer: Timer,
+}
+
+impl FromWorld for ShimmerBack {
+    fn from_world(world: &mut World) -> Self {
+        let source = world
+            .resource::<AssetServer>()
+            .load(Card::back_resource_name());
+        Self {
+            source,
+            checked: false,
+            sheet: None,
+            frame: 0,
+            timer: Timer::from_seconds(SHIMMER_FRAME_TIME, TimerMode::Repeating),
+        }
+    }
+}
+
+impl ShimmerBack {
+    /// Has the back texture been checked (see [`build_shimmer_sheet`])?
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Creates a card's shimmering back material with the given `depth_bias` (see [`CardBundle::back_material`]).
+    ///
+    /// Until the spritesheet is made, the plain back is shown.
+    pub fn material(
+        &self,
+        depth_bias: f32,
+        materials: &mut Assets<StandardMaterial>,
+    ) -> Handle<StandardMaterial> {
+        let material = CardBundle::back_material(self.source.clone(), depth_bias, materials);
+        if let Some(sheet) = &self.sheet
+            && let Some(material) = materials.get_mut(&material)
+        {
+            material.base_color_texture = Some(sheet.clone());
+            material.uv_transform = Self::frame_uv(self.frame);
+        }
+        material
+    }
+
+    /// The UV transform that shows the given `frame` of the spritesheet.
+    fn frame_uv(frame: u32) -> Affine2 {
+        let width = 1.0 / SHIMMER_FRAMES as f32;
+        Affine2::from_scale_angle_translation(
+            Vec2::new(width, 1.0),
+            0.0,
+            Vec2::new(frame as f32 * width, 0.0),
+        )
+    }
+
+    /// Makes the spritesheet from the back texture: each frame is a copy of the back
+    /// with a diagonal band of light a bit further across it.
+    ///
+    /// Returns [`None`] if the back texture is not in the expected 8-bit RGBA format.
+    fn make_sheet(back: &Image) -> Option<Image> {
+        if back.texture_descriptor.format != TextureFormat::Rgba8UnormSrgb {
+            return None;
+        }
+        let data = back.data.as_ref()?;
+        let UVec2 {
+            x: width,
+            y: height,
+        } = back.size();
+        let mut sheet = vec![0; data.len() * SHIMMER_FRAMES as usize];
+        let sheet_row = (width * SHIMMER_FRAMES * 4) as usize;
+        for frame in 0..SHIMMER_FRAMES {
+            // the band starts and ends just off the card, so the loop has a pause without light
+            let center = (frame as f32 / (SHIMMER_FRAMES - 1) as f32) * 1.4 - 0.2;
+            for y in 0..height {
+                for x in 0..width {
+                    let along = (x as f32 / width as f32 + y as f32 / height as f32) / 2.0;
+                    let light = (1.0 - (along - center).abs() / SHIMMER_BAND_WIDTH).max(0.0)
+                        * SHIMMER_STRENGTH;
+                    let from = ((y * width + x) * 4) as usize;
+                    let to = y as usize * sheet_row + ((frame * width + x) * 4) as usize;
+                    for channel in 0..3 {
+                        let value = data[from + channel] as f32;
+                        sheet[to + channel] = (value + (255.0 - value) * light) as u8;
+                    }
+                    sheet[to + 3] = data[from + 3];
+                }
+            }
+        }
+        Some(Image::new(
+            Extent3d {
+                width: width * SHIMMER_FRAMES,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            sheet,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::RENDER_WORLD,
+        ))
+    }
+}
+
+/// A card whose back shimmers (see [`ShimmerBack`]), with its shimmering back material
+/// (which is swapped out while the card is hovered).
+#[derive(Component, Debug)]
+pub struct ShimmerCard(pub Handle<StandardMaterial>);
+
+/// System that makes the [`ShimmerBack`]'s spritesheet once the back texture has loaded,
+/// and shows it on the backs of the [`ShimmerCard`]s already dealt.
+///
+/// If the spritesheet cannot be made, the back stays still.
+pub fn build_shimmer_sheet(
+    mut shimmer: ResMut<ShimmerBack>,
+    mut images: ResMut<Assets<Image>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cards: Query<&ShimmerCard>,
+) {
+    let Some(back) = images.get(&shimmer.source) else {
+        return;
+    };
+    shimmer.checked = true;
+    let Some(sheet) = ShimmerBack::make_sheet(back) else {
+        warn!("The card back's format is not supported for the shimmer; the back will stay still");
+        return;
+    };
+    let sheet = images.add(sheet);
+    for card in &cards {
+        if let Some(material) = materials.get_mut(&card.0) {
+            material.base_color_texture = Some(sheet.clone());
+            material.uv_transform = ShimmerBack::frame_uv(shimmer.frame);
+        }
+    }
+    shimmer.sheet = Some(sheet);
+}
+
+/// System that shows the next frame of the [`ShimmerBack`] (while the [`CardBack::Shimmer`] back is used).
+pub fn animate_shimmer(
+    time: Res<Time>,
+    settings: Res<Settings>,
+    mut shimmer: ResMut<ShimmerBack>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cards: Query<&ShimmerCard>,
+) {
+    if settings.card_back != CardBack::Shimmer
+        || shimmer.sheet.is_none()
+        || !shimmer.timer.tick(time.delta()).just_finished()
+    {
+        return;
+    }
+    shimmer.frame = (shimmer.frame + 1) % SHIMMER_FRAMES;
+    for card in &cards {
+        if let Some(material) = materials.get_mut(&card.0) {
+            material.uv_transform = ShimmerBack::frame_uv(shimmer.frame);
+        }
+    }
+}
+
+/// System that gives each [`ShimmerCard`] that was turned face-up (including collected cards)
+/// the plain back, so only the face-down cards on the board shimmer.
+pub fn freeze_shimmer(
+    mut commands: Commands,
+    shimmer: Res<ShimmerBack>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cards: Query<(Entity, &Card, &ShimmerCard)>,
+) {
+    for (entity, card, shimmer_card) in &cards {
+        if card.face_up {
+            if let Some(material) = materials.get_mut(&shimmer_card.0) {
+                material.base_color_texture = Some(shimmer.source.clone());
+                material.uv_transform = Affine2::IDENTITY;
+            }
+            commands.entity(entity).remove::<ShimmerCard>();
+        }
+    }
+}
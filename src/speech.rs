@@ -0,0 +1,140 @@
+//! Announces card names with the platform's text-to-speech
+//! (see [`Settings::announce_cards`](crate::settings::Settings::announce_cards)).
+//!
+//! No speech library is bundled; the platform's own speech command is run instead
+//! (`say` on macOS, PowerShell on Windows, and `spd-say` or `espeak` elsewhere).
+//! Nothing is announced on the web.
+
+use std::{collections::VecDeque, process::Child};
+
+use bevy::prelude::*;
+
+/// Resource that queues announcements, so they are spoken one after another instead of overlapping.
+#[derive(Resource, Debug, Default)]
+pub struct Announcer {
+    /// The announcements waiting to be spoken (oldest first).
+    queue: VecDeque<String>,
+    /// The speech command that is currently speaking.
+    speaking: Option<Child>,
+    /// Set once speaking fails, so the failure is only logged once.
+    unavailable: bool,
+}
+
+impl Announcer {
+    /// The most announcements that can wait in the queue.
+    ///
+    /// When more are queued (like when a whole suit is picked up), the oldest are dropped,
+    /// so the announcements never lag far behind the game.
+    const MAX_QUEUED: usize = 3;
+
+    /// Queues the `text` to be spoken after any announcements before it.
+    pub fn announce(&mut self, text: impl Into<String>) {
+        if self.unavailable {
+            return;
+        }
+        self.queue.push_back(text.into());
+        while self.queue.len() > Self::MAX_QUEUED {
+            self.queue.pop_front();
+        }
+    }
+
+    /// Logs (once) why text-to-speech is unavailable, and drops any further announcements.
+    fn give_up(&mut self, reason: impl std::fmt::Display) {
+        warn!("Text-to-speech is unavailable (card names will not be announced): {reason}");
+        self.unavailable = true;
+        self.queue.clear();
+    }
+}
+
+/// Starts the platform's speech command speaking the given `text`.
+#[cfg(not(target_arch = "wasm32"))]
+fn speak(text: &str) -> std::io::Result<Child> {
+    use std::process::{Command, Stdio};
+
+    let script;
+    let (commands, text): (&[(&str, &[&str])], &str) = if cfg!(target_os = "macos") {
+        (&[("say", &[])], text)
+    } else if cfg!(target_os = "windows") {
+        // a `-Command` script is not passed any further arguments, so the text is quoted into the script
+        script = format!(
+            "Add-Type -AssemblyName System.Speech; \
+             (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak({})",
+            powershell_quoted(text)
+        );
+        (&[("powershell", &["-NoProfile", "-Command"])], &script)
+    } else {
+        (&[("spd-say", &["--wait"]), ("espeak", &[])], text)
+    };
+    let mut result = Err(std::io::ErrorKind::NotFound.into());
+    for (program, args) in commands {
+        result = Command::new(program)
+            .args(*args)
+            .arg(text)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+/// Quotes the `text` as a PowerShell string literal, which takes it verbatim.
+///
+/// PowerShell also treats the typographic single quotes as quotes, so those are escaped (doubled) too.
+#[cfg(not(target_arch = "wasm32"))]
+fn powershell_quoted(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('\'');
+    for c in text.chars() {
+        if matches!(c, '\'' | '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}') {
+            quoted.push(c);
+        }
+        quoted.push(c);
+    }
+    quoted.push('\'');
+    quoted
+}
+
+#[cfg(target_arch = "wasm32")]
+fn speak(_text: &str) -> std::io::Result<Child> {
+    Err(std::io::ErrorKind::Unsupported.into())
+}
+
+/// System that speaks the next queued announcement once the previous one has finished.
+///
+/// If the platform has no text-to-speech, a warning is logged (once) and announcements are dropped.
+pub fn speak_announcements(mut announcer: ResMut<Announcer>) {
+    if let Some(child) = announcer.speaking.as_mut() {
+        match child.try_wait() {
+            Ok(None) => return,
+            // a command that runs but cannot speak (like without a speech engine) fails instead
+            Ok(Some(status)) if !status.success() => {
+                announcer.speaking = None;
+                announcer.give_up(format!("the speech command failed ({status})"));
+                return;
+            }
+            Ok(Some(_)) | Err(_) => announcer.speaking = None,
+        }
+    }
+    let Some(text) = announcer.queue.pop_front() else {
+        return;
+    };
+    match speak(&text) {
+        Ok(child) => announcer.speaking = Some(child),
+        Err(e) => announcer.give_up(e),
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powershell_quotes_are_doubled() {
+        assert_eq!(powershell_quoted("Ace of Spades"), "'Ace of Spades'");
+        assert_eq!(powershell_quoted("it's $x"), "'it''s $x'");
+        assert_eq!(powershell_quoted("\u{2019}"), "'\u{2019}\u{2019}'");
+    }
+}
@@ -1,4 +1,14 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    cards::Suit,
+    countdown::Countdown,
+    deck::DECK_SIZE,
+    foundation::Foundations,
+    heatmap::PickTimeline,
+    hotseat::{CurrentPlayer, PlayerScores},
+    stats::RoundClock,
+};
 
 /// The different states of the game.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, States)]
@@ -16,10 +26,250 @@ pub enum GameState {
     Play,
     /// Win screen is being displayed.
     Win,
+    /// Settings screen is being displayed.
+    Settings,
+    /// Summary screen is being displayed after the player gave up.
+    Lose,
+    /// Gallery of all card faces is being displayed.
+    Gallery,
+    /// Lifetime statistics are being displayed.
+    Statistics,
+    /// Key bindings are being displayed (and rebound).
+    Controls,
+    /// Heatmap of when each card was picked up (in the last round) is being displayed.
+    Heatmap,
+    /// The board is being displayed so the pile of collected cards can be dragged around.
+    PileLayout,
 }
 
 /// Resource that tracks how many cards have been collected by the player.
 ///
-/// This counter is reset to `0` when entering [`GameState::Win`].
+/// This counter is reset to `0` when entering [`GameState::Deal`] (see [`reset_round`]).
 #[derive(Resource, Debug, Default)]
 pub struct CardsCollected(pub u8);
+
+impl CardsCollected {
+    /// Counts one more collected card, which can never be more than the `cards_dealt`.
+    ///
+    /// Each card is only collected once, so going past the dealt cards is a bug (caught in debug builds).
+    pub fn add(&mut self, cards_dealt: &CardsDealt) {
+        self.0 += 1;
+        debug_assert!(
+            self.0 <= cards_dealt.0,
+            "more cards were collected than were dealt"
+        );
+    }
+}
+
+/// Resource that holds how many cards were dealt this round (see [`Settings::deal_count`](crate::settings::Settings::deal_count)).
+///
+/// The round is won once this many cards are collected.
+#[derive(Resource, Debug)]
+pub struct CardsDealt(pub u8);
+
+impl Default for CardsDealt {
+    fn default() -> Self {
+        Self(DECK_SIZE)
+    }
+}
+
+impl CardsDealt {
+    /// Was the whole deck dealt?
+    pub fn is_full_deck(&self) -> bool {
+        self.0 == DECK_SIZE
+    }
+}
+
+/// Resource that tracks how many cards of each suit have been collected (indexed by [`Suit::as_u8`]).
+///
+/// This is reset along with [`CardsCollected`] when a new game is dealt.
+#[derive(Resource, Debug, Default)]
+pub struct SuitsCollected(pub [u8; 4]);
+
+impl SuitsCollected {
+    /// Counts a collected card of the given suit.
+    pub fn add(&mut self, suit: Suit) {
+        self.0[suit.as_u8() as usize] += 1;
+    }
+
+    /// How many cards of the given suit were collected.
+    pub fn get(&self, suit: Suit) -> u8 {
+        self.0[suit.as_u8() as usize]
+    }
+}
+
+/// Resource that tracks how many places on the pile were handed out (see [`PileSlot`]).
+///
+/// This counts ahead of [`CardsCollected`] while picked up cards are still flipping.
+#[derive(Resource, Debug, Default)]
+pub struct PileSlots(pub u8);
+
+impl PileSlots {
+    /// Hands out the next place on the pile (starting at `1`, like [`CardsCollected`] after the first collect).
+    pub fn reserve(&mut self) -> PileSlot {
+        self.0 += 1;
+        PileSlot(self.0)
+    }
+}
+
+/// The place on the pile that a picked up card lands in, reserved when the card is picked up.
+///
+/// Collect animations can overlap when cards are picked up quickly, so the pile is built in the
+/// order the cards were picked up rather than the order their collect animations started.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PileSlot(pub u8);
+
+/// The resources that only describe the round in progress (see [`reset_round`]).
+///
+/// A resource added for a new per-round feature belongs here, so it is reset with the rest.
+#[derive(SystemParam)]
+pub struct RoundResources<'w> {
+    cards_collected: ResMut<'w, CardsCollected>,
+    cards_dealt: ResMut<'w, CardsDealt>,
+    suits_collected: ResMut<'w, SuitsCollected>,
+    pile_slots: ResMut<'w, PileSlots>,
+    round_clock: ResMut<'w, RoundClock>,
+    countdown: ResMut<'w, Countdown>,
+    foundations: ResMut<'w, Foundations>,
+    timeline: ResMut<'w, PickTimeline>,
+    current_player: ResMut<'w, CurrentPlayer>,
+    player_scores: ResMut<'w, PlayerScores>,
+}
+
+impl RoundResources<'_> {
+    /// Resets all per-round resources to their initial state.
+    pub fn reset(&mut self) {
+        self.cards_collected.0 = 0;
+        *self.cards_dealt = CardsDealt::default();
+        *self.suits_collected = SuitsCollected::default();
+        *self.pile_slots = PileSlots::default();
+        self.round_clock.0.reset();
+        self.countdown.0.reset();
+        *self.foundations = Foundations::default();
+        *self.timeline = PickTimeline::default();
+        *self.current_player = CurrentPlayer::default();
+        *self.player_scores = PlayerScores::default();
+    }
+}
+
+/// System that resets all [`RoundResources`] when a new game is dealt, so every game starts clean.
+///
+/// This is needed because a game can end without collecting all cards (see [`GameState::Lose`]).
+/// The [`PickTimeline`] is kept until now, so the last round's heatmap can be shown from the end screen.
+pub fn reset_round(mut round: RoundResources) {
+    round.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{
+        cards::{Card, Rank, Suit},
+        layout::pile_position_at,
+        settings::Settings,
+    };
+
+    #[test]
+    fn reset_round_restores_defaults() {
+        let card = Card {
+            rank: Rank::Ace,
+            suit: Suit::Hearts,
+            face_up: true,
+            playable: false,
+        };
+        let mut world = World::new();
+        world.insert_resource(CardsCollected(5));
+        world.insert_resource(CardsDealt(20));
+        let mut suits_collected = SuitsCollected::default();
+        suits_collected.add(card.suit);
+        world.insert_resource(suits_collected);
+        world.insert_resource(PileSlots(7));
+        let mut round_clock = RoundClock::default();
+        round_clock.0.tick(Duration::from_secs(30));
+        world.insert_resource(round_clock);
+        let mut countdown = Countdown::default();
+        countdown.0.tick(Duration::from_secs(30));
+        world.insert_resource(countdown);
+        let mut foundations = Foundations::default();
+        foundations.place(&card);
+        world.insert_resource(foundations);
+        let mut timeline = PickTimeline::default();
+        timeline.record(&card, 3.0);
+        world.insert_resource(timeline);
+        let mut current_player = CurrentPlayer::default();
+        current_player.advance();
+        world.insert_resource(current_player);
+        let mut player_scores = PlayerScores::default();
+        player_scores.0[0] = 4;
+        world.insert_resource(player_scores);
+
+        world.run_system_once(reset_round).unwrap();
+
+        assert_eq!(world.resource::<CardsCollected>().0, 0);
+        assert!(world.resource::<CardsDealt>().is_full_deck());
+        assert_eq!(world.resource::<SuitsCollected>().get(card.suit), 0);
+        assert_eq!(world.resource::<PileSlots>().0, 0);
+        assert_eq!(world.resource::<RoundClock>().0.elapsed(), Duration::ZERO);
+        assert_eq!(world.resource::<Countdown>().0.elapsed(), Duration::ZERO);
+        assert_eq!(world.resource::<Foundations>().top(card.suit), None);
+        assert!(world.resource::<PickTimeline>().splits().is_empty());
+        assert_eq!(world.resource::<CurrentPlayer>().0, 0);
+        assert_eq!(
+            world.resource::<PlayerScores>().0,
+            PlayerScores::default().0
+        );
+    }
+
+    #[test]
+    fn collects_up_to_dealt_cards() {
+        let cards_dealt = CardsDealt(3);
+        let mut cards_collected = CardsCollected::default();
+        for _ in 0..3 {
+            cards_collected.add(&cards_dealt);
+        }
+        assert_eq!(cards_collected.0, 3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "more cards were collected than were dealt")]
+    fn collecting_past_dealt_cards_panics() {
+        let cards_dealt = CardsDealt(1);
+        let mut cards_collected = CardsCollected::default();
+        cards_collected.add(&cards_dealt);
+        cards_collected.add(&cards_dealt);
+    }
+
+    #[test]
+    fn pile_slots_are_reserved_in_order() {
+        let mut slots = PileSlots::default();
+        let slots = [slots.reserve(), slots.reserve(), slots.reserve()].map(|slot| slot.0);
+        assert_eq!(slots, [1, 2, 3]);
+    }
+
+    #[test]
+    fn quick_pickups_stack_higher() {
+        let fanned = Settings {
+            pile_fan: Settings::PILE_FAN_MAX,
+            ..default()
+        };
+        for settings in [Settings::default(), fanned] {
+            // picked up before any of their collect animations start
+            let mut slots = PileSlots::default();
+            let picked: Vec<_> = (0..5).map(|_| slots.reserve()).collect();
+            // each card lands above the one picked up before it
+            let heights: Vec<_> = picked
+                .iter()
+                .map(|slot| pile_position_at(&settings, &settings.pile, slot.0).y)
+                .collect();
+            assert!(
+                heights.windows(2).all(|pair| pair[0] < pair[1]),
+                "{heights:?}"
+            );
+        }
+    }
+}
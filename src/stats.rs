@@ -0,0 +1,258 @@
+use bevy::{prelude::*, time::Stopwatch};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    menu::{ButtonAction, spawn_button},
+    mode::GameMode,
+    persist,
+    settings::Settings,
+    state::{CardsDealt, GameState},
+};
+
+/// Resource that holds the player's statistics across all sessions.
+///
+/// These statistics are persisted to a file in the user's config directory (except on the web).
+#[derive(Resource, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct LifetimeStats {
+    /// How many games were finished (won or given up).
+    pub games_played: u32,
+    /// How many games were won (including rounds cleared in zen mode).
+    pub games_won: u32,
+    /// How many cards were collected in total.
+    pub cards_collected: u64,
+    /// How many times a card was clicked (or tapped).
+    pub clicks: u64,
+    /// The fastest time (in seconds) to collect all cards.
+    pub best_time: Option<f32>,
+    /// The sum of the times (in seconds) of all won games, used to calculate the average time.
+    pub total_win_time: f64,
+    /// When (in seconds since the round started) each card was picked up in the [`Self::best_time`] game,
+    /// in the order they were picked up. This is empty if no game was won since it was recorded.
+    #[serde(default)]
+    pub best_splits: Vec<f32>,
+}
+
+impl LifetimeStats {
+    /// The name of the persisted statistics file.
+    const FILE_NAME: &str = "stats.ron";
+
+    /// Records a won game that took `time` seconds, with the `splits` (see [`Self::best_splits`])
+    /// that are kept if this is the new [`Self::best_time`].
+    pub fn record_win(&mut self, time: f32, splits: Vec<f32>) {
+        self.games_played += 1;
+        self.games_won += 1;
+        if self.best_time.is_none_or(|best| time < best) {
+            self.best_time = Some(time);
+            self.best_splits = splits;
+        }
+        self.total_win_time += time as f64;
+    }
+
+    /// How many seconds the current game is behind the [`Self::best_splits`] (negative if ahead),
+    /// given its `splits` so far and the `elapsed` time.
+    ///
+    /// Returns [`None`] if there is no best run to compare against yet.
+    pub fn split_difference(&self, splits: &[f32], elapsed: f32) -> Option<f32> {
+        if self.best_splits.is_empty() {
+            return None;
+        }
+        let picked = splits.len().min(self.best_splits.len());
+        // the difference at the last pick
+        let difference = match picked {
+            0 => 0.0,
+            _ => splits[picked - 1] - self.best_splits[picked - 1],
+        };
+        // falling behind the best run's next pick counts before the next card is picked up
+        let pending = self
+            .best_splits
+            .get(picked)
+            .map_or(f32::NEG_INFINITY, |next| elapsed - next);
+        Some(difference.max(pending))
+    }
+
+    /// The average time (in seconds) of all won games.
+    pub fn average_time(&self) -> Option<f32> {
+        (self.games_won > 0).then(|| (self.total_win_time / self.games_won as f64) as f32)
+    }
+}
+
+/// Resource that measures how long the current game has been played.
+#[derive(Resource, Debug, Default)]
+pub struct RoundClock(pub Stopwatch);
+
+/// Resource that exists while the statistics screen asks the player to confirm resetting the [`LifetimeStats`].
+#[derive(Resource, Debug)]
+pub struct ConfirmStatsReset;
+
+/// System that loads the persisted [`LifetimeStats`] at startup.
+pub fn load_stats(mut stats: ResMut<LifetimeStats>) {
+    if let Some(loaded) = persist::load(LifetimeStats::FILE_NAME) {
+        *stats = loaded;
+    }
+}
+
+/// System that persists the [`LifetimeStats`] whenever they change (outside of [`GameState::Play`]).
+pub fn save_stats(stats: Res<LifetimeStats>) {
+    persist::save(LifetimeStats::FILE_NAME, stats.as_ref());
+}
+
+/// System that advances the [`RoundClock`] during [`GameState::Play`].
+pub fn tick_round_clock(time: Res<Time>, mut clock: ResMut<RoundClock>) {
+    clock.0.tick(time.delta());
+}
+
+/// System that records a game that was given up, when entering the [`GameState::Lose`] state.
+///
+/// Like a won short game, a short game that was given up (see [`CardsDealt`]) is not recorded,
+/// and neither is a game in [`GameMode::Learn`].
+pub fn record_loss(
+    mut stats: ResMut<LifetimeStats>,
+    cards_dealt: Res<CardsDealt>,
+    mode: Res<GameMode>,
+) {
+    if cards_dealt.is_full_deck() && *mode != GameMode::Learn {
+        stats.games_played += 1;
+    }
+}
+
+/// Formats a duration (in seconds) as minutes and seconds (e.g. `1:05.3`).
+fn format_time(seconds: f32) -> String {
+    format!("{}:{:04.1}", (seconds / 60.0) as u32, seconds % 60.0)
+}
+
+/// System that spawns the statistics screen when entering the [`GameState::Statistics`] state.
+pub fn statistics_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    stats: Res<LifetimeStats>,
+    confirm_reset: Option<Res<ConfirmStatsReset>>,
+) {
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+    let time_or_none = |time: Option<f32>| time.map_or("-".to_string(), format_time);
+    let rows = [
+        ("Games played", stats.games_played.to_string()),
+        ("Games won", stats.games_won.to_string()),
+        ("Cards collected", stats.cards_collected.to_string()),
+        ("Card clicks", stats.clicks.to_string()),
+        ("Best time", time_or_none(stats.best_time)),
+        ("Average time", time_or_none(stats.average_time())),
+    ];
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                row_gap: Val::Px(8.0),
+                ..Default::default()
+            },
+            BackgroundColor(theme.background()),
+            DespawnOnExit(GameState::Statistics),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                // embedded the FontAwesome bar-chart icon via unicode code-point
+                Text::new("\u{F080} Statistics"),
+                TextColor(theme.text()),
+                TextFont {
+                    // nerd font required for code-point to render correctly
+                    font: font.clone(),
+                    font_size: 32.0,
+                    ..Default::default()
+                },
+            ));
+            if stats.games_played == 0 && stats.clicks == 0 {
+                root.spawn((
+                    Text::new("No games played yet. Go pick up some cards!"),
+                    TextColor(theme.muted_text()),
+                    font_component.clone(),
+                ));
+            } else {
+                for (label, value) in rows {
+                    root.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(8.0),
+                        ..Default::default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Node {
+                                width: Val::Px(240.0),
+                                ..Default::default()
+                            },
+                            Text::new(label),
+                            TextColor(theme.text()),
+                            font_component.clone(),
+                        ));
+                        row.spawn((
+                            Node {
+                                width: Val::Px(120.0),
+                                justify_content: JustifyContent::End,
+                                ..Default::default()
+                            },
+                            Text::new(value),
+                            TextLayout::new_with_justify(Justify::Right),
+                            TextColor(theme.text()),
+                            font_component.clone(),
+                        ));
+                    });
+                }
+            }
+            if confirm_reset.is_some() {
+                root.spawn((
+                    Text::new("Reset all statistics? This cannot be undone."),
+                    TextColor(theme.text()),
+                    font_component.clone(),
+                ));
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    spawn_button(
+                        row,
+                        font_component.clone(),
+                        theme,
+                        // embedded the FontAwesome trash icon via unicode code-point
+                        "\u{F1F8} Yes, Reset",
+                        ButtonAction::ResetStats(true),
+                    );
+                    spawn_button(
+                        row,
+                        font_component.clone(),
+                        theme,
+                        "Cancel",
+                        ButtonAction::OpenStatistics,
+                    );
+                });
+            } else {
+                spawn_button(
+                    root,
+                    font_component.clone(),
+                    theme,
+                    // embedded the FontAwesome trash icon via unicode code-point
+                    "\u{F1F8} Reset Stats",
+                    ButtonAction::ResetStats(false),
+                );
+                spawn_button(
+                    root,
+                    font_component.clone(),
+                    theme,
+                    // embedded the FontAwesome arrow-left icon via unicode code-point
+                    "\u{F060} Back",
+                    ButtonAction::Back,
+                );
+            }
+        });
+}
@@ -0,0 +1,104 @@
+use bevy::{color::palettes::tailwind::AMBER_900, prelude::*};
+
+use crate::{
+    cards::BOARD_HALF_SIZE,
+    layout::{from_table, to_table},
+    settings::Settings,
+};
+
+/// The width of each [`TableRail`] (on the table's plane).
+const RAIL_WIDTH: f32 = 8.0;
+
+/// How high each [`TableRail`] stands above the table.
+const RAIL_HEIGHT: f32 = 2.0;
+
+/// A rail that frames the board (with [`Settings::table_rails`] only), centered at the given point
+/// in the board's layout space (see [`to_table`](crate::layout::to_table)).
+#[derive(Component, Debug)]
+pub struct TableRail(Vec3);
+
+/// Is the given `position` (on the table) within the board (as turned by [`Settings::board_rotation`])?
+///
+/// Presses outside the board (on the floor's margin, where the pile and deck sit) are not picking presses.
+pub fn is_on_board(settings: &Settings, position: Vec3) -> bool {
+    from_table(settings, position)
+        .xz()
+        .abs()
+        .cmple(BOARD_HALF_SIZE)
+        .all()
+}
+
+/// Spawns the [`TableRail`]s along the board's edges (hidden unless [`Settings::table_rails`] is enabled).
+///
+/// The rails are sized from [`BOARD_HALF_SIZE`], so they sit just outside the board.
+pub fn spawn_table_rails(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    settings: &Settings,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: Color::from(AMBER_900),
+        perceptual_roughness: 0.6,
+        ..default()
+    });
+    // the long rails also cover the corners
+    let long = meshes.add(Cuboid::new(
+        (BOARD_HALF_SIZE.x + RAIL_WIDTH) * 2.0,
+        RAIL_HEIGHT,
+        RAIL_WIDTH,
+    ));
+    let short = meshes.add(Cuboid::new(
+        RAIL_WIDTH,
+        RAIL_HEIGHT,
+        BOARD_HALF_SIZE.y * 2.0,
+    ));
+    let offset = BOARD_HALF_SIZE + RAIL_WIDTH / 2.0;
+    let rails = [
+        (long.clone(), Vec3::new(0.0, 0.0, offset.y)),
+        (long, Vec3::new(0.0, 0.0, -offset.y)),
+        (short.clone(), Vec3::new(offset.x, 0.0, 0.0)),
+        (short, Vec3::new(-offset.x, 0.0, 0.0)),
+    ];
+    for (mesh, position) in rails {
+        let rail = TableRail(position.with_y(RAIL_HEIGHT / 2.0));
+        commands.spawn((
+            Mesh3d(mesh),
+            MeshMaterial3d(material.clone()),
+            rail.transform(settings),
+            rail,
+            rail_visibility(settings),
+            // the rails should never block the pointer from reaching the cards
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+impl TableRail {
+    /// The rail's transform on the table, turned with the board (see [`Settings::board_rotation`]).
+    fn transform(&self, settings: &Settings) -> Transform {
+        Transform::from_translation(to_table(settings, self.0))
+            .with_rotation(settings.board_rotation.quat())
+    }
+}
+
+/// System that shows (or hides) the [`TableRail`]s per the [`Settings::table_rails`], and turns them
+/// with the board (when the settings change).
+pub fn apply_table_rails(
+    settings: Res<Settings>,
+    rails: Query<(&TableRail, &mut Transform, &mut Visibility)>,
+) {
+    for (rail, mut transform, mut visibility) in rails {
+        *transform = rail.transform(&settings);
+        *visibility = rail_visibility(&settings);
+    }
+}
+
+/// The [`Visibility`] of the [`TableRail`]s per the [`Settings::table_rails`].
+fn rail_visibility(settings: &Settings) -> Visibility {
+    if settings.table_rails {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    }
+}
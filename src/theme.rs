@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The color theme of the menus (see [`Settings::ui_theme`](crate::settings::Settings::ui_theme)).
+///
+/// The theme is applied when a menu is spawned.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiTheme {
+    /// Light text on a translucent black background.
+    #[default]
+    Dark,
+    /// Dark text on a translucent white background.
+    Light,
+}
+
+impl UiTheme {
+    /// The human readable name of the theme.
+    pub fn label(&self) -> &'static str {
+        match self {
+            UiTheme::Dark => "Dark",
+            UiTheme::Light => "Light",
+        }
+    }
+
+    /// The background color of a menu (which covers the board).
+    pub fn background(&self) -> Color {
+        match self {
+            UiTheme::Dark => Color::BLACK.with_alpha(0.75),
+            UiTheme::Light => Color::srgb(0.95, 0.95, 0.92).with_alpha(0.85),
+        }
+    }
+
+    /// The color of text displayed on the [`Self::background`].
+    pub fn text(&self) -> Color {
+        match self {
+            UiTheme::Dark => Color::WHITE,
+            UiTheme::Light => Color::srgb(0.1, 0.1, 0.1),
+        }
+    }
+
+    /// The color of less important text (like tips) displayed on the [`Self::background`].
+    pub fn muted_text(&self) -> Color {
+        self.text().with_alpha(0.75)
+    }
+
+    /// The background color of a button.
+    pub fn button(&self) -> Color {
+        match self {
+            UiTheme::Dark => Color::srgb(0.125, 0.85, 0.125),
+            // a darker green keeps the white label readable on a light background
+            UiTheme::Light => Color::srgb(0.1, 0.5, 0.1),
+        }
+    }
+
+    /// The background color of a button while the pointer is over it.
+    pub fn button_hovered(&self) -> Color {
+        self.button().lighter(0.08)
+    }
+
+    /// The background color of a button while it is pressed.
+    pub fn button_pressed(&self) -> Color {
+        self.button().darker(0.08)
+    }
+
+    /// The color of a button's label.
+    pub fn button_text(&self) -> Color {
+        Color::WHITE
+    }
+}
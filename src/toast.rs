@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+/// A short-lived notification displayed at the top of the screen.
+#[derive(Component, Debug)]
+pub struct Toast(pub Timer);
+
+impl Toast {
+    /// How long a toast is displayed.
+    pub const DURATION: f32 = 2.0;
+
+    /// Spawns a toast displaying the given `message`.
+    pub fn spawn(commands: &mut Commands, asset_server: &AssetServer, message: impl Into<String>) {
+        commands.spawn((
+            Toast(Timer::from_seconds(Self::DURATION, TimerMode::Once)),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(16.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            // toasts should never block the pointer from reaching the cards
+            Pickable::IGNORE,
+            GlobalZIndex(10),
+            children![(
+                Node {
+                    padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                    border_radius: BorderRadius::all(Val::Px(8.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.75)),
+                Pickable::IGNORE,
+                children![(
+                    Text::new(message),
+                    TextColor(Color::WHITE),
+                    TextFont {
+                        font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                )],
+            )],
+        ));
+    }
+}
+
+/// System that despawns each [`Toast`] once its timer finishes.
+pub fn expire_toasts(mut commands: Commands, time: Res<Time>, query: Query<(Entity, &mut Toast)>) {
+    for (entity, mut toast) in query {
+        if toast.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+
+use crate::{
+    animator::{AnimationInfo, GameSpeed},
+    camera::MainCamera,
+    cards::Card,
+    state::GameState,
+};
+
+/// A floating label that displays a [`Card`]'s name while it is flipped face-up.
+#[derive(Component, Debug)]
+pub struct CardTooltip {
+    /// The card entity that the tooltip follows.
+    pub card: Entity,
+    /// Tracks how long the tooltip has been displayed.
+    pub timer: Timer,
+}
+
+impl CardTooltip {
+    /// How long the tooltip takes to fade out after the card's flip animation.
+    pub const FADE_DURATION: f32 = 0.5;
+
+    /// Spawns a tooltip (displaying the `card`'s name) that follows the given `entity`.
+    ///
+    /// The tooltip lasts as long as the card's flip animation (at the given `speed`), then fades out.
+    pub fn spawn(
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        entity: Entity,
+        card: &Card,
+        speed: GameSpeed,
+    ) {
+        commands.spawn((
+            CardTooltip {
+                card: entity,
+                timer: Timer::from_seconds(
+                    AnimationInfo::ANIMATION_DURATION / speed.factor() + Self::FADE_DURATION,
+                    TimerMode::Once,
+                ),
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                border_radius: BorderRadius::all(Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.75)),
+            // tooltips should never block the pointer from reaching the cards
+            Pickable::IGNORE,
+            // hidden until positioned over the card
+            Visibility::Hidden,
+            DespawnOnExit(GameState::Play),
+            children![(
+                Text::new(format!("{card:#}")),
+                TextColor(Color::WHITE),
+                TextFont {
+                    font: asset_server.load("fonts/UbuntuNerdFont-Medium.ttf"),
+                    font_size: 20.0,
+                    ..default()
+                },
+                Pickable::IGNORE,
+            )],
+        ));
+    }
+}
+
+/// System that moves each [`CardTooltip`] along with its card, then fades it out.
+///
+/// Tooltips are despawned once their timer finishes.
+pub fn update_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    camera: Single<(&Camera, &GlobalTransform), With<MainCamera>>,
+    cards: Query<&GlobalTransform, With<Card>>,
+    mut tooltips: Query<(
+        Entity,
+        &mut CardTooltip,
+        &mut Node,
+        &mut Visibility,
+        &mut BackgroundColor,
+        &Children,
+    )>,
+    mut text_colors: Query<&mut TextColor>,
+) {
+    let (camera, camera_transform) = *camera;
+    for (entity, mut tooltip, mut node, mut visibility, mut background, children) in &mut tooltips {
+        tooltip.timer.tick(time.delta());
+        if tooltip.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let Ok(card_transform) = cards.get(tooltip.card) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if let Ok(position) =
+            camera.world_to_viewport(camera_transform, card_transform.translation())
+        {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+            *visibility = Visibility::Inherited;
+        }
+
+        let elapsed = tooltip.timer.elapsed_secs();
+        let alpha = if elapsed > AnimationInfo::ANIMATION_DURATION {
+            1.0 - (elapsed - AnimationInfo::ANIMATION_DURATION) / CardTooltip::FADE_DURATION
+        } else {
+            1.0
+        };
+        background.0.set_alpha(0.75 * alpha);
+        for child in children {
+            if let Ok(mut color) = text_colors.get_mut(*child) {
+                color.0.set_alpha(alpha);
+            }
+        }
+    }
+}
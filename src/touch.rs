@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+
+use crate::{
+    animator::{PressableCards, press_card},
+    settings::Settings,
+    stats::LifetimeStats,
+};
+
+/// A card that is being touched (and held), which is not picked up until the touch is released.
+///
+/// If held for [`Settings::long_press_duration`], the card is lifted so the player can peek at it.
+/// Releasing a peeked card puts it back down without picking it up.
+#[derive(Component, Debug)]
+pub struct TouchHold {
+    /// Tracks how long the card has been held.
+    timer: Timer,
+    /// Where the card was before it was lifted (if it is being peeked at).
+    peek_origin: Option<Vec3>,
+}
+
+impl TouchHold {
+    /// How high (in world units) a peeked card is lifted.
+    const PEEK_LIFT: f32 = 10.0;
+
+    /// Starts holding a card for the [`Settings::long_press_duration`].
+    pub fn new(settings: &Settings) -> Self {
+        Self {
+            timer: Timer::from_seconds(settings.long_press_duration, TimerMode::Once),
+            peek_origin: None,
+        }
+    }
+}
+
+/// System that lifts each card held (with a touch) for longer than [`Settings::long_press_duration`].
+///
+/// Holds are dropped (and peeked cards put back down) once no touches remain on the screen.
+pub fn update_touch_holds(
+    mut commands: Commands,
+    time: Res<Time>,
+    touches: Res<Touches>,
+    mut query: Query<(Entity, &mut TouchHold, &mut Transform)>,
+) {
+    for (entity, mut hold, mut transform) in &mut query {
+        if touches.iter().next().is_none() {
+            // the touch ended without a release over this card
+            if let Some(origin) = hold.peek_origin {
+                transform.translation = origin;
+            }
+            commands.entity(entity).remove::<TouchHold>();
+            continue;
+        }
+        hold.timer.tick(time.delta());
+        if hold.timer.is_finished() && hold.peek_origin.is_none() {
+            hold.peek_origin = Some(transform.translation);
+            transform.translation.y += TouchHold::PEEK_LIFT;
+        }
+    }
+}
+
+/// System that runs when a touch on a card is released.
+///
+/// A short tap picks up the card (see [`press_card`]), but a long press only puts the peeked card back down.
+pub fn released_card(
+    entity_event: On<Pointer<Release>>,
+    mut commands: Commands,
+    mut stats: ResMut<LifetimeStats>,
+    settings: Res<Settings>,
+    mut cards: ParamSet<(PressableCards, Query<(&TouchHold, &mut Transform)>)>,
+) {
+    let entity = entity_event.event_target();
+    let mut holds = cards.p1();
+    let Ok((hold, mut transform)) = holds.get_mut(entity) else {
+        return;
+    };
+    let peek_origin = hold.peek_origin;
+    if let Some(origin) = peek_origin {
+        transform.translation = origin;
+    }
+    commands.entity(entity).remove::<TouchHold>();
+    if peek_origin.is_none() {
+        press_card(&mut commands, &mut stats, &settings, &cards.p0(), entity);
+    }
+}
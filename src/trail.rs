@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+use crate::{
+    animator::{AnimationInfo, GameSpeed},
+    cards::CARD_HALF_SIZE,
+    state::GameState,
+};
+
+/// How often (in seconds) a [`TrailGhost`] is left behind a collected card.
+const GHOST_INTERVAL: f32 = 0.04;
+
+/// How long (in seconds) a [`TrailGhost`] takes to fade out.
+const GHOST_LIFETIME: f32 = 0.25;
+
+/// The opacity of a new [`TrailGhost`].
+const GHOST_ALPHA: f32 = 0.35;
+
+/// The most [`TrailGhost`]s that can exist at once, so collecting many cards at once stays cheap.
+const MAX_GHOSTS: usize = 48;
+
+/// A component for a collected card that leaves a trail of [`TrailGhost`]s while it flies to the pile.
+#[derive(Component, Debug)]
+pub struct Trail {
+    /// Tracks how long the card is still flying.
+    duration: Timer,
+    /// Tracks when the next [`TrailGhost`] is left behind.
+    interval: Timer,
+}
+
+impl Trail {
+    /// A trail that lasts as long as the collect animation (at the given game speed).
+    pub fn new(speed: GameSpeed) -> Self {
+        Self {
+            duration: Timer::from_seconds(
+                AnimationInfo::ANIMATION_DURATION / speed.factor(),
+                TimerMode::Once,
+            ),
+            interval: Timer::from_seconds(GHOST_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// A decorative, fading copy of a collected card's outline (see [`Trail`]).
+///
+/// Ghosts cannot be pressed, and are despawned once they have faded out.
+#[derive(Component, Debug)]
+pub struct TrailGhost {
+    /// Tracks how far the ghost has faded out.
+    timer: Timer,
+}
+
+/// System that leaves [`TrailGhost`]s behind each card with a [`Trail`],
+/// and removes the [`Trail`] once the card has landed.
+pub fn emit_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ghost_mesh: Local<Option<Handle<Mesh>>>,
+    mut cards: Query<(Entity, &Transform, &mut Trail)>,
+    ghosts: Query<(), With<TrailGhost>>,
+) {
+    let mut ghost_count = ghosts.iter().count();
+    for (entity, transform, mut trail) in &mut cards {
+        if trail.duration.tick(time.delta()).is_finished() {
+            commands.entity(entity).remove::<Trail>();
+            continue;
+        }
+        trail.interval.tick(time.delta());
+        if !trail.interval.just_finished() || ghost_count >= MAX_GHOSTS {
+            continue;
+        }
+        ghost_count += 1;
+        let mesh = ghost_mesh
+            .get_or_insert_with(|| meshes.add(Rectangle::from_size(CARD_HALF_SIZE * 2.0)))
+            .clone();
+        commands.spawn((
+            TrailGhost {
+                timer: Timer::from_seconds(GHOST_LIFETIME, TimerMode::Once),
+            },
+            Mesh3d(mesh),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::WHITE.with_alpha(GHOST_ALPHA),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                double_sided: true,
+                cull_mode: None,
+                ..Default::default()
+            })),
+            *transform,
+            Pickable::IGNORE,
+            DespawnOnExit(GameState::Play),
+        ));
+    }
+}
+
+/// System that fades out each [`TrailGhost`] and despawns it once it is invisible.
+pub fn fade_trail_ghosts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut ghosts: Query<(Entity, &mut TrailGhost, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut ghost, material) in &mut ghosts {
+        if ghost.timer.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        if let Some(material) = materials.get_mut(material) {
+            material.base_color =
+                Color::WHITE.with_alpha(GHOST_ALPHA * ghost.timer.fraction_remaining());
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use crate::{
+    menu::{ButtonAction, spawn_button},
+    settings::Settings,
+};
+
+/// Resource that exists while the "How to Play" tutorial is shown (see [`TutorialOverlay`]).
+#[derive(Resource, Debug)]
+pub struct ShowTutorial;
+
+/// A marker for the overlay that explains how to play (while [`ShowTutorial`] exists).
+///
+/// The overlay is not tied to any [`GameState`](crate::state::GameState), so it can be shown over any screen.
+#[derive(Component, Debug)]
+pub struct TutorialOverlay;
+
+/// The steps explained by the tutorial, in order.
+const STEPS: [&str; 4] = [
+    "The cards are scattered all over the table.",
+    "Click (or tap) a card to pick it up.",
+    "Cards underneath others cannot be picked up until they are uncovered.",
+    "Pick up all 52 cards to win!",
+];
+
+/// System that shows (or hides) the [`TutorialOverlay`] while the [`ShowTutorial`] resource exists.
+///
+/// The overlay covers the whole screen, so the buttons underneath cannot be pressed while it is shown.
+pub fn update_tutorial_overlay(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<Settings>,
+    show: Option<Res<ShowTutorial>>,
+    overlay: Query<Entity, With<TutorialOverlay>>,
+) {
+    if show.is_none() {
+        for entity in overlay {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+    if !overlay.is_empty() {
+        return;
+    }
+    let theme = settings.ui_theme;
+    let font = asset_server.load("fonts/UbuntuNerdFont-Medium.ttf");
+    let font_component = TextFont {
+        font: font.clone(),
+        font_size: 24.0,
+        ..Default::default()
+    };
+    commands
+        .spawn((
+            TutorialOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            // this backdrop blocks the pointer from reaching the buttons underneath
+            BackgroundColor(Color::BLACK.with_alpha(0.5)),
+            GlobalZIndex(16),
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.0),
+                    padding: UiRect::all(Val::Px(16.0)),
+                    border_radius: BorderRadius::all(Val::Px(8.0)),
+                    ..Default::default()
+                },
+                BackgroundColor(theme.background()),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    // embedded the FontAwesome question-circle icon via unicode code-point
+                    Text::new("\u{F059} How to Play"),
+                    TextColor(theme.text()),
+                    TextFont {
+                        // nerd font required for code-point to render correctly
+                        font: font.clone(),
+                        font_size: 32.0,
+                        ..Default::default()
+                    },
+                ));
+                for (number, step) in STEPS.iter().enumerate() {
+                    panel.spawn((
+                        Text::new(format!("{}. {step}", number + 1)),
+                        TextColor(theme.muted_text()),
+                        font_component.clone(),
+                    ));
+                }
+                spawn_button(
+                    panel,
+                    font_component.clone(),
+                    theme,
+                    "Got it",
+                    ButtonAction::CloseTutorial,
+                );
+            });
+        });
+}
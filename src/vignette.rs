@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::Settings;
+
+/// How much the corners of the screen are darkened, which draws the eye to the board.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Vignette {
+    /// The screen is not darkened.
+    #[default]
+    Off,
+    /// The corners are slightly darkened.
+    Subtle,
+    /// The corners are noticeably darkened.
+    Strong,
+}
+
+impl Vignette {
+    /// The human readable name of the intensity.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Vignette::Off => "Off",
+            Vignette::Subtle => "Subtle",
+            Vignette::Strong => "Strong",
+        }
+    }
+
+    /// Changes to the next stronger (or weaker if `step` is negative) intensity, stopping at either end.
+    pub fn step(&mut self, step: i8) {
+        let list = [Vignette::Off, Vignette::Subtle, Vignette::Strong];
+        let index = list
+            .iter()
+            .position(|vignette| vignette == self)
+            .unwrap_or(0);
+        *self = list[(index as isize + step as isize).clamp(0, 2) as usize];
+    }
+
+    /// The opacity of the darkest (outermost) part of the vignette.
+    fn alpha(&self) -> f32 {
+        match self {
+            Vignette::Off => 0.0,
+            Vignette::Subtle => 0.35,
+            Vignette::Strong => 0.6,
+        }
+    }
+}
+
+/// A marker for the full-screen gradient that draws the [`Vignette`].
+#[derive(Component, Debug)]
+pub struct VignetteOverlay;
+
+/// System that spawns the [`VignetteOverlay`] (hidden until [`apply_vignette`] shows it) at startup.
+///
+/// The overlay is drawn above the 3D scene but below all other UI (including the menus).
+pub fn spawn_vignette(mut commands: Commands) {
+    commands.spawn((
+        VignetteOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        // the overlay should never block the pointer from reaching anything beneath it
+        Pickable::IGNORE,
+        GlobalZIndex(-1),
+    ));
+}
+
+/// System that applies the [`Settings::vignette`] to the [`VignetteOverlay`] (when the settings change).
+pub fn apply_vignette(
+    mut commands: Commands,
+    settings: Res<Settings>,
+    overlay: Single<(Entity, &mut Visibility), With<VignetteOverlay>>,
+) {
+    let (entity, mut visibility) = overlay.into_inner();
+    if settings.vignette == Vignette::Off {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    *visibility = Visibility::Inherited;
+    commands
+        .entity(entity)
+        .insert(BackgroundGradient::from(RadialGradient::new(
+            UiPosition::CENTER,
+            RadialGradientShape::FarthestCorner,
+            vec![
+                ColorStop::percent(Color::NONE, 55.0),
+                ColorStop::percent(Color::BLACK.with_alpha(settings.vignette.alpha()), 100.0),
+            ],
+        )));
+}